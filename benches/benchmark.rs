@@ -1,15 +1,92 @@
+#[path = "../src/cycle.rs"]
+pub mod cycle;
+
+#[path = "../src/error.rs"]
+pub mod error;
+
 #[path = "../src/file_handler.rs"]
 pub mod file_handler;
 
+#[path = "../src/grid.rs"]
+pub mod grid;
+
+#[path = "../src/interval.rs"]
+pub mod interval;
+
+#[path = "../src/parsing.rs"]
+pub mod parsing;
+
+#[path = "../src/point.rs"]
+pub mod point;
+
+#[path = "../src/registry.rs"]
+pub mod registry;
+
+#[path = "../src/search.rs"]
+pub mod search;
+
+#[path = "../src/solution.rs"]
+pub mod solution;
+
+#[path = "../src/Y2015/mod.rs"]
+pub mod Y2015;
+
 #[path = "../src/Y2022/mod.rs"]
 pub mod Y2022;
 
-use criterion::{black_box, criterion_group, criterion_main, Criterion};
+#[path = "../src/Y2023/mod.rs"]
+pub mod Y2023;
 
+#[path = "../src/Y2024/mod.rs"]
+pub mod Y2024;
+
+use criterion::measurement::WallTime;
+use criterion::{criterion_group, criterion_main, BenchmarkGroup, Criterion};
+
+use crate::file_handler::{FileHandler, InputSource};
+use crate::solution::Timer;
+
+/// Benchmarks of one day, named `Day_XX/<parse|part_one|part_two>` inside the group of its year.
+struct DayTimer<'a, 'b> {
+    group: &'a mut BenchmarkGroup<'b, WallTime>,
+    day: u8,
+}
+
+impl Timer for DayTimer<'_, '_> {
+    fn time(&mut self, label: &str, routine: &mut dyn FnMut()) {
+        self.group
+            .bench_function(format!("Day_{:02}/{}", self.day, label), |b| {
+                b.iter(&mut *routine)
+            });
+    }
+}
+
+/// Every day of the registry, grouped per year. The inputs are read before anything is timed,
+/// run e.g. `cargo bench -- Y2023/Day_08` to only benchmark a single day.
 pub fn criterion_benchmark(c: &mut Criterion) {
-    c.bench_function("Day_01", |b| {
-        b.iter(|| crate::Y2022::days::day_01::Day01::run())
-    });
+    let source = InputSource::from_env();
+
+    for year in registry::years() {
+        let mut group = c.benchmark_group(format!("Y{}", year));
+        for entry in registry::days_of(year).unwrap() {
+            let input = match FileHandler::read(&source, entry.year, entry.day) {
+                Ok(input) => input,
+                Err(error) => {
+                    eprintln!("skipping: {}", error);
+                    continue;
+                }
+            };
+
+            let mut timer = DayTimer {
+                group: &mut group,
+                day: entry.day,
+            };
+            if let Err(error) = (entry.bench)(&input, &mut timer) {
+                eprintln!("skipping Y{} Day {:02}: {}", entry.year, entry.day, error);
+            }
+        }
+        group.finish();
+    }
 }
 
 criterion_group!(benches, criterion_benchmark);
@@ -1,68 +1,71 @@
-use std::time::Instant;
+use crate::error::AocError;
+use crate::solution::{Example, Solution};
 
-use crate::file_handler::FileHandler;
-
-enum Direction {
+pub enum Direction {
     UP,
     DOWN,
 }
 
 pub struct Day01 {}
 
-impl Day01 {
-    pub fn run() -> (String, String, String, u128, u128) {
-        let first_input = FileHandler::read("./src/Y2015/inputs/day_01_1.txt");
-
-        let lines: Vec<&str> = first_input.split('\n').collect();
-
-        let start_1 = Instant::now();
-        let result_1 = Day01::part_01(&lines);
-        let time_calculation_1 = start_1.elapsed();
-
-        let start_2 = Instant::now();
-        let result_2 = Day01::part_02(&lines);
-        let time_calculation_2 = start_2.elapsed();
-
-        (
-            "Day_01".to_owned(),
-            format!("{}", result_1),
-            format!("{}", result_2),
-            time_calculation_1.as_nanos(),
-            time_calculation_2.as_nanos(),
-        )
+impl Solution for Day01 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 1;
+
+    type Input<'a> = (i32, i32, Vec<Direction>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let lines: Vec<&str> = input.split('\n').collect();
+        parsing(&lines)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day01::part_01(input).to_string())
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day01::part_02(input)?.to_string())
     }
 
+    fn examples() -> Vec<Example> {
+        vec![
+            Example::new(1, TEST_INPUT, "3"),
+            Example::new(2, "()())", "5"),
+        ]
+    }
+}
+
+impl Day01 {
     /// I want to see what the puzzle look like in 2015. So, I start playing previous years puzzle.
     /// The first puzzle quite easy.
-    fn part_01(lines: &Vec<&str>) -> i32 {
-        let (total_ups, total_downs, _) = parsing(lines);
+    fn part_01(input: &(i32, i32, Vec<Direction>)) -> i32 {
+        let (total_ups, total_downs, _) = input;
         return total_ups - total_downs;
     }
 
-    fn part_02(lines: &Vec<&str>) -> i32 {
-        let (_, _, move_orders) = parsing(lines);
-        let position = processing(&move_orders);
-        return position as i32;
+    fn part_02(input: &(i32, i32, Vec<Direction>)) -> Result<i32, AocError> {
+        let (_, _, move_orders) = input;
+        let position = processing(move_orders)
+            .ok_or_else(|| AocError::unsolvable("Santa never enters the basement"))?;
+        return Ok(position as i32);
     }
 }
 
 const TEST_INPUT: &str = "))(((((";
 
 #[test]
-fn test_part_1() {
-    let lines: Vec<&str> = TEST_INPUT.lines().collect();
-    let result = Day01::part_01(&lines);
-    assert_eq!(result, 3);
+fn test_examples() {
+    crate::solution::assert_examples::<Day01>();
 }
 
 #[test]
-fn test_part_2() {
-    let lines: Vec<&str> = "()())".lines().collect();
-    let result = Day01::part_02(&lines);
-    assert_eq!(result, 5);
+fn test_never_in_basement() {
+    let input = Day01::parse("()").unwrap();
+    assert!(Day01::part_two(&input).is_err());
 }
 
-fn processing(move_orders: &Vec<Direction>) -> usize {
+/// The position of the first move into the basement, `None` if Santa never goes there.
+fn processing(move_orders: &Vec<Direction>) -> Option<usize> {
     let mut floor = 0;
     for (index, direction) in move_orders.iter().enumerate() {
         match direction {
@@ -70,28 +73,40 @@ fn processing(move_orders: &Vec<Direction>) -> usize {
             Direction::DOWN => {
                 floor -= 1;
                 if floor < 0 {
-                    return index + 1;
+                    return Some(index + 1);
                 }
             }
         }
     }
 
-    panic!("It should not pass through here")
+    None
 }
 
-fn parsing(lines: &Vec<&str>) -> (i32, i32, Vec<Direction>) {
+fn parsing(lines: &Vec<&str>) -> Result<(i32, i32, Vec<Direction>), AocError> {
     let mut total_up = 0;
     let mut total_down = 0;
     let mut order = vec![];
-    for c in lines.first().unwrap().chars() {
-        if c == '(' {
-            total_up += 1;
-            order.push(Direction::UP);
-        } else if c == ')' {
-            total_down += 1;
-            order.push(Direction::DOWN);
+    for (index, line) in lines.iter().enumerate() {
+        for c in line.chars() {
+            match c {
+                '(' => {
+                    total_up += 1;
+                    order.push(Direction::UP);
+                }
+                ')' => {
+                    total_down += 1;
+                    order.push(Direction::DOWN);
+                }
+                _ => {
+                    return Err(AocError::parse(
+                        index,
+                        line,
+                        format!("unknown direction `{}`", c),
+                    ))
+                }
+            }
         }
     }
 
-    (total_up, total_down, order)
+    Ok((total_up, total_down, order))
 }
@@ -1,37 +1,38 @@
-use std::time::Instant;
+use crate::error::AocError;
+use crate::solution::{Example, Solution};
 
-use itertools::Itertools;
+pub struct Day02 {}
 
-use crate::file_handler::FileHandler;
+impl Solution for Day02 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 2;
 
-pub struct Day02 {}
+    type Input<'a> = Vec<(i32, i32, i32)>;
 
-impl Day02 {
-    pub fn run() -> (String, String, String, u128, u128) {
-        let first_input = FileHandler::read("./src/Y2015/inputs/day_02_1.txt");
-
-        let lines: Vec<&str> = first_input.split('\n').collect();
-
-        let start_1 = Instant::now();
-        let result_1 = Day02::part_01(&lines);
-        let time_calculation_1 = start_1.elapsed();
-
-        let start_2 = Instant::now();
-        let result_2 = Day02::part_02(&lines);
-        let time_calculation_2 = start_2.elapsed();
-
-        (
-            "Day_02".to_owned(),
-            format!("{}", result_1),
-            format!("{}", result_2),
-            time_calculation_1.as_nanos(),
-            time_calculation_2.as_nanos(),
-        )
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let lines: Vec<&str> = input.split('\n').collect();
+        parsing(&lines)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day02::part_01(input).to_string())
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day02::part_02(input).to_string())
     }
 
+    fn examples() -> Vec<Example> {
+        vec![
+            Example::new(1, TEST_INPUT, "58"),
+            Example::new(2, TEST_INPUT, "34"),
+        ]
+    }
+}
+
+impl Day02 {
     /// The puzzle is nothing much but still fun to do it.
-    fn part_01(lines: &Vec<&str>) -> i32 {
-        let boxes = parsing(lines);
+    fn part_01(boxes: &Vec<(i32, i32, i32)>) -> i32 {
         let mut total_areas = 0;
         for dimensions in boxes.iter() {
             total_areas += surface_area(dimensions.0, dimensions.1, dimensions.2);
@@ -39,8 +40,7 @@ impl Day02 {
         return total_areas;
     }
 
-    fn part_02(lines: &Vec<&str>) -> i32 {
-        let boxes = parsing(lines);
+    fn part_02(boxes: &Vec<(i32, i32, i32)>) -> i32 {
         let mut total_ribbon_length = 0;
         for dimensions in boxes.iter() {
             total_ribbon_length += ribbon_length(dimensions.0, dimensions.1, dimensions.2)
@@ -52,17 +52,8 @@ impl Day02 {
 const TEST_INPUT: &str = "2x3x4";
 
 #[test]
-fn test_part_1() {
-    let lines: Vec<&str> = TEST_INPUT.lines().collect();
-    let result = Day02::part_01(&lines);
-    assert_eq!(result, 58);
-}
-
-#[test]
-fn test_part_2() {
-    let lines: Vec<&str> = TEST_INPUT.lines().collect();
-    let result = Day02::part_02(&lines);
-    assert_eq!(result, 34);
+fn test_examples() {
+    crate::solution::assert_examples::<Day02>();
 }
 
 fn ribbon_length(w: i32, h: i32, l: i32) -> i32 {
@@ -82,16 +73,19 @@ fn surface_area(w: i32, h: i32, l: i32) -> i32 {
     (2 * total_areas) + (*areas.first().unwrap())
 }
 
-fn parsing(lines: &Vec<&str>) -> Vec<(i32, i32, i32)> {
+fn parsing(lines: &Vec<&str>) -> Result<Vec<(i32, i32, i32)>, AocError> {
     lines
         .iter()
-        .map(|line| {
-            let splited = (*line).split("x").collect::<Vec<&str>>();
-            return (
-                splited[0].parse::<i32>().unwrap(),
-                splited[1].parse::<i32>().unwrap(),
-                splited[2].parse::<i32>().unwrap(),
-            );
+        .enumerate()
+        .map(|(index, line)| {
+            let sides = (*line)
+                .split("x")
+                .map(|side| side.parse::<i32>())
+                .collect::<Result<Vec<i32>, _>>();
+            match sides.as_deref() {
+                Ok(&[length, width, height]) => Ok((length, width, height)),
+                _ => Err(AocError::parse(index, line, "expected `LxWxH`")),
+            }
         })
-        .collect_vec()
+        .collect()
 }
@@ -1,50 +1,52 @@
-use std::{collections::HashMap, time::Instant};
+use std::collections::HashMap;
 
-use itertools::Itertools;
-
-use crate::file_handler::FileHandler;
+use crate::error::AocError;
+use crate::point::{Direction, Point};
+use crate::solution::{Example, Solution};
 
 pub struct Day03 {}
 
-impl Day03 {
-    pub fn run() -> (String, String, String, u128, u128) {
-        let first_input = FileHandler::read("./src/Y2015/inputs/day_03_1.txt");
-
-        let lines: Vec<&str> = first_input.split('\n').collect();
-
-        let start_1 = Instant::now();
-        let result_1 = Day03::part_01(&lines);
-        let time_calculation_1 = start_1.elapsed();
-
-        let start_2 = Instant::now();
-        let result_2 = Day03::part_02(&lines);
-        let time_calculation_2 = start_2.elapsed();
-
-        (
-            "Day_03".to_owned(),
-            format!("{}", result_1),
-            format!("{}", result_2),
-            time_calculation_1.as_nanos(),
-            time_calculation_2.as_nanos(),
-        )
+impl Solution for Day03 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 3;
+
+    type Input<'a> = Vec<Direction>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let lines: Vec<&str> = input.split('\n').collect();
+        parsing(&lines)
     }
 
-    fn part_01(lines: &Vec<&str>) -> usize {
-        let directions = parsing(lines);
+    fn part_one(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day03::part_01(input).to_string())
+    }
 
-        return delivery(&directions).0;
+    fn part_two(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day03::part_02(input).to_string())
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![
+            Example::new(1, TEST_INPUT, "4"),
+            Example::new(2, TEST_INPUT, "3"),
+        ]
+    }
+}
+
+impl Day03 {
+    fn part_01(directions: &Vec<Direction>) -> usize {
+        return delivery(directions).0;
     }
 
     /// It is still easy, but I have learnt how to merge two hashmaps using .extend()
-    fn part_02(lines: &Vec<&str>) -> usize {
-        let directions = parsing(lines);
+    fn part_02(directions: &Vec<Direction>) -> usize {
         let mut first_directions: Vec<Direction> = vec![];
         let mut second_directions: Vec<Direction> = vec![];
         for (index, dir) in directions.iter().enumerate() {
             if index % 2 == 0 {
-                first_directions.push(dir.clone());
+                first_directions.push(*dir);
             } else {
-                second_directions.push(dir.clone());
+                second_directions.push(*dir);
             }
         }
 
@@ -58,62 +60,35 @@ impl Day03 {
 const TEST_INPUT: &str = "^>v<";
 
 #[test]
-fn test_part_1() {
-    let lines: Vec<&str> = TEST_INPUT.lines().collect();
-    let result = Day03::part_01(&lines);
-    assert_eq!(result, 4);
-}
-
-#[test]
-fn test_part_2() {
-    let lines: Vec<&str> = TEST_INPUT.lines().collect();
-    let result = Day03::part_02(&lines);
-    assert_eq!(result, 3);
-}
-
-#[derive(Clone)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-    None,
+fn test_examples() {
+    crate::solution::assert_examples::<Day03>();
 }
 
-fn delivery(directions: &Vec<Direction>) -> (usize, HashMap<(i32, i32), i32>) {
+fn delivery(directions: &Vec<Direction>) -> (usize, HashMap<Point, i32>) {
     let mut presents_count = HashMap::new();
-    let mut position = (0, 0);
-    presents_count.insert(position.clone(), 1);
+    let mut position = Point::ORIGIN;
+    presents_count.insert(position, 1);
 
     for dir in directions.iter() {
-        match dir {
-            Direction::Up => position.1 -= 1,
-            Direction::Down => position.1 += 1,
-            Direction::Left => position.0 -= 1,
-            Direction::Right => position.0 += 1,
-            _ => {}
-        }
+        position = position.step(*dir);
 
         if let Some(count) = presents_count.get_mut(&position) {
             *count += 1;
         } else {
-            presents_count.insert(position.clone(), 1);
+            presents_count.insert(position, 1);
         }
     }
 
     (presents_count.len(), presents_count)
 }
 
-fn parsing(lines: &Vec<&str>) -> Vec<Direction> {
+fn parsing(lines: &Vec<&str>) -> Result<Vec<Direction>, AocError> {
     lines[0]
-        .clone()
         .chars()
-        .map(|direction| match direction {
-            '^' => Direction::Up,
-            'v' => Direction::Down,
-            '<' => Direction::Left,
-            '>' => Direction::Right,
-            _ => Direction::None,
+        .map(|direction| {
+            Direction::from_char(direction).ok_or_else(|| {
+                AocError::parse(0, lines[0], format!("unknown direction `{}`", direction))
+            })
         })
-        .collect_vec()
+        .collect()
 }
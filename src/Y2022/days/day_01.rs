@@ -1,47 +1,58 @@
-use std::time::Instant;
-
-use crate::file_handler::FileHandler;
+use crate::error::AocError;
+use crate::parsing::blocks;
+use crate::solution::{Example, Solution};
 
 pub struct Day01 {}
 
-impl Day01 {
-    pub fn run() -> (String, String, String, u128, u128) {
-        let first_input = FileHandler::read("./src/Y2022/inputs/day_01_1.txt");
-
-        let lines: Vec<&str> = first_input.split('\n').collect();
-
-        let start_1 = Instant::now();
-        let result_1 = Day01::part_01(&lines);
-        let time_calculation_1 = start_1.elapsed();
-
-        let start_2 = Instant::now();
-        let result_2 = Day01::part_02(&lines);
-        let time_calculation_2 = start_2.elapsed();
-
-        (
-            "Day_01".to_owned(),
-            format!("{}", result_1),
-            format!("{}", result_2),
-            time_calculation_1.as_nanos(),
-            time_calculation_2.as_nanos(),
-        )
+impl Solution for Day01 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 1;
+
+    /// The calories carried by every elf.
+    type Input<'a> = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        blocks(input)
+            .into_iter()
+            .map(|(first_index, lines)| {
+                lines
+                    .iter()
+                    .enumerate()
+                    .map(|(offset, line)| {
+                        line.parse::<i32>().map_err(|_| {
+                            AocError::parse(first_index + offset, line, "expected calories")
+                        })
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day01::part_01(input).to_string())
     }
 
+    fn part_two(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day01::part_02(input).to_string())
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![
+            Example::new(1, TEST_INPUT, "24000"),
+            Example::new(2, TEST_INPUT, "45000"),
+        ]
+    }
+}
+
+impl Day01 {
     ///  I wrote something to get the result as soon as possible without thinking.
-    fn part_01(lines: &Vec<&str>) -> i32 {
-        let mut total_calories = 0;
+    fn part_01(elves: &[Vec<i32>]) -> i32 {
         let mut max_calories = 0;
 
-        for line in lines.iter() {
-            let value = (*line).to_owned();
-            if value.len() > 0 {
-                total_calories += value.parse::<i32>().unwrap_or(0);
-            } else {
-                if total_calories > max_calories {
-                    max_calories = total_calories;
-                }
-
-                total_calories = 0;
+        for calories in elves.iter() {
+            let total_calories: i32 = calories.iter().sum();
+            if total_calories > max_calories {
+                max_calories = total_calories;
             }
         }
 
@@ -49,20 +60,8 @@ impl Day01 {
     }
 
     /// It needs to use Vec because it will quickly find three maximum values by sorting.
-    fn part_02(lines: &Vec<&str>) -> i32 {
-        let mut total_calories = 0;
-        let mut calories = vec![];
-
-        for line in lines.iter() {
-            let value = (*line).to_owned();
-            if value.len() > 0 {
-                total_calories += value.parse::<i32>().unwrap_or(0);
-            } else {
-                calories.push(total_calories);
-                total_calories = 0;
-            }
-        }
-        calories.push(total_calories);
+    fn part_02(elves: &[Vec<i32>]) -> i32 {
+        let mut calories: Vec<i32> = elves.iter().map(|calories| calories.iter().sum()).collect();
         calories.sort();
 
         // take last 3 values of sorted list and sum.
@@ -88,15 +87,14 @@ const TEST_INPUT: &str = "1000
 10000";
 
 #[test]
-fn test_part_1() {
-    let lines: Vec<&str> = TEST_INPUT.lines().collect();
-    let result = Day01::part_01(&lines);
-    assert_eq!(result, 24000);
+fn test_examples() {
+    crate::solution::assert_examples::<Day01>();
 }
 
 #[test]
-fn test_part_2() {
-    let lines: Vec<&str> = TEST_INPUT.lines().collect();
-    let result = Day01::part_02(&lines);
-    assert_eq!(result, 45000);
+fn test_parse_error() {
+    assert_eq!(
+        Day01::parse("1000\n\n2000\nlots").unwrap_err().to_string(),
+        "cannot parse line 4 `lots`: expected calories"
+    );
 }
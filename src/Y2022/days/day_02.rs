@@ -1,9 +1,8 @@
-use std::time::Instant;
+use crate::error::AocError;
+use crate::solution::{Example, Solution};
 
-use crate::file_handler::FileHandler;
-
-#[derive(Clone, Copy)]
-enum RPS {
+#[derive(Debug, Clone, Copy)]
+pub enum RPS {
     Rock,
     Paper,
     Scissor,
@@ -11,38 +10,50 @@ enum RPS {
 
 pub struct Day02 {}
 
-impl Day02 {
-    pub fn run() -> (String, String, String, u128, u128) {
-        let first_input = FileHandler::read("./src/Y2022/inputs/day_02_1.txt");
-
-        let lines: Vec<&str> = first_input.split('\n').collect();
-
-        let start_1 = Instant::now();
-        let result_1 = Day02::part_01(&lines);
-        let time_calculation_1 = start_1.elapsed();
-
-        let start_2 = Instant::now();
-        let result_2 = Day02::part_02(&lines);
-        let time_calculation_2 = start_2.elapsed();
-
-        (
-            "Day_02".to_owned(),
-            format!("{}", result_1),
-            format!("{}", result_2),
-            time_calculation_1.as_nanos(),
-            time_calculation_2.as_nanos(),
-        )
+impl Solution for Day02 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 2;
+
+    /// The opponent's shape and the second column of every round, `X`, `Y` or `Z`.
+    type Input<'a> = Vec<(RPS, &'a str)>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        input
+            .split('\n')
+            .enumerate()
+            .map(|(index, line)| match line.split_once(' ') {
+                Some((opponent @ ("A" | "B" | "C"), second @ ("X" | "Y" | "Z"))) => {
+                    Ok((Day02::convert_input(opponent), second))
+                }
+                _ => Err(AocError::parse(index, line, "expected `A-C X-Z`")),
+            })
+            .collect()
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day02::part_01(input).to_string())
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day02::part_02(input).to_string())
     }
 
+    fn examples() -> Vec<Example> {
+        vec![
+            Example::new(1, TEST_INPUT, "15"),
+            Example::new(2, TEST_INPUT, "12"),
+        ]
+    }
+}
+
+impl Day02 {
     /// I started the part where it needed to compare two inputs with nested of "match".
-    fn part_01(lines: &Vec<&str>) -> i32 {
+    fn part_01(rounds: &[(RPS, &str)]) -> i32 {
         let mut total_score = 0;
-        for line in lines.iter() {
-            let values: Vec<&str> = line.split(' ').collect();
-            let opponent = Day02::convert_input(values[0]);
-            let you = Day02::convert_input(values[1]);
+        for (opponent, second) in rounds.iter() {
+            let you = Day02::convert_input(second);
 
-            let comparing_score = Day02::comparing(&you, &opponent);
+            let comparing_score = Day02::comparing(&you, opponent);
             let your_command_score = Day02::command_score(&you);
 
             let match_score = comparing_score + your_command_score;
@@ -94,15 +105,13 @@ impl Day02 {
     /// I continued this part with the code from the previous part and added the logic for choosing the command to have the expected match result.
     /// I continue using "if" and "match" for comparing.
     /// At the end. I have some ideas sparking; I could use a 2d array for comparing instead. I will get back to it if I have free time.
-    fn part_02(lines: &Vec<&str>) -> i32 {
+    fn part_02(rounds: &[(RPS, &str)]) -> i32 {
         let mut total_score = 0;
-        for line in lines.iter() {
-            let values: Vec<&str> = line.split(' ').collect();
-            let opponent = Day02::convert_input(values[0]);
-            let expected = Day02::convert_expected(values[1]);
-            let you = Day02::choose(&opponent, expected);
+        for (opponent, second) in rounds.iter() {
+            let expected = Day02::convert_expected(second);
+            let you = Day02::choose(opponent, expected);
 
-            let comparing_score = Day02::comparing(&you, &opponent);
+            let comparing_score = Day02::comparing(&you, opponent);
             let your_command_score = Day02::command_score(&you);
 
             let match_score = comparing_score + your_command_score;
@@ -144,15 +153,14 @@ B X
 C Z";
 
 #[test]
-fn test_part_1() {
-    let lines: Vec<&str> = TEST_INPUT.lines().collect();
-    let result = Day02::part_01(&lines);
-    assert_eq!(result, 15);
+fn test_examples() {
+    crate::solution::assert_examples::<Day02>();
 }
 
 #[test]
-fn test_part_2() {
-    let lines: Vec<&str> = TEST_INPUT.lines().collect();
-    let result = Day02::part_02(&lines);
-    assert_eq!(result, 12);
+fn test_parse_error() {
+    assert_eq!(
+        Day02::parse("A Y\n1").unwrap_err().to_string(),
+        "cannot parse line 2 `1`: expected `A-C X-Z`"
+    );
 }
@@ -1,34 +1,37 @@
-use std::time::Instant;
-
-use crate::file_handler::FileHandler;
+use crate::error::AocError;
+use crate::solution::{Example, Solution};
 
 ///  I woke up early and prepared for today's puzzle.
 /// I started working at 4:45PM and finished at 5:30pm
 pub struct Day03 {}
 
-impl Day03 {
-    pub fn run() -> (String, String, String, u128, u128) {
-        let first_input = FileHandler::read("./src/Y2022/inputs/day_03_1.txt");
-
-        let lines: Vec<&str> = first_input.split('\n').collect();
-
-        let start_1 = Instant::now();
-        let result_1 = Day03::part_01(&lines);
-        let time_calculation_1 = start_1.elapsed();
-
-        let start_2 = Instant::now();
-        let result_2 = Day03::part_02(&lines);
-        let time_calculation_2 = start_2.elapsed();
-
-        (
-            "Day_03".to_owned(),
-            format!("{}", result_1),
-            format!("{}", result_2),
-            time_calculation_1.as_nanos(),
-            time_calculation_2.as_nanos(),
-        )
+impl Solution for Day03 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 3;
+
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Vec<&str>, AocError> {
+        Ok(input.split('\n').collect())
+    }
+
+    fn part_one(lines: &Vec<&str>) -> Result<String, AocError> {
+        Ok(Day03::part_01(lines).to_string())
+    }
+
+    fn part_two(lines: &Vec<&str>) -> Result<String, AocError> {
+        Ok(Day03::part_02(lines).to_string())
     }
 
+    fn examples() -> Vec<Example> {
+        vec![
+            Example::new(1, TEST_INPUT, "157"),
+            Example::new(2, TEST_INPUT, "70"),
+        ]
+    }
+}
+
+impl Day03 {
     /// I look for how to find common characters in multiple strings. I found the solution about converting `char` into usize and using it as index of array.
     /// And It works very well to solve this issue.
     fn part_01(lines: &Vec<&str>) -> i32 {
@@ -131,15 +134,6 @@ ttgJtRGJQctTZtZT
 CrZsJsPPZsGzwwsLwLmpwMDw";
 
 #[test]
-fn test_part_1() {
-    let lines: Vec<&str> = TEST_INPUT.lines().collect();
-    let result = Day03::part_01(&lines);
-    assert_eq!(result, 157);
-}
-
-#[test]
-fn test_part_2() {
-    let lines: Vec<&str> = TEST_INPUT.lines().collect();
-    let result = Day03::part_02(&lines);
-    assert_eq!(result, 70);
+fn test_examples() {
+    crate::solution::assert_examples::<Day03>();
 }
@@ -1,39 +1,45 @@
-use std::time::Instant;
-
 use itertools::Itertools;
 use std::collections::HashMap;
 
-use crate::file_handler::FileHandler;
+use crate::error::AocError;
+use crate::parsing::labelled_values;
+use crate::solution::{Example, Solution};
 
 pub struct Day05 {}
 
-impl Day05 {
-    pub fn run() -> (String, String, String, u128, u128) {
-        let first_input = FileHandler::read("./src/Y2022/inputs/day_05_1.txt");
-
-        let lines: Vec<&str> = first_input.split('\n').collect();
-
-        let start_1 = Instant::now();
-        let result_1 = Day05::part_01(&lines);
-        let time_calculation_1 = start_1.elapsed();
-
-        let start_2 = Instant::now();
-        let result_2 = Day05::part_02(&lines);
-        let time_calculation_2 = start_2.elapsed();
-
-        (
-            "Day_05".to_owned(),
-            format!("{}", result_1),
-            format!("{}", result_2),
-            time_calculation_1.as_nanos(),
-            time_calculation_2.as_nanos(),
-        )
+impl Solution for Day05 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 5;
+
+    type Input<'a> = (HashMap<usize, Vec<String>>, Vec<(usize, usize, usize)>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let lines: Vec<&str> = input.split('\n').collect();
+        Day05::parsing_input(&lines)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day05::part_01(input).to_string())
     }
 
+    fn part_two(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day05::part_02(input).to_string())
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![
+            Example::new(1, TEST_INPUT, "CMZ"),
+            Example::new(2, TEST_INPUT, "MCD"),
+        ]
+    }
+}
+
+impl Day05 {
     /// The difficult part of this puzzle is parsing the input and separate into initial create stacks
     /// and crane moving commands after finishing parsing the input. The rest is very straight forward.
-    fn part_01(lines: &Vec<&str>) -> String {
-        let (mut stacks, commands) = Day05::parsing_input(lines);
+    fn part_01(input: &(HashMap<usize, Vec<String>>, Vec<(usize, usize, usize)>)) -> String {
+        let (stacks, commands) = input;
+        let mut stacks = stacks.clone();
 
         for command in commands.iter() {
             for _ in 0..(command.0) {
@@ -52,8 +58,9 @@ impl Day05 {
     }
 
     /// For the part 2, I have to modify function for moving one crate at a time into multiple crates.
-    fn part_02(lines: &Vec<&str>) -> String {
-        let (mut stacks, commands) = Day05::parsing_input(lines);
+    fn part_02(input: &(HashMap<usize, Vec<String>>, Vec<(usize, usize, usize)>)) -> String {
+        let (stacks, commands) = input;
+        let mut stacks = stacks.clone();
 
         for command in commands.iter() {
             Day05::move_crates(&mut stacks, command.0, command.1, command.2);
@@ -98,47 +105,77 @@ impl Day05 {
 
     fn parsing_input(
         lines: &Vec<&str>,
-    ) -> (HashMap<usize, Vec<String>>, Vec<(usize, usize, usize)>) {
-        let total_stacks = Day05::get_total_stacks(lines[0]);
-        let split_index = Day05::get_split_part_index(lines);
+    ) -> Result<(HashMap<usize, Vec<String>>, Vec<(usize, usize, usize)>), AocError> {
+        let split_index = Day05::get_split_part_index(lines).ok_or_else(|| {
+            AocError::parse(
+                lines.len() - 1,
+                lines[lines.len() - 1],
+                "missing the blank line between the stacks and the commands",
+            )
+        })?;
+        if split_index == 0 {
+            return Err(AocError::parse(0, lines[0], "missing the crate stacks"));
+        }
 
+        let total_stacks = Day05::get_total_stacks(lines[split_index - 1]);
         let stack_input: Vec<&str> = lines.clone().drain(..(split_index - 1)).collect();
 
         let mut stacks = HashMap::new();
+        let mut heights = vec![];
         for index in 0..total_stacks {
             let stack = Day05::get_stack(&stack_input, index);
+            heights.push(stack.len());
             stacks.insert(index + 1, stack);
         }
 
         let mut commands = vec![];
         let mut command_input = lines.clone();
         command_input.drain(..(split_index + 1));
-        for line in command_input.iter() {
-            let command = Day05::parsing_command(line);
+        for (index, line) in command_input.iter().enumerate() {
+            let command = Day05::parsing_command(line).ok_or_else(|| {
+                AocError::parse(
+                    split_index + 1 + index,
+                    line,
+                    "expected `move <count> from <stack> to <stack>`",
+                )
+            })?;
+
+            // Both parts move the same number of crates between the same stacks, so the
+            // heights only need to be followed once.
+            let (count, start, end) = command;
+            let invalid = |reason: String| AocError::parse(split_index + 1 + index, line, reason);
+            for stack in [start, end] {
+                if stack == 0 || stack > total_stacks {
+                    return Err(invalid(format!("there is no stack {}", stack)));
+                }
+            }
+            if heights[start - 1] < count {
+                return Err(invalid(format!(
+                    "stack {} only holds {} crates",
+                    start,
+                    heights[start - 1]
+                )));
+            }
+            heights[start - 1] -= count;
+            heights[end - 1] += count;
+
             commands.push(command);
         }
 
-        (stacks, commands)
+        Ok((stacks, commands))
     }
 
-    fn parsing_command(line: &str) -> (usize, usize, usize) {
-        let splited_parts: Vec<&str> = line.split(" from ").collect();
-        let total_crates = splited_parts[0]
-            .clone()
-            .replace("move ", "")
-            .parse::<usize>()
-            .unwrap();
-        let positions: Vec<&str> = splited_parts[1].clone().split(" to ").collect();
-        let start = positions[0].parse::<usize>().unwrap();
-        let end = positions[1].parse::<usize>().unwrap();
-        (total_crates, start, end)
+    fn parsing_command(line: &str) -> Option<(usize, usize, usize)> {
+        let values = labelled_values::<usize>(line, &["move ", " from ", " to "])?;
+        Some((values[0], values[1], values[2]))
     }
 
     fn get_stack(input: &Vec<&str>, stack_index: usize) -> Vec<String> {
         let mut result = vec![];
         let index = (stack_index * 4) + 1;
         for line in input.iter().rev() {
-            let letter = line.chars().nth(index).unwrap();
+            // Lines may have lost their trailing spaces.
+            let letter = line.chars().nth(index).unwrap_or(' ');
             if letter != ' ' {
                 result.push(String::from(letter));
             }
@@ -147,13 +184,14 @@ impl Day05 {
         result
     }
 
+    /// The stacks are counted from their numbers, ` 1   2   3 `.
     fn get_total_stacks(line: &str) -> usize {
-        (line.len() + 1) / 4
+        line.split_whitespace().count()
     }
 
-    fn get_split_part_index(lines: &Vec<&str>) -> usize {
-        let (index, _) = lines.iter().find_position(|line| line.len() == 0).unwrap();
-        index
+    fn get_split_part_index(lines: &Vec<&str>) -> Option<usize> {
+        let (index, _) = lines.iter().find_position(|line| line.len() == 0)?;
+        Some(index)
     }
 }
 
@@ -168,15 +206,45 @@ move 2 from 2 to 1
 move 1 from 1 to 2";
 
 #[test]
-fn test_part_1() {
-    let lines: Vec<&str> = TEST_INPUT.lines().collect();
-    let result = Day05::part_01(&lines);
-    assert_eq!(result, "CMZ");
+fn test_examples() {
+    crate::solution::assert_examples::<Day05>();
+}
+
+#[test]
+fn test_parsing_error() {
+    let input = TEST_INPUT.replace("move 3 from 1 to 3", "move three from 1 to 3");
+    let lines: Vec<&str> = input.lines().collect();
+    match Day05::parsing_input(&lines) {
+        Err(AocError::Parse { line, text, .. }) => {
+            assert_eq!(line, 7);
+            assert_eq!(text, "move three from 1 to 3");
+        }
+        _ => panic!("the command should not parse"),
+    }
+}
+
+#[test]
+fn test_parsing_invalid_move() {
+    let error = |input: &str| {
+        let lines: Vec<&str> = input.lines().collect();
+        Day05::parsing_input(&lines).unwrap_err().to_string()
+    };
+    assert_eq!(
+        error(&TEST_INPUT.replace("move 1 from 1 to 2", "move 1 from 1 to 4")),
+        "cannot parse line 9 `move 1 from 1 to 4`: there is no stack 4"
+    );
+    assert_eq!(
+        error(&TEST_INPUT.replace("move 3 from 1 to 3", "move 4 from 1 to 3")),
+        "cannot parse line 7 `move 4 from 1 to 3`: stack 1 only holds 3 crates"
+    );
+    assert_eq!(
+        error("\nmove 1 from 1 to 2"),
+        "cannot parse line 1 ``: missing the crate stacks"
+    );
 }
 
 #[test]
-fn test_part_2() {
-    let lines: Vec<&str> = TEST_INPUT.lines().collect();
-    let result = Day05::part_02(&lines);
-    assert_eq!(result, "MCD");
+fn test_get_stack_without_trailing_spaces() {
+    let lines = vec!["    [D]", "[N] [C]", "[Z] [M] [P]"];
+    assert_eq!(Day05::get_stack(&lines, 2), vec!["P".to_owned()]);
 }
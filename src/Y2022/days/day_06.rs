@@ -1,33 +1,36 @@
-use std::time::Instant;
-
 use itertools::Itertools;
 
-use crate::file_handler::FileHandler;
+use crate::error::AocError;
+use crate::solution::{Example, Solution};
 
 pub struct Day06 {}
 
-impl Day06 {
-    pub fn run() -> (String, String, String, u128, u128) {
-        let first_input = FileHandler::read("./src/Y2022/inputs/day_06_1.txt");
-
-        let lines: Vec<&str> = first_input.split('\n').collect();
-
-        let start_1 = Instant::now();
-        let result_1 = Day06::part_01(&lines);
-        let time_calculation_1 = start_1.elapsed();
-
-        let start_2 = Instant::now();
-        let result_2 = Day06::part_02(&lines);
-        let time_calculation_2 = start_2.elapsed();
-
-        (
-            "Day_06".to_owned(),
-            format!("{}", result_1),
-            format!("{}", result_2),
-            time_calculation_1.as_nanos(),
-            time_calculation_2.as_nanos(),
-        )
+impl Solution for Day06 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 6;
+
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Vec<&str>, AocError> {
+        Ok(input.split('\n').collect())
+    }
+
+    fn part_one(lines: &Vec<&str>) -> Result<String, AocError> {
+        Ok(Day06::part_01(lines).to_string())
+    }
+
+    fn part_two(lines: &Vec<&str>) -> Result<String, AocError> {
+        Ok(Day06::part_02(lines).to_string())
+    }
+
+    fn examples() -> Vec<Example> {
+        let part_1 = TEST_INPUT.map(|(input, expected)| Example::new(1, input, expected));
+        let part_2 = TEST_INPUT_2.map(|(input, expected)| Example::new(2, input, expected));
+        part_1.into_iter().chain(part_2).collect()
     }
+}
+
+impl Day06 {
     /// check common character on 4 chracters window on long string.
     fn part_01(lines: &Vec<&str>) -> i32 {
         let input = lines[0];
@@ -87,36 +90,23 @@ impl Day06 {
     }
 }
 
-const TEST_INPUT: [(&str, i32); 5] = [
-    ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 7),
-    ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5),
-    ("nppdvjthqldpwncqszvftbrmjlhg", 6),
-    ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10),
-    ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11),
+const TEST_INPUT: [(&str, &str); 5] = [
+    ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", "7"),
+    ("bvwbjplbgvbhsrlpgdmjqwftvncz", "5"),
+    ("nppdvjthqldpwncqszvftbrmjlhg", "6"),
+    ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", "10"),
+    ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", "11"),
 ];
 
-#[test]
-fn test_part_1() {
-    for (line, expected_result) in TEST_INPUT.iter() {
-        let lines: Vec<&str> = line.lines().collect();
-        let result = Day06::part_01(&lines);
-        assert_eq!(result, *expected_result);
-    }
-}
-
-const TEST_INPUT_2: [(&str, i32); 5] = [
-    ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 19),
-    ("bvwbjplbgvbhsrlpgdmjqwftvncz", 23),
-    ("nppdvjthqldpwncqszvftbrmjlhg", 23),
-    ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 29),
-    ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 26),
+const TEST_INPUT_2: [(&str, &str); 5] = [
+    ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", "19"),
+    ("bvwbjplbgvbhsrlpgdmjqwftvncz", "23"),
+    ("nppdvjthqldpwncqszvftbrmjlhg", "23"),
+    ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", "29"),
+    ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", "26"),
 ];
 
 #[test]
-fn test_part_2() {
-    for (line, expected_result) in TEST_INPUT_2.iter() {
-        let lines: Vec<&str> = line.lines().collect();
-        let result = Day06::part_02(&lines);
-        assert_eq!(result, *expected_result);
-    }
+fn test_examples() {
+    crate::solution::assert_examples::<Day06>();
 }
@@ -1,6 +1,7 @@
-use std::{fmt::format, time::Instant};
+use std::fmt::format;
 
-use crate::file_handler::FileHandler;
+use crate::error::AocError;
+use crate::solution::{Example, Solution};
 
 #[derive(Debug)]
 enum Commands {
@@ -13,36 +14,40 @@ enum Commands {
 
 pub struct Day07 {}
 
-impl Day07 {
-    pub fn run() -> (String, String, String, u128, u128) {
-        let first_input = FileHandler::read("./src/Y2022/inputs/day_07_1.txt");
-
-        let lines: Vec<&str> = first_input.split('\n').collect();
-
-        let start_1 = Instant::now();
-        let result_1 = Day07::part_01(&lines);
-        let time_calculation_1 = start_1.elapsed();
-
-        let start_2 = Instant::now();
-        let result_2 = Day07::part_02(&lines);
-        let time_calculation_2 = start_2.elapsed();
-
-        (
-            "Day_07".to_owned(),
-            format!("{}", result_1),
-            format!("{}", result_2),
-            time_calculation_1.as_nanos(),
-            time_calculation_2.as_nanos(),
-        )
+impl Solution for Day07 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 7;
+
+    type Input<'a> = (Vec<(String, usize, String)>, Vec<(String, String)>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let lines: Vec<&str> = input.split('\n').collect();
+        Ok(construct(&parsing_input(&lines)?))
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day07::part_01(input).to_string())
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day07::part_02(input).to_string())
     }
 
+    fn examples() -> Vec<Example> {
+        vec![
+            Example::new(1, TEST_INPUT, "95437"),
+            Example::new(2, TEST_INPUT, "24933642"),
+        ]
+    }
+}
+
+impl Day07 {
     /// After I read the puzzle, I struggle with what kind of data structure to choose.
     /// I end up with two lists for directories and files. Furthermore, I use an iterator to look for the directory size
     /// from the file that has parents containing the directory name. If it contains,
     /// then this file should be children of the directory.
-    fn part_01(lines: &Vec<&str>) -> i32 {
-        let commands = self::parsing_input(lines);
-        let (files, directories) = self::construct(&commands);
+    fn part_01(input: &(Vec<(String, usize, String)>, Vec<(String, String)>)) -> i32 {
+        let (files, directories) = input;
         let maximum_directory_size = 100000;
         let mut total_size = 0;
         for (parent, name) in directories.iter() {
@@ -56,11 +61,10 @@ impl Day07 {
     }
 
     /// Part 2 is relatively easy after part 1. I need to look at only the most minor directory size bigger than the threshold value.
-    fn part_02(lines: &Vec<&str>) -> i32 {
+    fn part_02(input: &(Vec<(String, usize, String)>, Vec<(String, String)>)) -> i32 {
         let maximum_disk_space = 70000000;
         let required_disk_space = 30000000;
-        let commands = self::parsing_input(lines);
-        let (files, directories) = self::construct(&commands);
+        let (files, directories) = input;
         let used_space = {
             let file_parent_name =
                 self::construct_file_parent_name(&"".to_owned(), &"/".to_owned());
@@ -139,9 +143,9 @@ fn get_parent_name(stacks: &Vec<String>) -> String {
     result
 }
 
-fn parsing_input(lines: &Vec<&str>) -> Vec<self::Commands> {
+fn parsing_input(lines: &Vec<&str>) -> Result<Vec<self::Commands>, AocError> {
     let mut result = vec![];
-    for line in lines.iter() {
+    for (index, line) in lines.iter().enumerate() {
         if line.contains("$ cd ..") {
             result.push(self::Commands::MOVE_OUT);
         } else if line.contains("$ cd ") {
@@ -153,14 +157,14 @@ fn parsing_input(lines: &Vec<&str>) -> Vec<self::Commands> {
             let name = line.to_owned().replace("dir ", "");
             result.push(self::Commands::DIRECTORY(name));
         } else {
-            let values = line.split(" ").collect::<Vec<&str>>();
-            let file_size = values[0].parse::<usize>().unwrap();
-            let filename = values[1].to_owned();
-            result.push(self::Commands::FILE(file_size, filename));
+            let invalid = || AocError::parse(index, line, "expected `<size> <name>`");
+            let (file_size, filename) = line.split_once(" ").ok_or_else(invalid)?;
+            let file_size = file_size.parse::<usize>().map_err(|_| invalid())?;
+            result.push(self::Commands::FILE(file_size, filename.to_owned()));
         }
     }
 
-    result
+    Ok(result)
 }
 
 const TEST_INPUT: &str = "$ cd /
@@ -188,15 +192,6 @@ $ ls
 7214296 k";
 
 #[test]
-fn test_part_1() {
-    let lines: Vec<&str> = TEST_INPUT.lines().collect();
-    let result = Day07::part_01(&lines);
-    assert_eq!(result, 95437);
-}
-
-#[test]
-fn test_part_2() {
-    let lines: Vec<&str> = TEST_INPUT.lines().collect();
-    let result = Day07::part_02(&lines);
-    assert_eq!(result, 24933642);
+fn test_examples() {
+    crate::solution::assert_examples::<Day07>();
 }
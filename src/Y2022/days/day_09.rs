@@ -1,12 +1,11 @@
-use std::time::Instant;
-
 use hashbrown::HashSet;
 use itertools::Itertools;
 
-use crate::file_handler::FileHandler;
+use crate::error::AocError;
+use crate::solution::{Example, Solution};
 
 #[derive(Clone, Copy, Debug)]
-enum Commands {
+pub enum Commands {
     Up(usize),
     Down(usize),
     Left(usize),
@@ -87,37 +86,41 @@ impl Knot {
 
 pub struct Day09 {}
 
-impl Day09 {
-    pub fn run() -> (String, String, String, u128, u128) {
-        let first_input = FileHandler::read("./src/Y2022/inputs/day_09_1.txt");
-
-        let lines: Vec<&str> = first_input.split('\n').collect();
-
-        let start_1 = Instant::now();
-        let result_1 = Day09::part_01(&lines);
-        let time_calculation_1 = start_1.elapsed();
-
-        let start_2 = Instant::now();
-        let result_2 = Day09::part_02(&lines);
-        let time_calculation_2 = start_2.elapsed();
-
-        (
-            "Day_09".to_owned(),
-            format!("{}", result_1),
-            format!("{}", result_2),
-            time_calculation_1.as_nanos(),
-            time_calculation_2.as_nanos(),
-        )
+impl Solution for Day09 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 9;
+
+    type Input<'a> = Vec<Commands>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let lines: Vec<&str> = input.split('\n').collect();
+        parsing_input(&lines)
     }
 
+    fn part_one(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day09::part_01(input).to_string())
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day09::part_02(input).to_string())
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![
+            Example::new(1, TEST_INPUT, "13"),
+            Example::new(2, TEST_INPUT_2, "36"),
+        ]
+    }
+}
+
+impl Day09 {
     /// Part 1 took me some time to get the correct result. Furthermore, the issue is that I start implementing the code
     /// before ensuring I understand what I need to do correctly. Also, The logic of the movement of the rope is basic.
-    fn part_01(lines: &Vec<&str>) -> i32 {
+    fn part_01(commands: &Vec<Commands>) -> i32 {
         let mut head = Knot::zero(false);
         let mut tail = vec![Knot::zero(true)];
-        let commands = parsing_input(lines);
 
-        execute_all_commands_with_multiple_knots(&commands, &mut head, &mut tail);
+        execute_all_commands_with_multiple_knots(commands, &mut head, &mut tail);
         return tail.last().unwrap().total_visited();
     }
 
@@ -126,12 +129,11 @@ impl Day09 {
     /// because I did not understand it correctly and kept coding the incorrect logic.
     ///Also, I have to tidy up the code to make it run faster and look ok. The issue of the slows is because
     /// I calculate distance using sqrt() and log all the steps of all knots instead of the last only knot.
-    fn part_02(lines: &Vec<&str>) -> i32 {
+    fn part_02(commands: &Vec<Commands>) -> i32 {
         let mut head = Knot::zero(false);
         let mut tail = (0..9).map(|index| Knot::zero(index == 8)).collect_vec();
-        let commands = parsing_input(lines);
 
-        execute_all_commands_with_multiple_knots(&commands, &mut head, &mut tail);
+        execute_all_commands_with_multiple_knots(commands, &mut head, &mut tail);
 
         return tail.last().unwrap().total_visited();
     }
@@ -147,10 +149,8 @@ L 5
 R 2";
 
 #[test]
-fn test_part_1() {
-    let lines: Vec<&str> = TEST_INPUT.lines().collect();
-    let result = Day09::part_01(&lines);
-    assert_eq!(result, 13);
+fn test_examples() {
+    crate::solution::assert_examples::<Day09>();
 }
 
 const TEST_INPUT_2: &str = "R 5
@@ -162,35 +162,24 @@ D 10
 L 25
 U 20";
 
-#[test]
-fn test_part_2() {
-    let lines: Vec<&str> = TEST_INPUT_2.lines().collect();
-    let result = Day09::part_02(&lines);
-    assert_eq!(result, 36);
-}
-
 /// I have learnt how to use split_once(). :)
-fn parsing_input(lines: &Vec<&str>) -> Vec<Commands> {
+fn parsing_input(lines: &Vec<&str>) -> Result<Vec<Commands>, AocError> {
     lines
         .iter()
-        .map(|line| {
-            let input = (*line).to_owned();
-            let (command, value) = input.split_once(" ").unwrap();
-            let value = value.parse::<usize>().unwrap();
-            let command = command.to_owned();
-            if command == "U" {
-                Commands::Up(value)
-            } else if command == "D" {
-                Commands::Down(value)
-            } else if command == "L" {
-                Commands::Left(value)
-            } else if command == "R" {
-                Commands::Right(value)
-            } else {
-                Commands::Up(0)
+        .enumerate()
+        .map(|(index, line)| {
+            let invalid = || AocError::parse(index, line, "expected `<U|D|L|R> <steps>`");
+            let (command, value) = line.split_once(" ").ok_or_else(invalid)?;
+            let value = value.parse::<usize>().map_err(|_| invalid())?;
+            match command {
+                "U" => Ok(Commands::Up(value)),
+                "D" => Ok(Commands::Down(value)),
+                "L" => Ok(Commands::Left(value)),
+                "R" => Ok(Commands::Right(value)),
+                _ => Err(invalid()),
             }
         })
-        .collect_vec()
+        .collect()
 }
 
 fn execute_all_commands_with_multiple_knots(
@@ -1,42 +1,45 @@
-use std::time::Instant;
+use crate::error::AocError;
+use crate::solution::{Example, Solution};
 
-use crate::file_handler::FileHandler;
-
-enum Instructions {
+pub enum Instructions {
     ADDX(i32, usize),
     NOOP,
 }
 pub struct Day10 {}
 
-impl Day10 {
-    pub fn run() -> (String, String, String, u128, u128) {
-        let first_input = FileHandler::read("./src/Y2022/inputs/day_10_1.txt");
-
-        let lines: Vec<&str> = first_input.split('\n').collect();
-
-        let start_1 = Instant::now();
-        let result_1 = Day10::part_01(&lines);
-        let time_calculation_1 = start_1.elapsed();
-
-        let start_2 = Instant::now();
-        let result_2 = Day10::part_02(&lines);
-        let time_calculation_2 = start_2.elapsed();
-
-        (
-            "Day_10".to_owned(),
-            format!("{}", result_1),
-            format!("{}", result_2),
-            time_calculation_1.as_nanos(),
-            time_calculation_2.as_nanos(),
-        )
+impl Solution for Day10 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 10;
+
+    type Input<'a> = Vec<Instructions>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let lines: Vec<&str> = input.split('\n').collect();
+        parsing_input(&lines)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day10::part_01(input).to_string())
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day10::part_02(input))
     }
 
+    fn examples() -> Vec<Example> {
+        vec![
+            Example::new(1, TEST_INPUT, "13140"),
+            Example::new(2, TEST_INPUT, TEST_SCREEN),
+        ]
+    }
+}
+
+impl Day10 {
     /// This part should been easy,
     /// but It took me much time to get the result. I felt like today's puzzle was playing with a word to trick me.
     /// So, I got the correct result after I read it carefully.
-    fn part_01(lines: &Vec<&str>) -> i32 {
-        let instructions = parsing_input(&lines);
-        let (_, registered_values) = process(&instructions);
+    fn part_01(instructions: &Vec<Instructions>) -> i32 {
+        let (_, registered_values) = process(instructions);
 
         return registered_values.iter().take(registered_values.len()).sum();
     }
@@ -44,11 +47,9 @@ impl Day10 {
     /// For part 2, while I implemented the code, The result of the example input did not match exactly with the example after struggling with it for some time.
     /// I desperately tried with my puzzle input and got the correct result. :)
     /// After I rechecked the code again, I found out that I messed up when converting the cycle's value and sprite position.
-    fn part_02(lines: &Vec<&str>) -> i32 {
-        let instructions = parsing_input(&lines);
-        println!("day_10_part2:\n{}\n", rendering(&instructions));
-
-        return 0;
+    /// The answer is the screen itself, its letters are read by eye.
+    fn part_02(instructions: &Vec<Instructions>) -> String {
+        rendering(instructions).trim_end().to_owned()
     }
 }
 
@@ -144,21 +145,23 @@ fn is_cycle_registered(cycle: usize) -> bool {
     registered_cycles.contains(&cycle)
 }
 
-fn parsing_input(lines: &Vec<&str>) -> Vec<Instructions> {
+fn parsing_input(lines: &Vec<&str>) -> Result<Vec<Instructions>, AocError> {
     let mut result = vec![];
-    for line in lines.iter() {
+    for (index, line) in lines.iter().enumerate() {
         let splited: Vec<&str> = line.split(" ").collect();
-        if splited[0] == "addx" {
-            let value = splited[1].parse::<i32>().unwrap();
-            result.push(Instructions::ADDX(value, 2))
-        } else if splited[0] == "noop" {
-            result.push(Instructions::NOOP);
-        } else {
-            panic!("Unknown instruction appear");
+        match splited[..] {
+            ["addx", value] => {
+                let value = value.parse::<i32>().map_err(|_| {
+                    AocError::parse(index, line, format!("invalid value `{}`", value))
+                })?;
+                result.push(Instructions::ADDX(value, 2))
+            }
+            ["noop"] => result.push(Instructions::NOOP),
+            _ => return Err(AocError::parse(index, line, "unknown instruction")),
         }
     }
 
-    result
+    Ok(result)
 }
 
 const TEST_PROCESS: &str = "noop
@@ -168,7 +171,7 @@ addx -5";
 #[test]
 fn test_processing() {
     let lines: Vec<&str> = TEST_PROCESS.lines().collect();
-    let instructions = parsing_input(&lines);
+    let instructions = parsing_input(&lines).unwrap();
     let (result, _) = process(&instructions);
     assert_eq!(result, -1);
 }
@@ -320,17 +323,22 @@ noop
 noop
 noop";
 
+const TEST_SCREEN: &str = "##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....";
+
 #[test]
-fn test_part_1() {
-    let lines: Vec<&str> = TEST_INPUT.lines().collect();
-    let result = Day10::part_01(&lines);
-    assert_eq!(result, 13140);
+fn test_examples() {
+    crate::solution::assert_examples::<Day10>();
 }
 
 #[test]
 fn test_part_2() {
     let lines: Vec<&str> = TEST_INPUT.lines().collect();
-    let instructions = parsing_input(&lines);
+    let instructions = parsing_input(&lines).unwrap();
     let expected_result = "##..##..##..##..##..##..##..##..##..##..\n###...###...###...###...###...###...###.\n####....####....####....####....####....\n#####.....#####.....#####.....#####.....\n######......######......######......####\n#######.......#######.......#######.....\n";
     let result = rendering(&instructions);
     println!("Canvas:\n{}\nexpected:\n{}\n\n", result, expected_result);
@@ -1,8 +1,7 @@
-use std::time::Instant;
-
 use itertools::Itertools;
 
-use crate::file_handler::FileHandler;
+use crate::error::AocError;
+use crate::solution::{Example, Solution};
 
 #[derive(Debug, Clone)]
 enum Operations {
@@ -19,7 +18,7 @@ enum Operations {
 //   If false: throw to monkey 3
 
 #[derive(Debug, Clone)]
-struct Monkey {
+pub struct Monkey {
     name: String,
     items: Vec<u128>,
     test_divisible: u128,
@@ -30,56 +29,55 @@ struct Monkey {
 }
 
 impl Monkey {
-    fn from(lines: &Vec<&str>) -> Self {
-        let name = (lines[0].split(":").collect::<Vec<&str>>())[0]
+    /// Reads the six lines of a monkey, `first_index` being the index of its first line in
+    /// the input.
+    fn from(lines: &Vec<&str>, first_index: usize) -> Result<Self, AocError> {
+        let line = |offset: usize, label: &str| {
+            let text = lines.get(offset).copied().unwrap_or_default();
+            text.trim()
+                .strip_prefix(label)
+                .map(|value| value.trim())
+                .ok_or_else(|| {
+                    AocError::parse(first_index + offset, text, format!("expected `{}`", label))
+                })
+        };
+        let number = |offset: usize, label: &str| {
+            let value = line(offset, label)?;
+            value.parse::<u128>().map_err(|_| {
+                AocError::parse(
+                    first_index + offset,
+                    lines[offset],
+                    format!("invalid number `{}`", value),
+                )
+            })
+        };
+
+        let name = line(0, "Monkey ")?
+            .trim_end_matches(":")
             .to_owned()
             .to_lowercase();
-        let items: Vec<u128> = {
-            let item_input = lines[1].replace("Starting items:", "").replace(" ", "");
-            item_input
-                .split(",")
-                .collect::<Vec<&str>>()
-                .iter()
-                .map(|v| v.parse::<u128>().unwrap())
-                .collect_vec()
-        };
+        let items: Vec<u128> = line(1, "Starting items:")?
+            .split(",")
+            .map(|v| v.trim().parse::<u128>())
+            .collect::<Result<Vec<u128>, _>>()
+            .map_err(|_| AocError::parse(first_index + 1, lines[1], "invalid item"))?;
         let operation = {
-            let input = (lines[2]
-                .split("Operation: new = old ")
-                .collect::<Vec<&str>>())[1]
-                .clone();
-            let (operand, value) = input.split_once(" ").unwrap();
-            match operand {
-                "*" => match value.parse::<u128>() {
-                    Ok(v) => Operations::Multiply(v),
-                    _ => Operations::MultiplyItSelf,
-                },
-                "+" => Operations::Add(value.parse::<u128>().unwrap()),
-                _ => {
-                    panic!("Unknown logic: {}", lines[2]);
-                }
+            let input = line(2, "Operation: new = old ")?;
+            let invalid = || AocError::parse(first_index + 2, lines[2], "unknown operation");
+            let (operand, value) = input.split_once(" ").ok_or_else(invalid)?;
+            match (operand, value.parse::<u128>()) {
+                ("*", Ok(v)) => Operations::Multiply(v),
+                ("*", _) if value == "old" => Operations::MultiplyItSelf,
+                ("+", Ok(v)) => Operations::Add(v),
+                _ => return Err(invalid()),
             }
         };
 
-        let test_divisible = (lines[3].split("Test: divisible by ").collect::<Vec<&str>>())[1]
-            .parse::<u128>()
-            .unwrap();
-        let first_throw = lines[4]
-            .split("If true: throw to monkey ")
-            .collect::<Vec<&str>>()
-            .last()
-            .unwrap()
-            .parse::<usize>()
-            .unwrap();
-        let second_throw = lines[5]
-            .split("If false: throw to monkey ")
-            .collect::<Vec<&str>>()
-            .last()
-            .unwrap()
-            .parse::<usize>()
-            .unwrap();
-
-        Self {
+        let test_divisible = number(3, "Test: divisible by ")?;
+        let first_throw = number(4, "If true: throw to monkey ")? as usize;
+        let second_throw = number(5, "If false: throw to monkey ")? as usize;
+
+        Ok(Self {
             name: name,
             items: items,
             test_divisible: test_divisible,
@@ -87,7 +85,7 @@ impl Monkey {
             true_throw: first_throw.to_owned(),
             false_throw: second_throw.to_owned(),
             total_inspected: 0,
-        }
+        })
     }
 
     fn inspect(&mut self) -> Vec<(usize, u128)> {
@@ -152,33 +150,38 @@ impl Monkey {
 
 pub struct Day11 {}
 
-impl Day11 {
-    pub fn run() -> (String, String, String, u128, u128) {
-        let first_input = FileHandler::read("./src/Y2022/inputs/day_11_1.txt");
-
-        let lines: Vec<&str> = first_input.split('\n').collect();
-
-        let start_1 = Instant::now();
-        let result_1 = Day11::part_01(&lines);
-        let time_calculation_1 = start_1.elapsed();
-
-        let start_2 = Instant::now();
-        let result_2 = Day11::part_02(&lines);
-        let time_calculation_2 = start_2.elapsed();
-
-        (
-            "Day_11".to_owned(),
-            format!("{}", result_1),
-            format!("{}", result_2),
-            time_calculation_1.as_nanos(),
-            time_calculation_2.as_nanos(),
-        )
+impl Solution for Day11 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 11;
+
+    type Input<'a> = Vec<Monkey>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let lines: Vec<&str> = input.split('\n').collect();
+        parse_input(&lines)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day11::part_01(input).to_string())
     }
 
+    fn part_two(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day11::part_02(input).to_string())
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![
+            Example::new(1, TEST_INPUT, "10605"),
+            Example::new(2, TEST_INPUT, "2713310158"),
+        ]
+    }
+}
+
+impl Day11 {
     /// For part 1, It took me almost an hour to finish implementing the parsing input code.
     /// I create a struct of Monkey to keep the data and processing. Everything was easy after that.
-    fn part_01(lines: &Vec<&str>) -> u128 {
-        let mut monkeys = parse_input(lines);
+    fn part_01(monkeys: &Vec<Monkey>) -> u128 {
+        let mut monkeys = monkeys.clone();
 
         for _ in 1..=20 {
             round(&mut monkeys);
@@ -199,8 +202,8 @@ impl Day11 {
 
     /// I asked for the hint for this part, and Someone told me about modular.
     /// I try with a common modular value from all monkeys, and it works. :)
-    fn part_02(lines: &Vec<&str>) -> u128 {
-        let mut monkeys = parse_input(lines);
+    fn part_02(monkeys: &Vec<Monkey>) -> u128 {
+        let mut monkeys = monkeys.clone();
 
         let common_modular: u128 = monkeys.iter().map(|m| m.test_divisible).product();
 
@@ -285,34 +288,38 @@ Test: divisible by 17
   If false: throw to monkey 1";
 
 #[test]
-fn test_part_1() {
-    let lines: Vec<&str> = TEST_INPUT.lines().collect();
-    let result = Day11::part_01(&lines);
-
-    assert_eq!(result, 10605);
-}
-
-#[test]
-fn test_part_2() {
-    let lines: Vec<&str> = TEST_INPUT.lines().collect();
-    let result = Day11::part_02(&lines);
-
-    assert_eq!(result, 2713310158);
+fn test_examples() {
+    crate::solution::assert_examples::<Day11>();
 }
 
-fn parse_input(lines: &Vec<&str>) -> Vec<Monkey> {
+fn parse_input(lines: &Vec<&str>) -> Result<Vec<Monkey>, AocError> {
     let mut group = vec![];
+    let mut first_index = 0;
     let mut monkeys = vec![];
-    for line in lines.iter() {
+    for (index, line) in lines.iter().enumerate() {
         if line.len() == 0 {
-            monkeys.push(Monkey::from(&group));
+            monkeys.push(Monkey::from(&group, first_index)?);
             group.clear();
+            first_index = index + 1;
         } else {
             group.push(line.to_owned());
         }
     }
 
-    monkeys.push(Monkey::from(&group));
+    monkeys.push(Monkey::from(&group, first_index)?);
 
-    monkeys
+    Ok(monkeys)
+}
+
+#[test]
+fn test_parse_input_error() {
+    let lines: Vec<&str> = TEST_INPUT.lines().collect();
+    assert_eq!(parse_input(&lines).unwrap().len(), 4);
+
+    let input = TEST_INPUT.replace("new = old * 19", "new = old / 19");
+    let lines: Vec<&str> = input.lines().collect();
+    assert_eq!(
+        parse_input(&lines).unwrap_err().to_string(),
+        "cannot parse line 3 `Operation: new = old / 19`: unknown operation"
+    );
 }
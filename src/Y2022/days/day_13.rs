@@ -1,39 +1,44 @@
-use std::{collections::VecDeque, time::Instant};
+use std::collections::VecDeque;
 
 use hashbrown::HashMap;
 use itertools::Itertools;
 
-use crate::file_handler::FileHandler;
+use crate::error::AocError;
+use crate::solution::{Example, Solution};
 
 pub struct Day13 {}
 
-impl Day13 {
-    pub fn run() -> (String, String, String, u128, u128) {
-        let first_input = FileHandler::read("./src/Y2022/inputs/day_13_1.txt");
-
-        let lines: Vec<&str> = first_input.split('\n').collect();
-
-        let start_1 = Instant::now();
-        let result_1 = Day13::part_01(&lines);
-        let time_calculation_1 = start_1.elapsed();
-
-        let start_2 = Instant::now();
-        let result_2 = Day13::part_02(&lines);
-        let time_calculation_2 = start_2.elapsed();
-
-        (
-            "Day_13".to_owned(),
-            format!("{}", result_1),
-            format!("{}", result_2),
-            time_calculation_1.as_nanos(),
-            time_calculation_2.as_nanos(),
-        )
+impl Solution for Day13 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 13;
+
+    type Input<'a> = Vec<VecDeque<String>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let lines: Vec<&str> = input.split('\n').collect();
+        parsing(&lines)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day13::part_01(input).to_string())
     }
 
+    fn part_two(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day13::part_02(input).to_string())
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![
+            Example::new(1, TEST_INPUT, "13"),
+            Example::new(2, TEST_INPUT, "140"),
+        ]
+    }
+}
+
+impl Day13 {
     /// Part 1, I took a very long time to implement parsing and support nesting of the list.
     /// Also, I still get confused with the detail of the puzzle again.
-    fn part_01(lines: &Vec<&str>) -> usize {
-        let packets = parsing(lines);
+    fn part_01(packets: &Vec<VecDeque<String>>) -> usize {
         let packets = packets
             .iter()
             .map(|p| converting_raw_data(p.clone()))
@@ -44,8 +49,8 @@ impl Day13 {
 
     /// Part 2, After reading the detail, I learned how to implement it when someone guided me that sorting is the key.
     /// I used the function that compares two packets as a condition in sorting.
-    fn part_02(lines: &Vec<&str>) -> usize {
-        let mut packets = parsing(lines);
+    fn part_02(packets: &Vec<VecDeque<String>>) -> usize {
+        let mut packets = packets.clone();
         packets.push(VecDeque::from([
             "[".to_owned(),
             "[".to_owned(),
@@ -100,17 +105,8 @@ const TEST_INPUT: &str = "[1,1,3,1,1]
 [1,[2,[3,[4,[5,6,0]]]],8,9]";
 
 #[test]
-fn test_part_1() {
-    let lines: Vec<&str> = TEST_INPUT.lines().collect();
-    let result = Day13::part_01(&lines);
-    assert_eq!(result, 13);
-}
-
-#[test]
-fn test_part_2() {
-    let lines: Vec<&str> = TEST_INPUT.lines().collect();
-    let result = Day13::part_02(&lines);
-    assert_eq!(result, 140);
+fn test_examples() {
+    crate::solution::assert_examples::<Day13>();
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -299,15 +295,17 @@ fn find_closed_list_index(packet: &VecDeque<String>, start_index: usize) -> usiz
     panic!("find_group_range: something wrong");
 }
 
-fn parsing(lines: &Vec<&str>) -> Vec<VecDeque<String>> {
+fn parsing(lines: &Vec<&str>) -> Result<Vec<VecDeque<String>>, AocError> {
     let mut result = vec![];
-    for line in lines.iter() {
+    for (index, line) in lines.iter().enumerate() {
         if line.len() > 0 {
             let splited: Vec<char> = line.chars().map(|c| c.to_owned()).collect_vec();
             let mut packet = VecDeque::new();
             let mut temp = String::new();
+            let mut depth = 0;
             for c in splited.iter() {
                 if *c == '[' {
+                    depth += 1;
                     packet.push_back(c.to_string());
                 } else if *c == ',' || *c == ']' {
                     if temp.len() > 0 {
@@ -316,16 +314,31 @@ fn parsing(lines: &Vec<&str>) -> Vec<VecDeque<String>> {
                     }
 
                     if *c == ']' {
+                        depth -= 1;
                         packet.push_back(c.to_string());
                     }
-                } else {
+                } else if c.is_ascii_digit() {
                     temp.push_str(c.to_string().as_str());
+                } else {
+                    return Err(AocError::parse(
+                        index,
+                        line,
+                        format!("unexpected character `{}`", c),
+                    ));
+                }
+
+                if depth < 0 {
+                    return Err(AocError::parse(index, line, "unbalanced brackets"));
                 }
             }
 
+            if depth != 0 || !temp.is_empty() || !line.starts_with('[') {
+                return Err(AocError::parse(index, line, "expected a list"));
+            }
+
             result.push(packet)
         }
     }
 
-    result
+    Ok(result)
 }
@@ -1,41 +1,45 @@
-use std::{borrow::Borrow, time::Instant};
+use std::borrow::Borrow;
 
 use hashbrown::HashMap;
 use itertools::Itertools;
 
-use crate::file_handler::FileHandler;
+use crate::error::AocError;
+use crate::solution::{Example, Solution};
 
 pub struct Day14 {}
 
-impl Day14 {
-    pub fn run() -> (String, String, String, u128, u128) {
-        let first_input = FileHandler::read("./src/Y2022/inputs/day_14_1.txt");
-
-        let lines: Vec<&str> = first_input.split('\n').collect();
-
-        let start_1 = Instant::now();
-        let result_1 = Day14::part_01(&lines);
-        let time_calculation_1 = start_1.elapsed();
-
-        let start_2 = Instant::now();
-        let result_2 = Day14::part_02(&lines);
-        let time_calculation_2 = start_2.elapsed();
-
-        (
-            "Day_14".to_owned(),
-            format!("{}", result_1),
-            format!("{}", result_2),
-            time_calculation_1.as_nanos(),
-            time_calculation_2.as_nanos(),
-        )
+impl Solution for Day14 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 14;
+
+    type Input<'a> = Vec<Vec<(i32, i32)>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let lines: Vec<&str> = input.split('\n').collect();
+        parsing(&lines)
     }
 
-    /// Today's puzzle is fun. It is a sand-falling simulation. It is one of the games that I would like to make one day.
-    fn part_01(lines: &Vec<&str>) -> usize {
-        let scan_input = parsing(lines);
+    fn part_one(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day14::part_01(input).to_string())
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day14::part_02(input).to_string())
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![
+            Example::new(1, TEST_INPUT, "24"),
+            Example::new(2, TEST_INPUT, "93"),
+        ]
+    }
+}
 
+impl Day14 {
+    /// Today's puzzle is fun. It is a sand-falling simulation. It is one of the games that I would like to make one day.
+    fn part_01(scan_input: &Vec<Vec<(i32, i32)>>) -> usize {
         let mut cave = Cave::new(1);
-        cave.apply_scan(&scan_input);
+        cave.apply_scan(scan_input);
         cave.set_sand_pouring(&(500, 0));
 
         loop {
@@ -49,11 +53,9 @@ impl Day14 {
         return cave.counting_sand();
     }
 
-    fn part_02(lines: &Vec<&str>) -> usize {
-        let scan_input = parsing(lines);
-
+    fn part_02(scan_input: &Vec<Vec<(i32, i32)>>) -> usize {
         let mut cave = Cave::new(1);
-        cave.apply_scan(&scan_input);
+        cave.apply_scan(scan_input);
         cave.set_sand_pouring(&(500, 0));
 
         loop {
@@ -72,17 +74,8 @@ const TEST_INPUT: &str = "498,4 -> 498,6 -> 496,6
 503,4 -> 502,4 -> 502,9 -> 494,9";
 
 #[test]
-fn test_part_1() {
-    let lines: Vec<&str> = TEST_INPUT.lines().collect();
-    let result = Day14::part_01(&lines);
-    assert_eq!(result, 24);
-}
-
-#[test]
-fn test_part_2() {
-    let lines: Vec<&str> = TEST_INPUT.lines().collect();
-    let result = Day14::part_02(&lines);
-    assert_eq!(result, 93);
+fn test_examples() {
+    crate::solution::assert_examples::<Day14>();
 }
 
 #[derive(Clone, Debug)]
@@ -336,20 +329,22 @@ impl Cave {
     }
 }
 
-fn parsing(lines: &Vec<&str>) -> Vec<Vec<(i32, i32)>> {
+fn parsing(lines: &Vec<&str>) -> Result<Vec<Vec<(i32, i32)>>, AocError> {
     let mut result = vec![];
-    for line in lines.iter() {
+    for (index, line) in lines.iter().enumerate() {
         let no_space = line.replace(" ", "");
         let splited: Vec<&str> = no_space.split("->").collect();
         let path = splited
             .iter()
             .map(|v| {
-                let (x, y) = (*v).split_once(",").unwrap();
-                (x.parse::<i32>().unwrap(), y.parse::<i32>().unwrap())
+                let point = (*v)
+                    .split_once(",")
+                    .and_then(|(x, y)| Some((x.parse::<i32>().ok()?, y.parse::<i32>().ok()?)));
+                point.ok_or_else(|| AocError::parse(index, line, format!("invalid point `{}`", v)))
             })
-            .collect_vec();
+            .collect::<Result<Vec<(i32, i32)>, AocError>>()?;
 
         result.push(path);
     }
-    result
+    Ok(result)
 }
@@ -1,76 +1,81 @@
-use std::{collections::HashSet, hash::Hash, time::Instant};
+use std::{collections::HashSet, hash::Hash};
 
 use hashbrown::HashMap;
 
-use crate::file_handler::FileHandler;
+use crate::error::AocError;
+use crate::parsing::labelled_values;
+use crate::solution::{configure_each, unknown_parameter, Example, Solution};
 
 pub struct Day15 {}
 
-impl Day15 {
-    pub fn run() -> (String, String, String, u128, u128) {
-        let first_input = FileHandler::read("./src/Y2022/inputs/day_15_1.txt");
-
-        let lines: Vec<&str> = first_input.split('\n').collect();
-
-        let start_1 = Instant::now();
-        let result_1 = Day15::part_01(&lines, 2000000);
-        let time_calculation_1 = start_1.elapsed();
-
-        let start_2 = Instant::now();
-        let result_2 = Day15::part_02(&lines);
-        let time_calculation_2 = start_2.elapsed();
-
-        (
-            "Day_15".to_owned(),
-            format!("{}", result_1),
-            format!("{}", result_2),
-            time_calculation_1.as_nanos(),
-            time_calculation_2.as_nanos(),
-        )
+impl Solution for Day15 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 15;
+
+    /// The area and the row part one looks at.
+    type Input<'a> = (Area, i32);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let lines: Vec<&str> = input.split('\n').collect();
+        Ok((build_area(&lines)?, AT_Y))
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<String, AocError> {
+        let (area, at_y) = input;
+        Ok(Day15::part_01(area, *at_y).to_string())
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<String, AocError> {
+        let (area, _) = input;
+        Ok(Day15::part_02(area)?.to_string())
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![
+            Example::new(1, TEST_INPUT, "26").with("at_y", 10),
+            Example::new(2, TEST_INPUT, "56000011"),
+        ]
     }
 
+    fn configure(input: &mut Self::Input<'_>, params: &[(&str, i64)]) -> Result<(), AocError> {
+        configure_each(params, |name, value| {
+            match name {
+                "at_y" => input.1 = value as i32,
+                _ => return Err(unknown_parameter(name)),
+            }
+
+            Ok(())
+        })
+    }
+}
+
+const AT_Y: i32 = 2000000;
+
+impl Day15 {
     /// I start with the dumb version that puts all the positions scanned by sensors in the HashSet.
     /// It can return the correct result for the example input, but it took ages to get a result from the actual input.
     /// So, I have to revise the code to improve the performance. (Use range instead of putting everything in HashSet)
-    fn part_01(lines: &Vec<&str>, at_y: i32) -> usize {
-        let data = parsing(lines);
-        let mut area = Area::new();
-        for (sensor, beacon) in data.iter() {
-            let node = Sensor::new(sensor, beacon);
-            area.add_sensor(&node);
-        }
-
-        area.mapping_scanned_area();
-
+    fn part_01(area: &Area, at_y: i32) -> usize {
         return area.count_unavailable_place_at(at_y);
     }
 
     /// Part 2, I tried to look into the rust document about combining multiple ranges and finding a spot inside,
     /// and I could not. Finally, I write a function that will start from the maximum range possible in the row
     /// and trim it down using the range of sensors until it has one spot inside or no spot.
-    fn part_02(lines: &Vec<&str>) -> u128 {
-        let data = parsing(lines);
-        let mut area = Area::new();
-        for (sensor, beacon) in data.iter() {
-            let node = Sensor::new(sensor, beacon);
-            area.add_sensor(&node);
-        }
-
-        area.mapping_scanned_area();
-
-        let mut tuning_frequency: u128 = 0;
+    fn part_02(area: &Area) -> Result<u128, AocError> {
         for y in 0..=4000000 {
             if let Some((tx, ty)) = area.find_beacon_available_spot_at(y) {
-                tuning_frequency = tx as u128 * 4000000 + ty as u128;
-                break;
+                return Ok(tx as u128 * 4000000 + ty as u128);
             }
 
             if y > area.bottom {
-                panic!("It should have the result before reach the bottom");
+                break;
             }
         }
 
-        return tuning_frequency;
+        Err(AocError::unsolvable(
+            "every spot is in range of a sensor, there is no distress beacon",
+        ))
     }
 }
 
@@ -90,17 +95,25 @@ Sensor at x=14, y=3: closest beacon is at x=15, y=3
 Sensor at x=20, y=1: closest beacon is at x=15, y=3";
 
 #[test]
-fn test_part_1() {
-    let lines: Vec<&str> = TEST_INPUT.lines().collect();
-    let result = Day15::part_01(&lines, 10);
-    assert_eq!(result, 26);
+fn test_examples() {
+    crate::solution::assert_examples::<Day15>();
+}
+
+#[test]
+fn test_no_distress_beacon() {
+    // The only sensor covers its whole row, up to its beacon at the same height.
+    let input = Day15::parse("Sensor at x=0, y=0: closest beacon is at x=5, y=0").unwrap();
+    assert!(Day15::part_two(&input).is_err());
 }
 
 #[test]
-fn test_part_2() {
-    let lines: Vec<&str> = TEST_INPUT.lines().collect();
-    let result = Day15::part_02(&lines);
-    assert_eq!(result, 56000011);
+fn test_parsing_error() {
+    let input = TEST_INPUT.replace("x=13, y=2:", "x=13 y=2:");
+    let lines: Vec<&str> = input.lines().collect();
+    match parsing(&lines) {
+        Err(AocError::Parse { line, .. }) => assert_eq!(line, 3),
+        _ => panic!("the sensor should not parse"),
+    }
 }
 
 #[derive(Clone)]
@@ -194,7 +207,7 @@ impl Sensor {
     }
 }
 
-struct Area {
+pub struct Area {
     sensors: Vec<Sensor>,
     scanned_area: HashSet<(i32, i32)>,
     sensor_positions: HashSet<(i32, i32)>,
@@ -302,12 +315,14 @@ impl Area {
     fn find_beacon_available_spot_at(&self, y: i32) -> Option<(i32, i32)> {
         let mut most_left = self.left_at(y);
         let mut most_right = self.right_at(y);
-        if most_left == most_right {
+        // No sensor reaches the row when the left is still `i32::MAX`.
+        if most_left >= most_right {
             return None;
         }
 
-        let mut loop_count = 0;
-        'outer: loop {
+        // Both ends only move inwards, so the passes stop once one of them changes nothing.
+        loop {
+            let before = (most_left, most_right);
             for sensor in self.sensors.iter() {
                 if let Some(sensor_left) = sensor.left_at(y) {
                     if let Some(sensor_right) = sensor.right_at(y) {
@@ -324,20 +339,20 @@ impl Area {
                         }
 
                         if most_left >= most_right {
-                            break 'outer;
+                            return None;
                         }
                     }
                 }
             }
 
-            if loop_count > 5 && (most_right - most_left).abs() == 2 {
-                return Some((most_left + 1, y));
+            if (most_left, most_right) == before {
+                return if most_right - most_left == 2 {
+                    Some((most_left + 1, y))
+                } else {
+                    None
+                };
             }
-
-            loop_count += 1;
         }
-
-        None
     }
 
     fn left_at(&self, y: i32) -> i32 {
@@ -359,22 +374,36 @@ impl Area {
     }
 }
 
-fn parsing(lines: &Vec<&str>) -> Vec<((i32, i32), (i32, i32))> {
+/// Both parts work on the same scanned area, so it is built once from the sensors.
+fn build_area(lines: &Vec<&str>) -> Result<Area, AocError> {
+    let data = parsing(lines)?;
+    let mut area = Area::new();
+    for (sensor, beacon) in data.iter() {
+        let node = Sensor::new(sensor, beacon);
+        area.add_sensor(&node);
+    }
+
+    area.mapping_scanned_area();
+    Ok(area)
+}
+
+/// A sensor position and the position of the closest beacon it detects.
+type SensorReading = ((i32, i32), (i32, i32));
+
+fn parsing(lines: &Vec<&str>) -> Result<Vec<SensorReading>, AocError> {
+    let labels = ["Sensor at x=", ", y=", ": closest beacon is at x=", ", y="];
     let mut result = vec![];
-    for line in lines.iter() {
-        let splited: Vec<&str> = line.split(": closest beacon is at x=").collect();
-        let sensor_part = splited[0].replace("Sensor at x=", "").replace(" y=", "");
-        let (sensor_x, sensor_y) = sensor_part.split_once(",").unwrap();
-        let sensor_x = sensor_x.parse::<i32>().unwrap();
-        let sensor_y = sensor_y.parse::<i32>().unwrap();
-
-        let beacon_part = splited[1].replace(" y=", "");
-        let (beacon_x, beacon_y) = beacon_part.split_once(",").unwrap();
-        let beacon_x = beacon_x.parse::<i32>().unwrap();
-        let beacon_y = beacon_y.parse::<i32>().unwrap();
-
-        result.push(((sensor_x, sensor_y), (beacon_x, beacon_y)));
+    for (index, line) in lines.iter().enumerate() {
+        let values = labelled_values::<i32>(line, &labels).ok_or_else(|| {
+            AocError::parse(
+                index,
+                line,
+                "expected `Sensor at x=<x>, y=<y>: closest beacon is at x=<x>, y=<y>`",
+            )
+        })?;
+
+        result.push(((values[0], values[1]), (values[2], values[3])));
     }
 
-    result
+    Ok(result)
 }
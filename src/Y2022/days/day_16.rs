@@ -1,535 +1,323 @@
-use std::{collections::VecDeque, time::Instant};
-
 use itertools::Itertools;
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
 
-use crate::file_handler::FileHandler;
+use crate::error::AocError;
+use crate::search;
+use crate::solution::{configure_each, unknown_parameter, Example, Solution};
 
 const TOTAL_MINUTES: i32 = 30;
-pub struct Day16 {}
 
-impl Day16 {
-    pub fn run() -> (String, String, String, u128, u128) {
-        let first_input = FileHandler::read("./src/Y2022/inputs/day_16_1.txt");
-
-        let lines: Vec<&str> = first_input.split('\n').collect();
-
-        let start_1 = Instant::now();
-        let result_1 = Day16::part_01(&lines);
-        let time_calculation_1 = start_1.elapsed();
-
-        let start_2 = Instant::now();
-        let result_2 = Day16::part_02(&lines);
-        let time_calculation_2 = start_2.elapsed();
-
-        (
-            "Day_16".to_owned(),
-            format!("{}", result_1),
-            format!("{}", result_2),
-            time_calculation_1.as_nanos(),
-            time_calculation_2.as_nanos(),
-        )
-    }
+/// The minutes spent teaching the elephants before anyone walks.
+const TEACHING_MINUTES: i32 = 4;
 
-    fn part_01(lines: &Vec<&str>) -> i32 {
-        let (start, graph) = parsing(lines);
-        process(&graph, &"AA".to_owned())
-    }
+/// Who opens the valves in part two: you and an elephant.
+const AGENTS: usize = 2;
 
-    fn part_02(lines: &Vec<&str>) -> i32 {
-        return 0;
-    }
-}
+pub struct Day16 {}
 
-const TEST_INPUT: &str = "Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
-Valve BB has flow rate=13; tunnels lead to valves CC, AA
-Valve CC has flow rate=2; tunnels lead to valves DD, BB
-Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
-Valve EE has flow rate=3; tunnels lead to valves FF, DD
-Valve FF has flow rate=0; tunnels lead to valves EE, GG
-Valve GG has flow rate=0; tunnels lead to valves FF, HH
-Valve HH has flow rate=22; tunnel leads to valve GG
-Valve II has flow rate=0; tunnels lead to valves AA, JJ
-Valve JJ has flow rate=21; tunnel leads to valve II";
+/// The flow rate and the tunnels of every valve.
+type Tunnels = HashMap<String, (i32, Vec<String>)>;
 
-#[test]
-fn test_part_1() {
-    let lines: Vec<&str> = TEST_INPUT.lines().collect();
-    let result = Day16::part_01(&lines);
-    assert_eq!(result, 1651);
+/// The valves, the minutes before the eruption and who opens them in part two.
+pub struct Volcano {
+    tunnels: Tunnels,
+    total_minutes: i32,
+    agents: usize,
 }
 
-#[test]
-fn test_part_2() {
-    let lines: Vec<&str> = TEST_INPUT.lines().collect();
-    let result = Day16::part_02(&lines);
-    assert_eq!(result, 0);
-}
+impl Solution for Day16 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 16;
 
-#[test]
-fn test_calculate_releasing_pressure_from_valves() {
-    let mut opened_valves = HashMap::new();
-    opened_valves.insert("A".to_owned(), 5);
-    assert_eq!(5, calculate_releasing_pressure_from_valves(&opened_valves));
+    type Input<'a> = Volcano;
 
-    opened_valves.insert("B".to_owned(), 3);
-    assert_eq!(8, calculate_releasing_pressure_from_valves(&opened_valves));
-}
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let lines: Vec<&str> = input.split('\n').collect();
+        Ok(Volcano {
+            tunnels: parsing(&lines)?,
+            total_minutes: TOTAL_MINUTES,
+            agents: AGENTS,
+        })
+    }
 
-#[test]
-fn test_calculate_release_pressure_from_path() {
-    let mut path = vec![];
-    path.push(("A".to_owned(), 2, 5, (TOTAL_MINUTES - 2) * 5));
-    assert_eq!(
-        (TOTAL_MINUTES - 2) * 5,
-        calculate_release_pressure_from_path(&path)
-    );
+    fn part_one(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day16::part_01(input)?.to_string())
+    }
 
-    path.push(("B".to_owned(), 5, 10, (TOTAL_MINUTES - 5) * 10));
-    assert_eq!(
-        (TOTAL_MINUTES - 2) * 5 + (TOTAL_MINUTES - 5) * 10,
-        calculate_release_pressure_from_path(&path)
-    );
-}
+    fn part_two(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day16::part_02(input)?.to_string())
+    }
 
-#[test]
-fn test_calculate_release_pressure_from_path_at() {
-    let mut path = vec![];
-    path.push(("A".to_owned(), 2, 5, (TOTAL_MINUTES - 2) * 5));
-    assert_eq!(
-        (3 - 2) * 5,
-        calculate_release_pressure_from_path_at(&path, 3)
-    );
+    fn examples() -> Vec<Example> {
+        vec![
+            Example::new(1, TEST_INPUT, "1651"),
+            Example::new(1, TEST_INPUT, "1327").with("total_minutes", 26),
+            Example::new(2, TEST_INPUT, "1707"),
+            Example::new(2, TEST_INPUT, "1651").with("agents", 1),
+            Example::new(2, TEST_INPUT, "1327")
+                .with("total_minutes", 26)
+                .with("agents", 1),
+        ]
+    }
 
-    path.push(("B".to_owned(), 5, 10, (TOTAL_MINUTES - 5) * 10));
-    assert_eq!(
-        (10 - 2) * 5 + (10 - 5) * 10,
-        calculate_release_pressure_from_path_at(&path, 10)
-    );
-}
+    fn configure(input: &mut Self::Input<'_>, params: &[(&str, i64)]) -> Result<(), AocError> {
+        configure_each(params, |name, value| {
+            match name {
+                "total_minutes" => input.total_minutes = value as i32,
+                "agents" if value < 1 => {
+                    return Err(AocError::unsolvable(format!(
+                        "{} agents cannot open any valve",
+                        value
+                    )))
+                }
+                "agents" => input.agents = value as usize,
+                _ => return Err(unknown_parameter(name)),
+            }
 
-struct OptimalResult {
-    total_process: i32,
-    path: Vec<(String, i32, i32, i32)>,
-    travel_path: Vec<String>,
+            Ok(())
+        })
+    }
 }
 
-fn process(graph: &HashMap<String, (i32, Vec<String>)>, start: &String) -> i32 {
-    let mut optimal_result = OptimalResult {
-        total_process: 0,
-        path: vec![],
-        travel_path: vec![],
-    };
-
-    let opened_valves = HashMap::new();
-    let mut unopened_valves = HashMap::new();
-    for (valve, (flow_rate, _)) in graph.iter() {
-        if *flow_rate > 0 {
-            unopened_valves.insert(valve.to_owned(), *flow_rate);
-        }
+impl Day16 {
+    fn part_01(input: &Volcano) -> Result<i32, AocError> {
+        let network = ValveNetwork::new(&input.tunnels, "AA")?;
+        Ok(network.most_pressure(input.total_minutes, 1))
     }
-    // println!(
-    //     "process: {} graph size: {}\nunopened {}: {:?}\n",
-    //     start,
-    //     graph.len(),
-    //     unopened_valves.len(),
-    //     unopened_valves
-    // );
-    dfs(
-        graph,
-        &mut optimal_result,
-        &opened_valves,
-        &unopened_valves,
-        &vec![],
-        0,
-        start,
-        &vec![],
-    );
-
-    // println!(
-    //     "Result: {}\npath: {:?}\ntraveled: {:?}\n--",
-    //     calculate_release_pressure_from_path(&optimal_result.path),
-    //     optimal_result.path,
-    //     optimal_result.travel_path,
-    // );
 
-    calculate_release_pressure_from_path(&optimal_result.path)
-}
+    fn part_02(input: &Volcano) -> Result<i32, AocError> {
+        let network = ValveNetwork::new(&input.tunnels, "AA")?;
+        let minutes = if input.agents > 1 {
+            input.total_minutes - TEACHING_MINUTES
+        } else {
+            input.total_minutes
+        };
 
-fn calculate_releasing_pressure_from_valves(opened_valves: &HashMap<String, i32>) -> i32 {
-    let mut total_pressure = 0;
-    for (_, value) in opened_valves.iter() {
-        total_pressure += *value;
+        Ok(network.most_pressure(minutes, input.agents))
     }
+}
 
-    total_pressure
+/// The valves with a flow rate, numbered so that a set of them fits in a bitmask, and the
+/// minutes it takes to walk from the start or any of them to any other.
+struct ValveNetwork {
+    flow_rates: Vec<i32>,
+    /// `distances[from][to]`, the start being the last row.
+    distances: Vec<Vec<i32>>,
 }
 
-// calculate released pressure from path at TOTAL MINUTES
-fn calculate_release_pressure_from_path(path: &Vec<(String, i32, i32, i32)>) -> i32 {
-    let mut total_released_pressure = 0;
-    for (_, _, _, total_released) in path.iter() {
-        total_released_pressure += total_released;
-    }
+impl ValveNetwork {
+    fn new(
+        graph: &HashMap<String, (i32, Vec<String>)>,
+        start: &str,
+    ) -> Result<ValveNetwork, AocError> {
+        let valves: Vec<&String> = graph
+            .iter()
+            .filter(|(_, (flow_rate, _))| *flow_rate > 0)
+            .map(|(valve, _)| valve)
+            .sorted()
+            .collect();
+        if valves.len() > 20 {
+            return Err(AocError::unsolvable(format!(
+                "{} valves with a flow rate are too many to track as a set",
+                valves.len()
+            )));
+        }
 
-    total_released_pressure
-}
+        let start = start.to_owned();
+        let mut distances = vec![];
+        for from in valves.iter().copied().chain([&start]) {
+            let result = search::bfs(
+                [from.to_owned()],
+                |node| {
+                    graph
+                        .get(node)
+                        .map(|(_, tunnels)| tunnels.clone())
+                        .unwrap_or_default()
+                },
+                |_| false,
+            );
+            let row = valves
+                .iter()
+                .map(|to| {
+                    result
+                        .distance(to)
+                        .map(|distance| distance as i32)
+                        .ok_or_else(|| {
+                            AocError::unsolvable(format!(
+                                "valve {} cannot be reached from {}",
+                                to, from
+                            ))
+                        })
+                })
+                .collect::<Result<Vec<i32>, AocError>>()?;
+            distances.push(row);
+        }
 
-fn calculate_release_pressure_from_path_at(
-    path: &Vec<(String, i32, i32, i32)>,
-    current_minutes: i32,
-) -> i32 {
-    let mut total_released_pressure = 0;
-    for (_, minutes, flow_rate, _) in path.iter() {
-        let active_minutes = current_minutes - minutes;
-        total_released_pressure += flow_rate * active_minutes;
+        Ok(ValveNetwork {
+            flow_rates: valves.iter().map(|valve| graph[*valve].0).collect(),
+            distances,
+        })
     }
 
-    total_released_pressure
-}
+    /// The most pressure `agents` walking at the same time release in `minutes`, each of them
+    /// opening a set of valves that none of the others opens.
+    fn most_pressure(&self, minutes: i32, agents: usize) -> i32 {
+        if agents == 0 {
+            return 0;
+        }
 
-fn dfs(
-    graph: &HashMap<String, (i32, Vec<String>)>,
-    optimal_result: &mut OptimalResult,
-    opened_valves: &HashMap<String, i32>,
-    unopened_valves: &HashMap<String, i32>,
-    current_path: &Vec<(String, i32, i32, i32)>,
-    minutes: i32,
-    current_node: &String,
-    travel_path: &Vec<String>,
-) {
-    /// Reach time limit.
-    if minutes > TOTAL_MINUTES || unopened_valves.len() == 0 {
-        // Is current path it the best
-        update_optimal_result(optimal_result, current_path, travel_path, minutes);
-
-        optimal_result.total_process += 1;
-
-        return;
-    }
+        let best = self.best_per_opened_set(minutes);
+        let mut team = best.clone();
+        for _ in 1..agents {
+            team = combine_disjoint(&team, &best);
+        }
 
-    // println!("DFS path length: {}", current_path.len());
-
-    // before continue recursive. It need to check that this it is possible that
-    // it can have result better than optimal result
-    if validate_possible_result(
-        optimal_result,
-        &opened_valves,
-        unopened_valves,
-        current_path,
-        minutes,
-    ) == false
-    {
-        return;
+        team[team.len() - 1]
     }
 
-    // recursive next node from unopened valves
-    for (valve, flow_rate) in unopened_valves.iter() {
-        let (operation_minute_cost, mut traveled_path) =
-            calculate_operation_cost(graph, current_node, valve);
-        let new_minutes = minutes + operation_minute_cost + 1;
-        // if new_minutes > TOTAL_MINUTES {
-        //     continue;
-        // }
-
-        let minutes_left = TOTAL_MINUTES - new_minutes;
-
-        let mut new_path = if minutes_left > 0 {
-            let mut new_path = current_path.clone();
-            new_path.push((
-                valve.to_owned(),
-                new_minutes,
-                *flow_rate,
-                flow_rate * minutes_left,
-            ));
-            new_path
-        } else {
-            current_path.clone()
-        };
+    /// The most pressure a single agent releases in `minutes` while opening at most the
+    /// valves of each set, indexed by the bitmask of the set.
+    fn best_per_opened_set(&self, minutes: i32) -> Vec<i32> {
+        let count = self.flow_rates.len();
+        let mut best = vec![0; 1 << count];
+        self.visit(count, minutes, 0, 0, &mut best);
+
+        // Opening fewer valves is always allowed, so every set is worth at least its subsets.
+        for bit in 0..count {
+            for set in 0..best.len() {
+                if set & (1 << bit) != 0 {
+                    best[set] = best[set].max(best[set ^ (1 << bit)]);
+                }
+            }
+        }
 
-        let mut new_travel_path = travel_path.clone();
-        new_travel_path.append(&mut traveled_path);
+        best
+    }
 
-        let mut new_unopened_valves = unopened_valves.clone();
-        new_unopened_valves.remove(valve);
+    /// Walks to every valve still closed from `position`, recording the pressure released by
+    /// each set opened along the way.
+    fn visit(
+        &self,
+        position: usize,
+        minutes_left: i32,
+        opened: usize,
+        pressure: i32,
+        best: &mut [i32],
+    ) {
+        best[opened] = best[opened].max(pressure);
+        for (valve, flow_rate) in self.flow_rates.iter().enumerate() {
+            if opened & (1 << valve) != 0 {
+                continue;
+            }
 
-        let mut new_opened_valves = opened_valves.clone();
-        new_opened_valves.insert(valve.to_owned(), *flow_rate);
-        if optimal_result.path.len() == 0 {
-            println!("{:?}", new_path);
+            // Walking there and turning the valve.
+            let remaining = minutes_left - self.distances[position][valve] - 1;
+            if remaining > 0 {
+                self.visit(
+                    valve,
+                    remaining,
+                    opened | (1 << valve),
+                    pressure + flow_rate * remaining,
+                    best,
+                );
+            }
         }
-
-        dfs(
-            graph,
-            optimal_result,
-            &new_opened_valves,
-            &new_unopened_valves,
-            &new_path,
-            new_minutes,
-            &valve.clone(),
-            &new_travel_path,
-        );
     }
 }
 
-fn update_optimal_result(
-    optimal_result: &mut OptimalResult,
-    current_path: &Vec<(String, i32, i32, i32)>,
-    travel_path: &Vec<String>,
-    minutes: i32,
-) {
-    if calculate_release_pressure_from_path(&optimal_result.path)
-        < calculate_release_pressure_from_path(current_path)
-        || optimal_result.path.len() == 0
-    {
-        optimal_result.path.clear();
-        optimal_result.path.append(&mut current_path.clone());
-
-        optimal_result.travel_path.clear();
-        optimal_result.travel_path.append(&mut travel_path.clone());
-
-        // println!(
-        //     "dfs END: {} minutes {} times\nrelease pressure: {}\npath: {:?}\n--\n",
-        //     optimal_result.total_process,
-        //     minutes,
-        //     calculate_release_pressure_from_path(current_path),
-        //     current_path
-        // );
-    }
+/// The best of `team` opening some of each set and one more agent opening the rest, both
+/// tables indexed by the bitmask of the valves they may open.
+fn combine_disjoint(team: &[i32], agent: &[i32]) -> Vec<i32> {
+    (0..team.len())
+        .map(|set| {
+            let mut best = agent[set];
+            // Every subset of `set`, including `set` itself.
+            let mut subset = set;
+            while subset > 0 {
+                best = best.max(team[subset] + agent[set ^ subset]);
+                subset = (subset - 1) & set;
+            }
+            best
+        })
+        .collect()
 }
 
-fn calculate_operation_cost(
-    graph: &HashMap<String, (i32, Vec<String>)>,
-    start: &String,
-    target: &String,
-) -> (i32, Vec<String>) {
-    let parents = bfs(graph, start);
-    let (_, path) = construct_path(&parents, start, target);
-    // println!("calculate_operation_cost:\nstart: {} target: {}\npath: {:?}\n---\n", start, target, path);
-    (path.len() as i32, path)
+#[test]
+fn test_valve_network() {
+    let lines: Vec<&str> = TEST_INPUT.lines().collect();
+    let graph = parsing(&lines).unwrap();
+    let network = ValveNetwork::new(&graph, "AA").unwrap();
+    // BB, CC, DD, EE, HH and JJ, AA last.
+    assert_eq!(network.flow_rates, vec![13, 2, 20, 3, 22, 21]);
+    assert_eq!(network.distances[6], vec![1, 2, 1, 2, 5, 2]);
+    assert_eq!(network.distances[4][5], 7);
+
+    assert_eq!(network.most_pressure(TOTAL_MINUTES, 1), 1651);
+    assert_eq!(network.most_pressure(26, 2), 1707);
+    assert_eq!(network.most_pressure(26, 0), 0);
+    assert_eq!(network.most_pressure(26, 3), 1794);
 }
 
 #[test]
-fn test_validate_possible_result() {
-    let B = ("B".to_owned(), 3, 2, (TOTAL_MINUTES - 3) * 2);
-    let A = ("A".to_owned(), 15, 5, (TOTAL_MINUTES - 15) * 5);
-    let C = ("C".to_owned(), 20, 10, (TOTAL_MINUTES - 20) * 10);
-    let optimal_result = OptimalResult {
-        total_process: 0,
-        path: vec![B.clone(), A.clone(), C.clone()],
-        travel_path: vec![],
-    };
-
-    println!(
-        "Optimal Result: {}",
-        calculate_release_pressure_from_path(&optimal_result.path)
-    );
+fn test_combine_disjoint() {
+    // Two valves, worth 5 and 7 alone but only 9 when one agent opens both.
+    let agent = vec![0, 5, 7, 9];
+    assert_eq!(combine_disjoint(&agent, &agent), vec![0, 5, 7, 12]);
+}
 
-    let mut opened_valves = HashMap::new();
-    let mut unopened_valves = HashMap::new();
-    unopened_valves.insert("A".to_owned(), 5);
-    unopened_valves.insert("B".to_owned(), 2);
-    unopened_valves.insert("C".to_owned(), 10);
-    assert_eq!(
-        true,
-        validate_possible_result(
-            &optimal_result,
-            &opened_valves,
-            &unopened_valves,
-            &vec![],
-            5
-        )
-    );
-    assert_eq!(
-        false,
-        validate_possible_result(
-            &optimal_result,
-            &opened_valves,
-            &unopened_valves,
-            &vec![],
-            29
-        )
-    );
+const TEST_INPUT: &str = "Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+Valve BB has flow rate=13; tunnels lead to valves CC, AA
+Valve CC has flow rate=2; tunnels lead to valves DD, BB
+Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
+Valve EE has flow rate=3; tunnels lead to valves FF, DD
+Valve FF has flow rate=0; tunnels lead to valves EE, GG
+Valve GG has flow rate=0; tunnels lead to valves FF, HH
+Valve HH has flow rate=22; tunnel leads to valve GG
+Valve II has flow rate=0; tunnels lead to valves AA, JJ
+Valve JJ has flow rate=21; tunnel leads to valve II";
 
-    opened_valves.insert("A".to_owned(), 15);
-    let mut unopened_valves = HashMap::new();
-    unopened_valves.insert("B".to_owned(), 2);
-    unopened_valves.insert("C".to_owned(), 10);
+#[test]
+fn test_examples() {
+    crate::solution::assert_examples::<Day16>();
+}
 
-    let A = ("A".to_owned(), 2, 5, (TOTAL_MINUTES - 2) * 5);
-    let mut current_path = vec![A];
-    assert_eq!(
-        true,
-        validate_possible_result(
-            &optimal_result,
-            &opened_valves,
-            &unopened_valves,
-            &current_path,
-            5
-        )
-    );
-    assert_eq!(
-        true,
-        validate_possible_result(
-            &optimal_result,
-            &opened_valves,
-            &unopened_valves,
-            &current_path,
-            15
-        )
-    );
-    assert_eq!(
-        true,
-        validate_possible_result(
-            &optimal_result,
-            &opened_valves,
-            &unopened_valves,
-            &current_path,
-            20
-        )
-    );
-    assert_eq!(
-        true,
-        validate_possible_result(
-            &optimal_result,
-            &opened_valves,
-            &unopened_valves,
-            &current_path,
-            25
-        )
-    );
-    assert_eq!(
-        false,
-        validate_possible_result(
-            &optimal_result,
-            &opened_valves,
-            &unopened_valves,
-            &current_path,
-            26
-        )
-    );
+#[test]
+fn test_configure() {
+    let mut input = Day16::parse(TEST_INPUT).unwrap();
     assert_eq!(
-        false,
-        validate_possible_result(
-            &optimal_result,
-            &opened_valves,
-            &unopened_valves,
-            &current_path,
-            27
-        )
+        Day16::configure(&mut input, &[("agents", 0)])
+            .unwrap_err()
+            .to_string(),
+        "no solution: 0 agents cannot open any valve"
     );
     assert_eq!(
-        false,
-        validate_possible_result(
-            &optimal_result,
-            &opened_valves,
-            &unopened_valves,
-            &current_path,
-            30
-        )
+        Day16::configure(&mut input, &[("elephants", 1)])
+            .unwrap_err()
+            .to_string(),
+        "no solution: unknown parameter `elephants`"
     );
 }
 
-fn validate_possible_result(
-    optimal_result: &OptimalResult,
-    opened_valves: &HashMap<String, i32>,
-    unopened_valves: &HashMap<String, i32>,
-    current_path: &Vec<(String, i32, i32, i32)>,
-    minutes: i32,
-) -> bool {
-    let minute_left = TOTAL_MINUTES - minutes;
-    let opened_release_value = calculate_releasing_pressure_from_valves(opened_valves);
-    let unopened_release_value = calculate_releasing_pressure_from_valves(&unopened_valves);
-    let possible_value = calculate_release_pressure_from_path_at(current_path, minutes)
-        + opened_release_value * minute_left
-        + unopened_release_value * minute_left;
-
-    if calculate_release_pressure_from_path(&optimal_result.path) < possible_value {
-        // println!("validate_possible_result: TRUE {} | {}\nopened: {} / {}\n{:?}\nunopened: {} / {}\n{:?}\noptimal: {}\npath:\n{:?}\n++\n",
-        //     minutes, minute_left,
-        //     opened_release_value, opened_release_value * minute_left, opened_valves,
-        //     unopened_release_value, unopened_release_value * minute_left, unopened_valves,
-        //     calculate_release_pressure_from_path(&optimal_result.path), optimal_result.path);
-        true
-    } else {
-        // println!("validate_possible_result: FALSE {} | {}:\nopened: {} / {}\n{:?}\nunopened: {} / {}\n{:?}\ncurrent_path:\n{:?}\noptimal: {}\npath:\n{:?}\n--\n",
-        //     minutes, minute_left,
-        //     opened_release_value, opened_release_value * minute_left, opened_valves,
-        //     unopened_release_value, unopened_release_value * minute_left, unopened_valves,
-        //     current_path,
-        //     calculate_release_pressure_from_path(&optimal_result.path) , optimal_result.path);
-        false
-    }
-}
-
-fn construct_path(
-    parents: &HashMap<String, String>,
-    start: &String,
-    target: &String,
-) -> (bool, Vec<String>) {
-    let mut path = vec![];
-    let mut current_node = parents.get(target);
-    let mut path_completed = false;
-    path.push(target.to_owned());
-    loop {
-        match current_node {
-            Some(node) => {
-                if node == start {
-                    path_completed = true;
-                } else {
-                    path.push(node.to_owned());
-                }
-
-                current_node = parents.get(node);
-            }
-            None => {
-                break;
-            }
-        }
-    }
-
-    // println!("Construct {} to {}\n{:?}\n--", start, target, path.clone().into_iter().rev());
-    (path_completed, Vec::from_iter(path.into_iter().rev()))
-}
-
-fn bfs(graphs: &HashMap<String, (i32, Vec<String>)>, start: &String) -> HashMap<String, String> {
-    // println!("BFS: {:?}", start);
-    let mut queue: VecDeque<String> = VecDeque::new();
-    queue.push_back(start.to_owned());
-    let mut visited: HashSet<String> = HashSet::new();
-    visited.insert(start.to_owned());
-    let mut parents: HashMap<String, String> = HashMap::new();
-
-    while queue.len() > 0 {
-        let current_node = queue.pop_front().unwrap();
-        if let Some((_, neighbours)) = graphs.get(&current_node) {
-            for node in neighbours.iter() {
-                if visited.contains(node) == false {
-                    visited.insert(node.to_owned());
-                    parents.insert(node.to_owned(), current_node.to_owned());
-                    queue.push_back(node.to_owned());
-                    // println!("{:?}", parents);
-                }
-            }
-        }
-    }
-
-    parents
-}
-
-fn parsing(lines: &Vec<&str>) -> (String, HashMap<String, (i32, Vec<String>)>) {
-    let mut start_tunnel = String::new();
+fn parsing(lines: &Vec<&str>) -> Result<Tunnels, AocError> {
     let mut result = HashMap::new();
     for (index, line) in lines.iter().enumerate() {
-        let (first, second) = line.split_once(" has flow rate=").unwrap();
-        let valve = first.replace("Valve ", "");
+        let invalid = || {
+            AocError::parse(
+                index,
+                line,
+                "expected `Valve <name> has flow rate=<rate>; tunnels lead to valves <names>`",
+            )
+        };
+        let (first, second) = line.split_once(" has flow rate=").ok_or_else(invalid)?;
+        let valve = first.strip_prefix("Valve ").ok_or_else(invalid)?;
         let (second, third) = if second.contains("tunnels") {
-            second.split_once("; tunnels lead to valves ").unwrap()
+            second.split_once("; tunnels lead to valves ")
         } else {
-            second.split_once("; tunnel leads to valve ").unwrap()
-        };
+            second.split_once("; tunnel leads to valve ")
+        }
+        .ok_or_else(invalid)?;
 
-        let rate = second.parse::<i32>().unwrap();
+        let rate = second.parse::<i32>().map_err(|_| invalid())?;
         let tunnels = third
             .replace(" ", "")
             .split(",")
@@ -537,10 +325,6 @@ fn parsing(lines: &Vec<&str>) -> (String, HashMap<String, (i32, Vec<String>)>) {
             .collect_vec();
 
         result.insert(valve.to_owned(), (rate, tunnels));
-
-        if index == 0 {
-            start_tunnel = valve.to_owned()
-        }
     }
-    (start_tunnel, result)
+    Ok(result)
 }
@@ -1,64 +1,82 @@
-use std::{collections::VecDeque, time::Instant, vec};
+use crate::cycle::find_cycle_by_key;
+use crate::error::AocError;
+use crate::solution::{Example, Solution};
 
-use itertools::Itertools;
+pub struct Day17 {}
 
-use crate::file_handler::FileHandler;
+impl Solution for Day17 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 17;
 
-pub struct Day17 {}
+    type Input<'a> = Vec<bool>;
 
-impl Day17 {
-    pub fn run() -> (String, String, String, u128, u128) {
-        let first_input = FileHandler::read("./src/Y2022/inputs/day_17_1.txt");
-
-        let lines: Vec<&str> = first_input.split('\n').collect();
-
-        let start_1 = Instant::now();
-        let result_1 = Day17::part_01(&lines);
-        let time_calculation_1 = start_1.elapsed();
-
-        let start_2 = Instant::now();
-        let result_2 = Day17::part_02(&lines);
-        let time_calculation_2 = start_2.elapsed();
-
-        (
-            "Day_17".to_owned(),
-            format!("{}", result_1),
-            format!("{}", result_2),
-            time_calculation_1.as_nanos(),
-            time_calculation_2.as_nanos(),
-        )
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let lines: Vec<&str> = input.split('\n').collect();
+        parsing(&lines)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day17::part_01(input).to_string())
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day17::part_02(input).to_string())
     }
 
-    fn part_01(lines: &Vec<&str>) -> i32 {
-        let pattern = parsing(lines);
+    fn examples() -> Vec<Example> {
+        vec![
+            Example::new(1, TEST_INPUT, "3068"),
+            Example::new(2, TEST_INPUT, "1514285714288"),
+        ]
+    }
+}
 
-        let chamber = VerticalChamber::new(&pattern);
-        chamber.print();
+impl Day17 {
+    fn part_01(pattern: &[bool]) -> usize {
+        let mut chamber = VerticalChamber::new(pattern);
+        for _ in 0..2022 {
+            chamber.process();
+        }
 
-        return 0;
+        chamber.height()
     }
 
-    fn part_02(lines: &Vec<&str>) -> i32 {
-        return 0;
+    /// Far too many rocks to drop one by one, but the chamber starts repeating itself once
+    /// the same rock meets the same jet on the same surface, so the tower grows by the same
+    /// height every period from then on.
+    fn part_02(pattern: &[bool]) -> u64 {
+        tower_height(pattern, 1_000_000_000_000)
     }
 }
 
 const TEST_INPUT: &str = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
 
 #[test]
-fn test_part_1() {
-    let lines: Vec<&str> = TEST_INPUT.lines().collect();
-    let result = Day17::part_01(&lines);
-    assert_eq!(result, 0);
+fn test_examples() {
+    crate::solution::assert_examples::<Day17>();
 }
 
 #[test]
-fn test_part_2() {
-    let lines: Vec<&str> = TEST_INPUT.lines().collect();
-    let result = Day17::part_02(&lines);
-    assert_eq!(result, 0);
+fn test_process() {
+    let pattern = parsing(&[TEST_INPUT]).unwrap();
+    let mut chamber = VerticalChamber::new(&pattern);
+    let heights: Vec<usize> = (0..5)
+        .map(|_| {
+            chamber.process();
+            chamber.height()
+        })
+        .collect();
+    assert_eq!(heights, vec![1, 4, 6, 7, 9]);
+    assert_eq!(tower_height(&pattern, 2022), 3068);
 }
 
+#[test]
+fn test_parsing_error() {
+    assert!(parsing(&[">><x"]).is_err());
+    assert!(parsing(&[""]).is_err());
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Blocks {
     Horizontal = 1,
     Plus = 2,
@@ -67,91 +85,154 @@ enum Blocks {
     Square = 5,
 }
 
+impl Blocks {
+    /// The order the rocks fall in, over and over.
+    const ORDER: [Blocks; 5] = [
+        Blocks::Horizontal,
+        Blocks::Plus,
+        Blocks::InverseL,
+        Blocks::Vertical,
+        Blocks::Square,
+    ];
+
+    /// The rows of the rock from the bottom up as they appear, two units away from the left
+    /// wall. Bit 6 is the leftmost column of the chamber and bit 0 the rightmost one.
+    fn rows(&self) -> &'static [u8] {
+        match self {
+            Blocks::Horizontal => &[0b0011110],
+            Blocks::Plus => &[0b0001000, 0b0011100, 0b0001000],
+            Blocks::InverseL => &[0b0011100, 0b0000100, 0b0000100],
+            Blocks::Vertical => &[0b0010000, 0b0010000, 0b0010000, 0b0010000],
+            Blocks::Square => &[0b0011000, 0b0011000],
+        }
+    }
+}
+
+/// What the tower looks like after a rock came to rest, `surface` being all that matters
+/// for how the next rocks fall.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Snapshot {
+    height: usize,
+    surface: (usize, usize, [usize; 7]),
+}
+
 struct VerticalChamber {
-    width: i32,
-    rows: VecDeque<[bool; 7]>,
-    current_block: Option<(Blocks, i32, i32)>,
+    /// The rows from the floor up, one bit per column like `Blocks::rows`.
+    rows: Vec<u8>,
+    current_block_index: usize,
     jet_pattern: Vec<bool>,
     current_pattern_index: usize,
 }
 
 impl VerticalChamber {
-    fn new(pattern: &Vec<bool>) -> Self {
+    fn new(pattern: &[bool]) -> Self {
         Self {
-            width: 7,
-            rows: VecDeque::new(),
-            current_block: None,
-            jet_pattern: pattern.clone(),
+            rows: vec![],
+            current_block_index: 0,
+            jet_pattern: pattern.to_vec(),
             current_pattern_index: 0,
         }
     }
 
-    fn spawn_point(&self, block_type: &Blocks) -> (i32, i32) {
-        let (_, h) = block_size(block_type);
-        (2, (self.rows.len() as i32) + h - 1)
+    fn height(&self) -> usize {
+        self.rows.len()
     }
 
-    fn process(&mut self) {}
-
-    fn print(&self) {
-        let mut top = self.rows.len() as i32 + 3 - 1;
-        // if let Some((x1, y1, x2, y2)) = self.current_bounding_block() {
-        //     if top < y1 {
-        //         top = y1;
-        //     }
-        // }
-
-        // for y in (0..=top).rev()
-        // {
-        //     print!("|");
-        //     if y < self.rows.len() as i32
-        //     {
-        //         // draw existing area
-        //         if let Some(row) = self.rows.get(y as usize)
-        //         {
-
-        //         }
-        //     }
-        //     println!("|");
-        // }
-
-        // for (_, row) in self.rows.iter().rev().enumerate() {
-
-        //     for v in row.iter() {
-        //         if *v == true {
-        //             print!("#");
-        //         } else {
-        //             print!(".");
-        //         }
-        //     }
-
-        // }
-
-        println!("+-------+");
+    /// Drops the next rock, pushed by a jet then falling one unit at a time, until it rests.
+    fn process(&mut self) {
+        let block = Blocks::ORDER[self.current_block_index];
+        self.current_block_index = (self.current_block_index + 1) % Blocks::ORDER.len();
+
+        let mut rock = block.rows().to_vec();
+        let mut bottom = self.height() + 3;
+        loop {
+            let push_right = self.jet_pattern[self.current_pattern_index];
+            self.current_pattern_index = (self.current_pattern_index + 1) % self.jet_pattern.len();
+            if let Some(pushed) = push(&rock, push_right) {
+                if !self.collides(&pushed, bottom) {
+                    rock = pushed;
+                }
+            }
+
+            if bottom == 0 || self.collides(&rock, bottom - 1) {
+                break;
+            }
+            bottom -= 1;
+        }
+
+        for (index, row) in rock.iter().enumerate() {
+            if bottom + index == self.rows.len() {
+                self.rows.push(0);
+            }
+            self.rows[bottom + index] |= row;
+        }
     }
-}
 
-fn parsing(lines: &Vec<&str>) -> Vec<bool> {
-    lines
-        .first()
-        .unwrap()
-        .chars()
-        .map(|c| {
-            if c == '>' {
-                return true;
-            } else {
-                return false;
+    fn collides(&self, rock: &[u8], bottom: usize) -> bool {
+        rock.iter()
+            .enumerate()
+            .any(|(index, row)| self.rows.get(bottom + index).is_some_and(|r| r & row != 0))
+    }
+
+    /// The next rock and jet with how far below the top each column is first filled.
+    fn snapshot(&self) -> Snapshot {
+        let mut depths = [self.height(); 7];
+        for (column, depth) in depths.iter_mut().enumerate() {
+            let mask = 1 << (6 - column);
+            if let Some(position) = self.rows.iter().rev().position(|row| row & mask != 0) {
+                *depth = position;
             }
-        })
-        .collect_vec()
+        }
+
+        Snapshot {
+            height: self.height(),
+            surface: (self.current_block_index, self.current_pattern_index, depths),
+        }
+    }
 }
 
-fn block_size(block: &Blocks) -> (i32, i32) {
-    match block {
-        Blocks::Horizontal => (4, 1),
-        Blocks::Plus => (3, 3),
-        Blocks::InverseL => (3, 3),
-        Blocks::Vertical => (1, 4),
-        Blocks::Square => (2, 2),
+/// The rock moved one unit by a jet, `None` if a wall is in the way.
+fn push(rock: &[u8], push_right: bool) -> Option<Vec<u8>> {
+    if push_right {
+        if rock.iter().any(|row| row & 1 != 0) {
+            return None;
+        }
+        Some(rock.iter().map(|row| row >> 1).collect())
+    } else {
+        if rock.iter().any(|row| row & 0b1000000 != 0) {
+            return None;
+        }
+        Some(rock.iter().map(|row| row << 1).collect())
     }
 }
+
+/// The height of the tower after `total_rocks`, extrapolated from the first time the
+/// chamber repeats itself.
+fn tower_height(pattern: &[bool], total_rocks: u64) -> u64 {
+    let mut chamber = VerticalChamber::new(pattern);
+    let cycle = find_cycle_by_key(
+        chamber.snapshot(),
+        |_| {
+            chamber.process();
+            chamber.snapshot()
+        },
+        |snapshot| snapshot.surface,
+    );
+
+    cycle.extrapolate(total_rocks, |snapshot| snapshot.height as i64) as u64
+}
+
+fn parsing(lines: &[&str]) -> Result<Vec<bool>, AocError> {
+    let line = lines.first().copied().unwrap_or_default();
+    if line.is_empty() {
+        return Err(AocError::parse(0, line, "expected a jet pattern"));
+    }
+
+    line.chars()
+        .map(|c| match c {
+            '>' => Ok(true),
+            '<' => Ok(false),
+            _ => Err(AocError::parse(0, line, "expected only `<` and `>`")),
+        })
+        .collect()
+}
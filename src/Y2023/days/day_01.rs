@@ -1,33 +1,37 @@
 use rayon::prelude::*;
-use std::time::Instant;
 
-use crate::file_handler::FileHandler;
+use crate::error::AocError;
+use crate::solution::{Example, Solution};
 
 pub struct Day01 {}
 
-impl Day01 {
-    pub fn run() -> (String, String, String, u128, u128) {
-        let first_input = FileHandler::read("./src/Y2023/inputs/day_01_1.txt");
-
-        let lines: Vec<&str> = first_input.split('\n').collect();
-
-        let start_1 = Instant::now();
-        let result_1 = Day01::part_01(&lines);
-        let time_calculation_1 = start_1.elapsed();
-
-        let start_2 = Instant::now();
-        let result_2 = Day01::part_02(&lines);
-        let time_calculation_2 = start_2.elapsed();
-
-        (
-            "Day_01".to_owned(),
-            format!("{}", result_1),
-            format!("{}", result_2),
-            time_calculation_1.as_nanos(),
-            time_calculation_2.as_nanos(),
-        )
+impl Solution for Day01 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 1;
+
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Vec<&str>, AocError> {
+        Ok(input.split('\n').collect())
+    }
+
+    fn part_one(lines: &Vec<&str>) -> Result<String, AocError> {
+        Ok(Day01::part_01(lines).to_string())
+    }
+
+    fn part_two(lines: &Vec<&str>) -> Result<String, AocError> {
+        Ok(Day01::part_02(lines).to_string())
     }
 
+    fn examples() -> Vec<Example> {
+        vec![
+            Example::new(1, TEST_INPUT, "142"),
+            Example::new(2, TEST_INPUT_2, "281"),
+        ]
+    }
+}
+
+impl Day01 {
     fn part_01(lines: &Vec<&str>) -> i32 {
         let total_sum = lines
             .par_iter()
@@ -140,10 +144,8 @@ mod tests {
     }
 
     #[test]
-    fn test_part_1() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day01::part_01(&lines);
-        assert_eq!(result, 142);
+    fn test_examples() {
+        crate::solution::assert_examples::<Day01>();
     }
 
     #[test]
@@ -168,11 +170,4 @@ mod tests {
             );
         }
     }
-
-    #[test]
-    fn test_part_2() {
-        let lines: Vec<&str> = TEST_INPUT_2.lines().collect();
-        let result = Day01::part_02(&lines);
-        assert_eq!(result, 281);
-    }
 }
@@ -1,44 +1,57 @@
-use std::time::Instant;
-
 use std::collections::HashMap;
 
-use crate::file_handler::FileHandler;
+use crate::error::AocError;
+use crate::parsing::{key_value, list, signed_integers};
+use crate::solution::{Example, Solution};
 
 pub struct Day02 {}
 
-impl Day02 {
-    pub fn run() -> (String, String, String, u128, u128) {
-        let first_input = FileHandler::read("./src/Y2023/inputs/day_02_1.txt");
+/// The id of a game and the cubes of every set revealed in it.
+pub type Game = (i32, Vec<HashMap<CubeType, i32>>);
 
-        let lines: Vec<&str> = first_input.split('\n').collect();
+impl Solution for Day02 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 2;
 
-        let start_1 = Instant::now();
-        let result_1 = Day02::part_01(&lines);
-        let time_calculation_1 = start_1.elapsed();
+    type Input<'a> = Vec<Game>;
 
-        let start_2 = Instant::now();
-        let result_2 = Day02::part_02(&lines);
-        let time_calculation_2 = start_2.elapsed();
+    fn parse(input: &str) -> Result<Vec<Game>, AocError> {
+        input
+            .split('\n')
+            .enumerate()
+            .map(|(index, line)| {
+                parsing_input(line)
+                    .ok_or_else(|| AocError::parse(index, line, "expected `Game N: cube sets`"))
+            })
+            .collect()
+    }
+
+    fn part_one(games: &Vec<Game>) -> Result<String, AocError> {
+        Ok(Day02::part_01(games).to_string())
+    }
 
-        (
-            "Day_02".to_owned(),
-            format!("{}", result_1),
-            format!("{}", result_2),
-            time_calculation_1.as_nanos(),
-            time_calculation_2.as_nanos(),
-        )
+    fn part_two(games: &Vec<Game>) -> Result<String, AocError> {
+        Ok(Day02::part_02(games).to_string())
     }
 
+    fn examples() -> Vec<Example> {
+        vec![
+            Example::new(1, TEST_INPUT, "8"),
+            Example::new(2, TEST_INPUT, "2286"),
+        ]
+    }
+}
+
+impl Day02 {
     //only 12 red cubes, 13 green cubes, and 14 blue cubes?
-    fn part_01(lines: &Vec<&str>) -> i32 {
+    fn part_01(games: &[Game]) -> i32 {
         let mut maximum_values = HashMap::new();
         maximum_values.insert(CubeType::Red, 12);
         maximum_values.insert(CubeType::Green, 13);
         maximum_values.insert(CubeType::Blue, 14);
 
         let mut total_sum = 0;
-        for line in lines.iter() {
-            let (game_id, game_sets) = parsing_input(*line);
+        for (game_id, game_sets) in games.iter() {
             let mut possible = true;
             for game in game_sets.iter() {
                 if validate_game(&maximum_values, game) == false {
@@ -55,11 +68,10 @@ impl Day02 {
         total_sum
     }
 
-    fn part_02(lines: &Vec<&str>) -> i32 {
+    fn part_02(games: &[Game]) -> i32 {
         let mut total_sum = 0;
-        for line in lines.iter() {
-            let (_, game_sets) = parsing_input(*line);
-            let power = get_game_power(&game_sets);
+        for (_, game_sets) in games.iter() {
+            let power = get_game_power(game_sets);
 
             total_sum += power;
         }
@@ -102,50 +114,44 @@ fn validate_game(maximum_values: &HashMap<CubeType, i32>, game: &HashMap<CubeTyp
     true
 }
 
-fn parsing_input(line: &str) -> (i32, Vec<HashMap<CubeType, i32>>) {
-    let text = line.to_owned();
-    let values: Vec<&str> = text.split(": ").collect();
-    let game_id = values[0].replace("Game ", "").parse::<i32>().unwrap();
-    let game_sets_text: Vec<&str> = values[1].split("; ").collect();
+/// The game on a line, `None` if it is malformed.
+fn parsing_input(line: &str) -> Option<Game> {
+    let (game, sets) = key_value(line)?;
+    let game_id = *signed_integers::<i32>(game).first()?;
 
     let mut game_sets = vec![];
-    for set in game_sets_text.iter() {
-        let cube_set = parsing_set(*set);
+    for set in list(sets, ';') {
+        let cube_set = parsing_set(set)?;
         game_sets.push(cube_set);
     }
 
-    (game_id, game_sets)
+    Some((game_id, game_sets))
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
-enum CubeType {
+pub enum CubeType {
     Blue,
     Red,
     Green,
 }
 
 impl CubeType {
-    fn from_str(text: &str) -> CubeType {
+    fn from_str(text: &str) -> Option<CubeType> {
         match text.to_lowercase().as_str() {
-            "blue" => CubeType::Blue,
-            "green" => CubeType::Green,
-            "red" => CubeType::Red,
-            _ => panic!("Unknown cube type: {}", text),
+            "blue" => Some(CubeType::Blue),
+            "green" => Some(CubeType::Green),
+            "red" => Some(CubeType::Red),
+            _ => None,
         }
     }
 }
 
-fn parsing_set(text: &str) -> HashMap<CubeType, i32> {
-    let cube_rolls: Vec<&str> = text.split(", ").collect();
-
+fn parsing_set(text: &str) -> Option<HashMap<CubeType, i32>> {
     let mut result = HashMap::new();
-    for cubes in cube_rolls.iter() {
-        let values: Vec<&str> = (*cubes).split(" ").collect();
-        if values.len() != 2 {
-            continue;
-        }
-        let total_cubes = values[0].parse::<i32>().unwrap();
-        let cube_type = CubeType::from_str(values[1]);
+    for cubes in list(text, ',') {
+        let (total_cubes, cube_type) = cubes.split_once(' ')?;
+        let total_cubes = total_cubes.parse::<i32>().ok()?;
+        let cube_type = CubeType::from_str(cube_type)?;
 
         if let Some(value) = result.get(&cube_type) {
             result.insert(cube_type, *value + total_cubes);
@@ -154,7 +160,7 @@ fn parsing_set(text: &str) -> HashMap<CubeType, i32> {
         }
     }
 
-    result
+    Some(result)
 }
 
 const TEST_INPUT: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
@@ -172,7 +178,7 @@ mod tests {
     #[test]
     fn test_parsing() {
         let (game_id, game_sets) =
-            parsing_input("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green");
+            parsing_input("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green").unwrap();
         assert_eq!(game_id, 1);
         assert_eq!(game_sets[0][&CubeType::Blue], 3);
         assert_eq!(game_sets[0][&CubeType::Red], 4);
@@ -182,24 +188,24 @@ mod tests {
         assert_eq!(game_sets[2][&CubeType::Green], 2);
     }
 
+    #[test]
+    fn test_parsing_error() {
+        let error = Day02::parse("Game 1: 3 blue\nGame 2: 4 purple").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "cannot parse line 2 `Game 2: 4 purple`: expected `Game N: cube sets`"
+        );
+    }
+
     #[test]
     fn test_get_game_power() {
         let (game_id, game_sets) =
-            parsing_input("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green");
+            parsing_input("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green").unwrap();
         let power = get_game_power(&game_sets);
         assert_eq!(power, 48);
     }
     #[test]
-    fn test_part_1() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day02::part_01(&lines);
-        assert_eq!(result, 8);
-    }
-
-    #[test]
-    fn test_part_2() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day02::part_02(&lines);
-        assert_eq!(result, 2286);
+    fn test_examples() {
+        crate::solution::assert_examples::<Day02>();
     }
 }
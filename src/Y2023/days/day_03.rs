@@ -1,42 +1,54 @@
-use std::time::Instant;
-
 use std::collections::{HashMap, HashSet};
 
-use crate::file_handler::FileHandler;
+use crate::error::AocError;
+use crate::solution::{Example, Solution};
 
 pub struct Day03 {}
 
-impl Day03 {
-    pub fn run() -> (String, String, String, u128, u128) {
-        let first_input = FileHandler::read("./src/Y2023/inputs/day_03_1.txt");
-
-        let lines: Vec<&str> = first_input.split('\n').collect();
-
-        let start_1 = Instant::now();
-        let result_1 = Day03::part_01(&lines);
-        let time_calculation_1 = start_1.elapsed();
-
-        let start_2 = Instant::now();
-        let result_2 = Day03::part_02(&lines);
-        let time_calculation_2 = start_2.elapsed();
-
-        (
-            "Day_03".to_owned(),
-            format!("{}", result_1),
-            format!("{}", result_2),
-            time_calculation_1.as_nanos(),
-            time_calculation_2.as_nanos(),
-        )
+impl Solution for Day03 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 3;
+
+    type Input<'a> = (
+        HashMap<(i32, i32), ScehmaticType>,
+        HashMap<i32, Vec<(i32, i32)>>,
+    );
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let lines: Vec<&str> = input.split('\n').collect();
+        Ok(parsing_input(&lines))
     }
 
+    fn part_one(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day03::part_01(input).to_string())
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day03::part_02(input).to_string())
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![
+            Example::new(1, TEST_INPUT, "4361"),
+            Example::new(2, TEST_INPUT, "467835"),
+        ]
+    }
+}
+
+impl Day03 {
     //only 12 red cubes, 13 green cubes, and 14 blue cubes?
-    fn part_01(lines: &Vec<&str>) -> i32 {
-        let (grids, numbers) = parsing_input(lines);
+    fn part_01(
+        input: &(
+            HashMap<(i32, i32), ScehmaticType>,
+            HashMap<i32, Vec<(i32, i32)>>,
+        ),
+    ) -> i32 {
+        let (grids, numbers) = input;
 
         let mut total_sum = 0;
         for (value, coordinates) in numbers.iter() {
             'next_value: for interested_coord in coordinates.iter() {
-                if Day03::check_nearby_symbols(&grids, interested_coord) == true {
+                if Day03::check_nearby_symbols(grids, interested_coord) == true {
                     total_sum += value;
                     break 'next_value;
                 }
@@ -63,8 +75,13 @@ impl Day03 {
         false
     }
 
-    fn part_02(lines: &Vec<&str>) -> u128 {
-        let (grids, numbers) = parsing_input(lines);
+    fn part_02(
+        input: &(
+            HashMap<(i32, i32), ScehmaticType>,
+            HashMap<i32, Vec<(i32, i32)>>,
+        ),
+    ) -> u128 {
+        let (grids, numbers) = input;
 
         let mut reverse_numbers: HashMap<(i32, i32), i32> = HashMap::new();
         for (value, coordinates) in numbers.iter() {
@@ -106,7 +123,7 @@ impl Day03 {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-enum ScehmaticType {
+pub enum ScehmaticType {
     Digit(i32),
     Symbol(String),
     None,
@@ -244,16 +261,7 @@ mod tests {
         let (grids, numbers) = parsing_input(&lines);
     }
     #[test]
-    fn test_part_1() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day03::part_01(&lines);
-        assert_eq!(result, 4361);
-    }
-
-    #[test]
-    fn test_part_2() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day03::part_02(&lines);
-        assert_eq!(result, 467835);
+    fn test_examples() {
+        crate::solution::assert_examples::<Day03>();
     }
 }
@@ -1,39 +1,55 @@
-use std::{collections::VecDeque, time::Instant};
+use std::collections::VecDeque;
 
 use hashbrown::HashMap;
 
-use crate::file_handler::FileHandler;
+use crate::error::AocError;
+use crate::parsing::{key_value, signed_integers};
+use crate::solution::{Example, Solution};
 
 pub struct Day04 {}
 
-impl Day04 {
-    pub fn run() -> (String, String, String, u128, u128) {
-        let first_input = FileHandler::read("./src/Y2023/inputs/day_04_1.txt");
-
-        let lines: Vec<&str> = first_input.split('\n').collect();
-
-        let start_1 = Instant::now();
-        let result_1 = Day04::part_01(&lines);
-        let time_calculation_1 = start_1.elapsed();
-
-        let start_2 = Instant::now();
-        let result_2 = Day04::part_02(&lines);
-        let time_calculation_2 = start_2.elapsed();
-
-        (
-            "Day_04".to_owned(),
-            format!("{}", result_1),
-            format!("{}", result_2),
-            time_calculation_1.as_nanos(),
-            time_calculation_2.as_nanos(),
-        )
+/// The number, the winning numbers and the numbers you have of a card.
+type Card = (i32, Vec<i32>, Vec<i32>);
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 4;
+
+    type Input<'a> = Vec<Card>;
+
+    fn parse(input: &str) -> Result<Vec<Card>, AocError> {
+        input
+            .split('\n')
+            .enumerate()
+            .map(|(index, line)| {
+                parsing_card(line).ok_or_else(|| {
+                    AocError::parse(index, line, "expected `Card N: numbers | numbers`")
+                })
+            })
+            .collect()
+    }
+
+    fn part_one(cards: &Vec<Card>) -> Result<String, AocError> {
+        Ok(Day04::part_01(cards).to_string())
+    }
+
+    fn part_two(cards: &Vec<Card>) -> Result<String, AocError> {
+        Ok(Day04::part_02(cards).to_string())
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![
+            Example::new(1, TEST_INPUT, "13"),
+            Example::new(2, TEST_INPUT, "30"),
+        ]
     }
+}
 
-    fn part_01(lines: &Vec<&str>) -> u128 {
+impl Day04 {
+    fn part_01(cards: &[Card]) -> u128 {
         let mut total_points = 0;
-        for input in lines.iter() {
-            let (_, winning_numbers, numbers) = parsing_card(input);
-            let points = validate_card_winning(&winning_numbers, &numbers);
+        for (_, winning_numbers, numbers) in cards.iter() {
+            let points = validate_card_winning(winning_numbers, numbers);
 
             total_points += points;
         }
@@ -41,20 +57,19 @@ impl Day04 {
         return total_points;
     }
 
-    fn part_02(lines: &Vec<&str>) -> u128 {
+    fn part_02(cards: &[Card]) -> u128 {
         let mut card_references = HashMap::new();
         let mut process_queue: VecDeque<i32> = VecDeque::new();
 
-        let last_card_number = lines.len() as i32;
-        for input in lines.iter() {
-            let (card_number, winning_numbers, numbers) = parsing_card(input);
+        let last_card_number = cards.len() as i32;
+        for (card_number, winning_numbers, numbers) in cards.iter() {
             if let Some(copy_list) =
-                get_copy_list(card_number, last_card_number, &winning_numbers, &numbers)
+                get_copy_list(*card_number, last_card_number, winning_numbers, numbers)
             {
-                card_references.insert(card_number, copy_list);
+                card_references.insert(*card_number, copy_list);
             }
 
-            process_queue.push_back(card_number);
+            process_queue.push_back(*card_number);
         }
 
         let mut total_all_cards = last_card_number as u128;
@@ -75,36 +90,25 @@ impl Day04 {
     }
 }
 
-fn parsing_card(input: &str) -> (i32, Vec<i32>, Vec<i32>) {
-    let (card_number, input) = parsing_card_number(input);
-    let list_of_numbers: Vec<&str> = input.split(" | ").collect();
-    let winning_numbers = parsing_numbers(list_of_numbers[0]);
-    let numbers = parsing_numbers(list_of_numbers[1]);
+/// The card on a line, `None` if it is malformed.
+fn parsing_card(input: &str) -> Option<Card> {
+    let (card_number, input) = parsing_card_number(input)?;
+    let (winning_numbers, numbers) = input.split_once(" | ")?;
+    let winning_numbers = parsing_numbers(winning_numbers);
+    let numbers = parsing_numbers(numbers);
 
-    (card_number, winning_numbers, numbers)
+    Some((card_number, winning_numbers, numbers))
 }
 
-fn parsing_card_number(line: &str) -> (i32, String) {
-    let values: Vec<&str> = line.split(": ").collect();
-    let card_number = values[0]
-        .replace("Card", "")
-        .replace(" ", "")
-        .parse::<i32>()
-        .unwrap();
+fn parsing_card_number(line: &str) -> Option<(i32, String)> {
+    let (card, numbers) = key_value(line)?;
+    let card_number = *signed_integers::<i32>(card).first()?;
 
-    (card_number, values[1].to_owned())
+    Some((card_number, numbers.to_owned()))
 }
 
 fn parsing_numbers(line: &str) -> Vec<i32> {
-    let mut result = vec![];
-
-    let values: Vec<&str> = line.split(" ").collect();
-    for v in values.iter() {
-        if let Ok(number) = (*v).parse::<i32>() {
-            result.push(number);
-        }
-    }
-    result
+    signed_integers(line)
 }
 
 fn validate_card_winning(winning_numbers: &Vec<i32>, numbers: &Vec<i32>) -> u128 {
@@ -168,7 +172,7 @@ mod tests {
     #[test]
     fn test_card_number() {
         let input = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53";
-        let (card_number, remain_input) = parsing_card_number(input);
+        let (card_number, remain_input) = parsing_card_number(input).unwrap();
         assert_eq!(card_number, 1);
         assert_eq!(remain_input, "41 48 83 86 17 | 83 86  6 31 17  9 48 53");
     }
@@ -189,7 +193,7 @@ mod tests {
     #[test]
     fn test_validating_winning_card() {
         let input = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53";
-        let (card_number, winning_numbers, numbers) = parsing_card(input);
+        let (card_number, winning_numbers, numbers) = parsing_card(input).unwrap();
         let points = validate_card_winning(&winning_numbers, &numbers);
         assert_eq!(points, 8);
     }
@@ -197,7 +201,7 @@ mod tests {
     #[test]
     fn test_get_copy_card_numbers() {
         let input = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53";
-        let (card_number, winning_numbers, numbers) = parsing_card(input);
+        let (card_number, winning_numbers, numbers) = parsing_card(input).unwrap();
         let result = get_copy_list(card_number, 6, &winning_numbers, &numbers);
         assert!(result.is_some());
         assert_eq!(result, Some(VecDeque::from([2, 3, 4, 5])));
@@ -208,16 +212,7 @@ mod tests {
     }
 
     #[test]
-    fn test_part_1() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day04::part_01(&lines);
-        assert_eq!(result, 13);
-    }
-
-    #[test]
-    fn test_part_2() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day04::part_02(&lines);
-        assert_eq!(result, 30);
+    fn test_examples() {
+        crate::solution::assert_examples::<Day04>();
     }
 }
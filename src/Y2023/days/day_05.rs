@@ -1,182 +1,142 @@
-use rayon::prelude::*;
-use std::time::Instant;
-
-use crate::file_handler::FileHandler;
+use crate::error::AocError;
+use crate::interval::{Interval, IntervalSet, RangeMapper};
+use crate::parsing::blocks;
+use crate::solution::{Example, Solution};
 
 pub struct Day05 {}
 
-impl Day05 {
-    pub fn run() -> (String, String, String, u128, u128) {
-        let first_input = FileHandler::read("./src/Y2023/inputs/day_05_1.txt");
-
-        let lines: Vec<&str> = first_input.split('\n').collect();
-
-        let start_1 = Instant::now();
-        let result_1 = Day05::part_01(&lines);
-        let time_calculation_1 = start_1.elapsed();
-
-        let start_2 = Instant::now();
-        let result_2 = Day05::part_02(&lines);
-        let time_calculation_2 = start_2.elapsed();
-
-        (
-            "Day_05".to_owned(),
-            format!("{}", result_1),
-            format!("{}", result_2),
-            time_calculation_1.as_nanos(),
-            time_calculation_2.as_nanos(),
-        )
-    }
-
-    fn part_01(lines: &Vec<&str>) -> u128 {
-        let indexes = find_mapping_indexes(lines);
-        let mut data = parsing_seeds(lines[indexes[0]]);
+impl Solution for Day05 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 5;
 
-        for index in 1..7 {
-            data = mapping_data(lines, indexes[index] + 1, indexes[index + 1] - 1, &data);
-        }
-
-        data = mapping_data(lines, indexes[7] + 1, lines.len(), &data);
+    type Input<'a> = Almanac;
 
-        data.sort();
-        return *data.first().unwrap();
+    fn parse(input: &str) -> Result<Almanac, AocError> {
+        parsing_almanac(input)
     }
 
-    fn part_02(lines: &Vec<&str>) -> u128 {
-        let indexes = find_mapping_indexes(lines);
-        let seeds = parsing_seeds(lines[indexes[0]]);
-        println!("Prepare seeds");
-        let mut data = parsing_seed_ranges(&seeds);
-
-        println!("Start Mapping");
-        for index in 1..7 {
-            data = mapping_data(lines, indexes[index] + 1, indexes[index + 1] - 1, &data);
-        }
-
-        data = mapping_data(lines, indexes[7] + 1, lines.len(), &data);
-        println!("Finish Mapping");
-
-        return *data.par_iter().min().unwrap();
+    fn part_one(almanac: &Almanac) -> Result<String, AocError> {
+        Ok(Day05::part_01(almanac)?.to_string())
     }
-}
 
-fn mapping_data(
-    input: &Vec<&str>,
-    start_index: usize,
-    end_index: usize,
-    data: &Vec<u128>,
-) -> Vec<u128> {
-    let mut mappers = vec![];
-    for index in start_index..end_index {
-        let input = input[index];
-        let mapper = parsing_mapping(input);
-        mappers.push(mapper);
+    fn part_two(almanac: &Almanac) -> Result<String, AocError> {
+        Ok(Day05::part_02(almanac)?.to_string())
     }
 
-    let par_iter = data.par_iter().map(|data_number| {
-        for mapper in mappers.iter() {
-            if let Some(soil_number) = mapper.get_destination(*data_number) {
-                return soil_number;
-            }
-        }
-
-        return *data_number;
-    });
-
-    par_iter.collect()
-}
-
-fn find_mapping_indexes(input: &Vec<&str>) -> Vec<usize> {
-    let mut result = vec![];
-    let keywords = vec![
-        "seeds:",
-        "seed-to-soil map:",
-        "soil-to-fertilizer map:",
-        "fertilizer-to-water map:",
-        "water-to-light map:",
-        "light-to-temperature map:",
-        "temperature-to-humidity map:",
-        "humidity-to-location map:",
-    ];
-
-    for (index, line) in input.iter().enumerate() {
-        for key in keywords.iter() {
-            if line.contains(key) {
-                result.push(index);
-                break;
-            }
-        }
+    fn examples() -> Vec<Example> {
+        vec![
+            Example::new(1, TEST_INPUT, "35"),
+            Example::new(2, TEST_INPUT, "46"),
+        ]
     }
-
-    result
 }
 
-fn parsing_seeds(input: &str) -> Vec<u128> {
-    let values = input.replace("seeds: ", "");
-    let values: Vec<&str> = values.split(" ").collect();
-    let mut result = vec![];
-    for value in values.iter() {
-        if let Ok(number) = value.parse::<u128>() {
-            result.push(number);
-        }
+impl Day05 {
+    fn part_01(almanac: &Almanac) -> Result<i64, AocError> {
+        almanac
+            .seeds
+            .iter()
+            .map(|seed| almanac.maps.iter().fold(*seed, |value, map| map.map(value)))
+            .min()
+            .ok_or_else(|| AocError::unsolvable("the almanac has no seeds"))
     }
 
-    result
+    fn part_02(almanac: &Almanac) -> Result<i64, AocError> {
+        let locations = almanac
+            .maps
+            .iter()
+            .fold(parsing_seed_ranges(&almanac.seeds), |data, map| {
+                map.map_set(&data)
+            });
+
+        locations
+            .min()
+            .ok_or_else(|| AocError::unsolvable("the almanac has no seed ranges"))
+    }
 }
 
-fn parsing_seed_ranges(seeds: &Vec<u128>) -> Vec<u128> {
-    let mut result = vec![];
-    for index in (0..(seeds.len() - 1)).step_by(2) {
-        let start = seeds[index];
-        let end = start + seeds[index + 1];
+/// The seeds to plant and the maps from seed to location, in order.
+#[derive(Debug)]
+pub struct Almanac {
+    seeds: Vec<i64>,
+    maps: Vec<RangeMapper>,
+}
 
-        let v: Vec<_> = (start..end).collect();
-        let par_iter = v.par_iter().map(|number| *number);
-        let mut data: Vec<u128> = par_iter.collect();
+fn parsing_almanac(input: &str) -> Result<Almanac, AocError> {
+    let sections = blocks(input);
+    let Some(((index, seeds), maps)) = sections.split_first() else {
+        return Err(AocError::unsolvable("the almanac is empty"));
+    };
+    let seeds = match seeds[..] {
+        [line] => {
+            parsing_seeds(line).ok_or_else(|| AocError::parse(*index, line, "invalid seeds"))?
+        }
+        _ => {
+            return Err(AocError::parse(
+                *index,
+                seeds[0],
+                "expected a single `seeds:` line",
+            ))
+        }
+    };
 
-        result.append(&mut data);
-    }
+    let maps = maps
+        .iter()
+        .map(|(first_index, lines)| parsing_map(*first_index, lines))
+        .collect::<Result<_, _>>()?;
 
-    result
+    Ok(Almanac { seeds, maps })
 }
 
-fn parsing_mapping(input: &str) -> DataMapping {
-    let values: Vec<&str> = input.split(" ").collect();
-    DataMapping::new(
-        values[1].parse::<u128>().unwrap(),
-        values[0].parse::<u128>().unwrap(),
-        values[2].parse::<u128>().unwrap(),
-    )
+fn parsing_seeds(input: &str) -> Option<Vec<i64>> {
+    input
+        .strip_prefix("seeds:")?
+        .split_whitespace()
+        .map(|value| value.parse::<i64>().ok())
+        .collect()
 }
 
-#[derive(Debug, PartialEq, Eq)]
-struct DataMapping {
-    source: u128,
-    destination: u128,
-    range: u128,
+fn parsing_seed_ranges(seeds: &[i64]) -> IntervalSet {
+    seeds
+        .chunks_exact(2)
+        .map(|pair| Interval::with_length(pair[0], pair[1]))
+        .collect()
 }
 
-impl DataMapping {
-    fn new(source: u128, destination: u128, range: u128) -> Self {
-        Self {
-            source: source,
-            destination: destination,
-            range: range,
-        }
+/// The map whose name is on line `first_index`, every following line adding a range to it.
+fn parsing_map(first_index: usize, lines: &[&str]) -> Result<RangeMapper, AocError> {
+    if !lines[0].ends_with(" map:") {
+        return Err(AocError::parse(
+            first_index,
+            lines[0],
+            "expected a map name",
+        ));
     }
 
-    fn get_destination(&self, source: u128) -> Option<u128> {
-        let start_source = self.source;
-        let end_source = self.source + self.range;
-
-        let start_destination = self.destination;
-        if start_source <= source && source < end_source {
-            let diff = source - start_source;
-            let result = start_destination + diff;
-            Some(result)
-        } else {
-            None
-        }
+    let mut mapper = RangeMapper::new();
+    for (offset, line) in lines.iter().enumerate().skip(1) {
+        let (destination, source, range) = parsing_mapping(line).ok_or_else(|| {
+            AocError::parse(
+                first_index + offset,
+                line,
+                "expected `destination source length`",
+            )
+        })?;
+        mapper.add(Interval::with_length(source, range), destination);
+    }
+
+    Ok(mapper)
+}
+
+/// The destination start, source start and length of a range.
+fn parsing_mapping(input: &str) -> Option<(i64, i64, i64)> {
+    let values = input
+        .split_whitespace()
+        .map(|value| value.parse::<i64>().ok())
+        .collect::<Option<Vec<i64>>>()?;
+    match values[..] {
+        [destination, source, range] => Some((destination, source, range)),
+        _ => None,
     }
 }
 
@@ -222,36 +182,49 @@ mod tests {
     fn test_parsing_seeds() {
         let input = "seeds: 79 14 55 13";
         let result = parsing_seeds(input);
-        assert_eq!(result, vec![79, 14, 55, 13]);
+        assert_eq!(result, Some(vec![79, 14, 55, 13]));
+        assert_eq!(parsing_seeds("seeds: 79 x"), None);
     }
 
     #[test]
     fn test_parsing_mapping() {
         let input = "50 98 2";
         let data = parsing_mapping(input);
-        assert_eq!(data, DataMapping::new(98, 50, 2));
+        assert_eq!(data, Some((50, 98, 2)));
+        assert_eq!(parsing_mapping("50 98"), None);
     }
 
     #[test]
-    fn test_get_destination_from_mapping() {
-        let data = parsing_mapping("50 98 2");
-        assert_eq!(data.get_destination(97), None);
-        assert_eq!(data.get_destination(98), Some(50));
-        assert_eq!(data.get_destination(99), Some(51));
-        assert_eq!(data.get_destination(100), None);
+    fn test_parsing_almanac() {
+        let almanac = parsing_almanac(TEST_INPUT).unwrap();
+        assert_eq!(almanac.seeds, vec![79, 14, 55, 13]);
+        assert_eq!(almanac.maps.len(), 7);
+        assert_eq!(almanac.maps[0].map(79), 81);
+        assert_eq!(almanac.maps[0].map(98), 50);
+        assert_eq!(almanac.maps[0].map(10), 10);
+
+        let error = parsing_almanac("seeds: 1 2\n\nseed-to-soil map:\n50 98").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "cannot parse line 4 `50 98`: expected `destination source length`"
+        );
+
+        let almanac = parsing_almanac("seeds:\n\nseed-to-soil map:\n50 98 2").unwrap();
+        assert!(Day05::part_01(&almanac).is_err());
+        assert!(Day05::part_02(&almanac).is_err());
     }
 
     #[test]
-    fn test_part_1() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day05::part_01(&lines);
-        assert_eq!(result, 35);
+    fn test_seed_ranges() {
+        let ranges = parsing_seed_ranges(&[79, 14, 55, 13]);
+        assert_eq!(
+            ranges.iter().collect::<Vec<_>>(),
+            vec![Interval::new(55, 68), Interval::new(79, 93)]
+        );
     }
 
     #[test]
-    fn test_part_2() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day05::part_02(&lines);
-        assert_eq!(result, 46);
+    fn test_examples() {
+        crate::solution::assert_examples::<Day05>();
     }
 }
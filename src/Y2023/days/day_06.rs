@@ -1,37 +1,40 @@
-use std::time::Instant;
-
-use crate::file_handler::FileHandler;
+use crate::error::AocError;
+use crate::solution::{Example, Solution};
 use itertools::Itertools;
 use rayon::prelude::*;
 
 pub struct Day06 {}
 
-impl Day06 {
-    pub fn run() -> (String, String, String, u128, u128) {
-        let first_input = FileHandler::read("./src/Y2023/inputs/day_06_1.txt");
-
-        let lines: Vec<&str> = first_input.split('\n').collect();
-
-        let start_1 = Instant::now();
-        let result_1 = Day06::part_01(&lines);
-        let time_calculation_1 = start_1.elapsed();
-
-        let start_2 = Instant::now();
-        let result_2 = Day06::part_02(&lines);
-        let time_calculation_2 = start_2.elapsed();
-
-        (
-            "Day_06".to_owned(),
-            format!("{}", result_1),
-            format!("{}", result_2),
-            time_calculation_1.as_nanos(),
-            time_calculation_2.as_nanos(),
-        )
+impl Solution for Day06 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 6;
+
+    /// The duration and the record distance of every race.
+    type Input<'a> = (Vec<i32>, Vec<i32>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let lines: Vec<&str> = input.split('\n').collect();
+        parsing_input(&lines)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day06::part_01(input).to_string())
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day06::part_02(input).to_string())
     }
 
-    fn part_01(lines: &Vec<&str>) -> u128 {
-        let data = parsing_input(lines);
+    fn examples() -> Vec<Example> {
+        vec![
+            Example::new(1, TEST_INPUT, "288"),
+            Example::new(2, TEST_INPUT, "71503"),
+        ]
+    }
+}
 
+impl Day06 {
+    fn part_01(data: &(Vec<i32>, Vec<i32>)) -> u128 {
         let mut total_multiply = 1;
         for index in (0..data.0.len()) {
             let duration = data.0[index] as u128;
@@ -44,8 +47,10 @@ impl Day06 {
         return total_multiply;
     }
 
-    fn part_02(lines: &Vec<&str>) -> u128 {
-        let (duration, distance) = parsing_input_2(lines);
+    fn part_02(data: &(Vec<i32>, Vec<i32>)) -> u128 {
+        let (times, distances) = data;
+        let duration = concatenate(times);
+        let distance = concatenate(distances);
 
         let count = find_win_solutions(duration, distance);
 
@@ -73,44 +78,43 @@ fn find_win_solutions(total_duration: u128, minimum_distance: u128) -> u128 {
     total_solutions as u128
 }
 
-fn parsing_input(lines: &Vec<&str>) -> (Vec<i32>, Vec<i32>) {
-    let times = parsing_values(lines[0], "Time: ");
-    let distance = parsing_values(lines[1], "Distance: ");
-
-    (times, distance)
-}
-
-fn parsing_input_2(lines: &Vec<&str>) -> (u128, u128) {
-    let times = parsing_values(lines[0], "Time: ");
-    let distance = parsing_values(lines[1], "Distance: ");
-
-    let mut text = String::new();
-    for value in times.iter() {
-        text.push_str(&format!("{}", value));
-    }
-    let duration = text.parse::<u128>().unwrap();
-
-    let mut text = String::new();
-    for value in distance.iter() {
-        text.push_str(&format!("{}", value));
+fn parsing_input(lines: &Vec<&str>) -> Result<(Vec<i32>, Vec<i32>), AocError> {
+    let times = parsing_values(lines, 0, "Time:")?;
+    let distance = parsing_values(lines, 1, "Distance:")?;
+    if times.len() != distance.len() {
+        return Err(AocError::parse(
+            1,
+            lines[1],
+            format!("expected {} distances", times.len()),
+        ));
     }
 
-    let distance = text.parse::<u128>().unwrap();
-
-    (duration, distance)
+    Ok((times, distance))
 }
 
-fn parsing_values(input: &str, removing_prefix: &str) -> Vec<i32> {
-    let input = input.replace(removing_prefix, "");
-    let values: Vec<&str> = input.split(" ").collect();
-    let mut result = vec![];
-    for value in values.iter() {
-        if let Ok(number) = value.parse::<i32>() {
-            result.push(number);
-        }
-    }
+/// The number written by the digits of every value one after the other, as the kerning of
+/// part two reads the sheet.
+fn concatenate(values: &[i32]) -> u128 {
+    values.iter().fold(0, |number, value| {
+        let digits = value.to_string();
+        number * 10u128.pow(digits.len() as u32) + *value as u128
+    })
+}
 
-    result
+/// The numbers on the line at `index` after `prefix`.
+fn parsing_values(lines: &Vec<&str>, index: usize, prefix: &str) -> Result<Vec<i32>, AocError> {
+    let line = lines.get(index).copied().unwrap_or_default();
+    let values = line
+        .strip_prefix(prefix)
+        .ok_or_else(|| AocError::parse(index, line, format!("expected `{}`", prefix)))?;
+    values
+        .split_whitespace()
+        .map(|value| {
+            value
+                .parse::<i32>()
+                .map_err(|_| AocError::parse(index, line, format!("invalid number `{}`", value)))
+        })
+        .collect()
 }
 
 const TEST_INPUT: &str = "Time:      7  15   30
@@ -122,9 +126,15 @@ mod tests {
     #[test]
     fn test_parsing_input() {
         let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let (times, distances) = parsing_input(&lines);
+        let (times, distances) = parsing_input(&lines).unwrap();
         assert_eq!(times, vec![7, 15, 30]);
         assert_eq!(distances, vec![9, 40, 200]);
+
+        let lines = vec!["Time: 7 15", "Distance: 9 x"];
+        assert_eq!(
+            parsing_input(&lines).unwrap_err().to_string(),
+            "cannot parse line 2 `Distance: 9 x`: invalid number `x`"
+        );
     }
     #[test]
     fn test_find_win_solutions() {
@@ -139,24 +149,15 @@ mod tests {
     }
 
     #[test]
-    fn test_parsing_input_2() {
+    fn test_concatenate() {
         let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let (times, distances) = parsing_input_2(&lines);
-        assert_eq!(times, 71530);
-        assert_eq!(distances, 940200);
+        let (times, distances) = parsing_input(&lines).unwrap();
+        assert_eq!(concatenate(&times), 71530);
+        assert_eq!(concatenate(&distances), 940200);
     }
 
     #[test]
-    fn test_part_1() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day06::part_01(&lines);
-        assert_eq!(result, 288);
-    }
-
-    #[test]
-    fn test_part_2() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day06::part_02(&lines);
-        assert_eq!(result, 71503);
+    fn test_examples() {
+        crate::solution::assert_examples::<Day06>();
     }
 }
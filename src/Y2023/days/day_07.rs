@@ -1,37 +1,42 @@
-use std::time::Instant;
-
 use hashbrown::HashMap;
 use rayon::iter::{IndexedParallelIterator, IntoParallelRefIterator, ParallelIterator};
 
-use crate::file_handler::FileHandler;
+use crate::error::AocError;
+use crate::solution::{Example, Solution};
 
 pub struct Day07 {}
 
-impl Day07 {
-    pub fn run() -> (String, String, String, u128, u128) {
-        let first_input = FileHandler::read("./src/Y2023/inputs/day_07_1.txt");
-
-        let lines: Vec<&str> = first_input.split('\n').collect();
-
-        let start_1 = Instant::now();
-        let result_1 = Day07::part_01(&lines);
-        let time_calculation_1 = start_1.elapsed();
-
-        let start_2 = Instant::now();
-        let result_2 = Day07::part_02(&lines);
-        let time_calculation_2 = start_2.elapsed();
-
-        (
-            "Day_07".to_owned(),
-            format!("{}", result_1),
-            format!("{}", result_2),
-            time_calculation_1.as_nanos(),
-            time_calculation_2.as_nanos(),
-        )
+impl Solution for Day07 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 7;
+
+    /// The cards and the bid of every hand.
+    type Input<'a> = Vec<(&'a str, u128)>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let lines: Vec<&str> = input.split('\n').collect();
+        parse_bids(&lines)
+    }
+
+    fn part_one(bids: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day07::part_01(bids).to_string())
     }
 
-    fn part_01(lines: &Vec<&str>) -> u128 {
-        let mut hands = parse_input(lines);
+    fn part_two(bids: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day07::part_02(bids).to_string())
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![
+            Example::new(1, TEST_INPUT, "6440"),
+            Example::new(2, TEST_INPUT, "5905"),
+        ]
+    }
+}
+
+impl Day07 {
+    fn part_01(bids: &Vec<(&str, u128)>) -> u128 {
+        let mut hands = parse_input(bids);
         hands.sort();
 
         hands
@@ -41,8 +46,8 @@ impl Day07 {
             .sum()
     }
 
-    fn part_02(lines: &Vec<&str>) -> u128 {
-        let mut hands = parse_input_with_joker(lines);
+    fn part_02(bids: &Vec<(&str, u128)>) -> u128 {
+        let mut hands = parse_input_with_joker(bids);
         hands.sort();
 
         hands
@@ -53,29 +58,32 @@ impl Day07 {
     }
 }
 
-fn parse_input(lines: &Vec<&str>) -> Vec<Hand> {
+fn parse_bids<'a>(lines: &Vec<&'a str>) -> Result<Vec<(&'a str, u128)>, AocError> {
     lines
         .iter()
-        .map(|input| {
-            let values: Vec<&str> = input.split(" ").collect();
-            let bet = values[1].parse::<u128>().unwrap();
-            let hand = Hand::new(parse_cards(values[0]), bet);
+        .enumerate()
+        .map(|(index, input)| {
+            let invalid = || AocError::parse(index, input, "expected `<5 cards> <bid>`");
+            let (cards, bet) = input.split_once(" ").ok_or_else(invalid)?;
+            let bet = bet.parse::<u128>().map_err(|_| invalid())?;
+            if cards.len() != 5 || !cards.chars().all(|card| "23456789TJQKA".contains(card)) {
+                return Err(invalid());
+            }
 
-            hand
+            Ok((cards, bet))
         })
         .collect()
 }
 
-fn parse_input_with_joker(lines: &Vec<&str>) -> Vec<Hand> {
-    lines
-        .iter()
-        .map(|input| {
-            let values: Vec<&str> = input.split(" ").collect();
-            let bet = values[1].parse::<u128>().unwrap();
-            let hand = Hand::new(parse_cards_with_joker(values[0]), bet);
+fn parse_input(bids: &Vec<(&str, u128)>) -> Vec<Hand> {
+    bids.iter()
+        .map(|(cards, bet)| Hand::new(parse_cards(cards), *bet))
+        .collect()
+}
 
-            hand
-        })
+fn parse_input_with_joker(bids: &Vec<(&str, u128)>) -> Vec<Hand> {
+    bids.iter()
+        .map(|(cards, bet)| Hand::new(parse_cards_with_joker(cards), *bet))
         .collect()
 }
 
@@ -326,16 +334,7 @@ mod tests {
     }
 
     #[test]
-    fn test_part_1() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day07::part_01(&lines);
-        assert_eq!(result, 6440);
-    }
-
-    #[test]
-    fn test_part_2() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day07::part_02(&lines);
-        assert_eq!(result, 5905);
+    fn test_examples() {
+        crate::solution::assert_examples::<Day07>();
     }
 }
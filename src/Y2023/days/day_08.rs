@@ -1,5 +1,3 @@
-use std::time::Instant;
-
 use std::collections::{HashMap, HashSet};
 
 use num::Integer;
@@ -9,53 +7,62 @@ use rayon::{
     str::ParallelString,
 };
 
-use crate::file_handler::FileHandler;
+use crate::error::AocError;
+use crate::solution::{Example, Solution};
 
 pub struct Day08 {}
 
-impl Day08 {
-    pub fn run() -> (String, String, String, u128, u128) {
-        let first_input = FileHandler::read("./src/Y2023/inputs/day_08_1.txt");
-
-        let lines: Vec<&str> = first_input.split('\n').collect();
-
-        let start_1 = Instant::now();
-        let result_1 = Day08::part_01(&lines);
-        let time_calculation_1 = start_1.elapsed();
-
-        let start_2 = Instant::now();
-        let result_2 = Day08::part_02(&lines);
-        let time_calculation_2 = start_2.elapsed();
-
-        (
-            "Day_08".to_owned(),
-            format!("{}", result_1),
-            format!("{}", result_2),
-            time_calculation_1.as_nanos(),
-            time_calculation_2.as_nanos(),
-        )
+impl Solution for Day08 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 8;
+
+    type Input<'a> = (Vec<i32>, HashMap<String, (String, String)>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let lines: Vec<&str> = input.split('\n').collect();
+        parse_input(&lines)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day08::part_01(input)?.to_string())
     }
 
-    fn part_01(lines: &Vec<&str>) -> u128 {
-        let (commands, references) = parse_input(lines);
+    fn part_two(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day08::part_02(input)?.to_string())
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![
+            Example::new(1, TEST_INPUT, "2"),
+            Example::new(2, TEST_INPUT_2, "6"),
+        ]
+    }
+}
+
+impl Day08 {
+    fn part_01(input: &(Vec<i32>, HashMap<String, (String, String)>)) -> Result<u128, AocError> {
+        let (commands, references) = input;
 
-        find_total_steps(&commands, &references, "AAA")
+        find_total_steps(commands, references, "AAA")
     }
 
-    fn part_02(lines: &Vec<&str>) -> u128 {
-        let (commands, references) = parse_input(lines);
-        let start_values = find_all_start_values(&references);
+    fn part_02(input: &(Vec<i32>, HashMap<String, (String, String)>)) -> Result<u128, AocError> {
+        let (commands, references) = input;
+        let start_values = find_all_start_values(references);
         let total_steps: Vec<u128> = start_values
             .par_iter()
-            .map(|start_value| find_total_steps(&commands, &references, start_value))
-            .collect();
+            .map(|start_value| find_total_steps(commands, references, start_value))
+            .collect::<Result<_, _>>()?;
+        if total_steps.is_empty() {
+            return Err(AocError::unsolvable("no node ends with `A`"));
+        }
 
         let mut first = total_steps[0];
         for index in 1..total_steps.len() {
             first = first.lcm(&total_steps[index]);
         }
 
-        first
+        Ok(first)
     }
 }
 
@@ -63,36 +70,48 @@ fn find_total_steps(
     commands: &Vec<i32>,
     references: &HashMap<String, (String, String)>,
     start_value: &str,
-) -> u128 {
+) -> Result<u128, AocError> {
+    let missing_node = |node: &str| AocError::unsolvable(format!("node `{}` is not defined", node));
+    if commands.is_empty() {
+        return Err(AocError::unsolvable("there are no commands"));
+    }
+
+    // After visiting every (node, command) pair once the walk can only repeat itself.
+    let max_steps = (commands.len() * references.len()) as u128;
     let mut total_step = 0;
-    let mut current_navigation = references.get(start_value).clone().unwrap();
+    let mut current_navigation = references
+        .get(start_value)
+        .ok_or_else(|| missing_node(start_value))?;
     let mut index = 0;
     loop {
-        let next_command = if let Some(command) = commands.get(index) {
-            total_step += 1;
-            if *command == LEFT {
-                current_navigation.0.to_owned()
-            } else if *command == RIGHT {
-                current_navigation.1.to_owned()
-            } else {
-                panic!("It should not reach here");
-            }
+        total_step += 1;
+        let next_command = if commands[index] == LEFT {
+            &current_navigation.0
         } else {
-            panic!("It should not reach here");
+            &current_navigation.1
         };
 
-        if next_command.chars().last().unwrap() == 'Z' {
+        if next_command.ends_with('Z') {
             break;
+        } else if total_step > max_steps {
+            return Err(AocError::unsolvable(format!(
+                "`{}` never reaches a node ending with `Z`",
+                start_value
+            )));
         } else {
-            current_navigation = references.get(&next_command).clone().unwrap();
+            current_navigation = references
+                .get(next_command)
+                .ok_or_else(|| missing_node(next_command))?;
             index = (index + 1) % commands.len();
         }
     }
 
-    return total_step;
+    Ok(total_step)
 }
 
-fn parse_input(input: &Vec<&str>) -> (Vec<i32>, HashMap<String, (String, String)>) {
+fn parse_input(
+    input: &Vec<&str>,
+) -> Result<(Vec<i32>, HashMap<String, (String, String)>), AocError> {
     let commands = input[0];
     let references: Vec<&str> = input
         .par_iter()
@@ -100,33 +119,39 @@ fn parse_input(input: &Vec<&str>) -> (Vec<i32>, HashMap<String, (String, String)
         .filter_map(|(index, value)| if index < 2 { None } else { Some(*value) })
         .collect();
 
-    (parse_commands(commands), parse_references(&references))
+    Ok((parse_commands(commands)?, parse_references(&references)?))
 }
 
 const LEFT: i32 = -1;
 const RIGHT: i32 = -3;
-fn parse_commands(input: &str) -> Vec<i32> {
+fn parse_commands(input: &str) -> Result<Vec<i32>, AocError> {
     input
         .par_chars()
         .map(|c| match c {
-            'L' => LEFT,
-            'R' => RIGHT,
-            _ => 0,
+            'L' => Ok(LEFT),
+            'R' => Ok(RIGHT),
+            _ => Err(AocError::parse(
+                0,
+                input,
+                format!("unknown command `{}`", c),
+            )),
         })
         .collect()
 }
 
-fn parse_references(input: &Vec<&str>) -> HashMap<String, (String, String)> {
+/// The references start on the third line of the input, after the commands and a blank line.
+fn parse_references(input: &Vec<&str>) -> Result<HashMap<String, (String, String)>, AocError> {
     input
         .par_iter()
-        .map(|text| {
-            let values: Vec<&str> = (*text).split(" = ").collect();
-            let key = values[0].to_owned();
+        .enumerate()
+        .map(|(index, text)| {
+            let invalid = || AocError::parse(index + 2, text, "expected `AAA = (BBB, CCC)`");
+            let (key, right) = (*text).split_once(" = ").ok_or_else(invalid)?;
 
-            let right = values[1].replace("(", "").replace(")", "").replace(" ", "");
-            let navigation: Vec<&str> = right.split(",").collect();
+            let right = right.replace("(", "").replace(")", "").replace(" ", "");
+            let (left, right) = right.split_once(",").ok_or_else(invalid)?;
 
-            (key, (navigation[0].to_owned(), navigation[1].to_owned()))
+            Ok((key.to_owned(), (left.to_owned(), right.to_owned())))
         })
         .collect()
 }
@@ -185,7 +210,7 @@ mod tests {
     #[test]
     fn test_find_start_values() {
         let lines: Vec<&str> = TEST_INPUT_2.lines().collect();
-        let (_, references) = parse_input(&lines);
+        let (_, references) = parse_input(&lines).unwrap();
 
         let result = find_all_start_values(&references);
         println!("{:?}", result);
@@ -195,8 +220,9 @@ mod tests {
 
     #[test]
     fn test_parse_commands() {
-        let result = parse_commands("RL");
+        let result = parse_commands("RL").unwrap();
         assert_eq!(result, vec![RIGHT, LEFT]);
+        assert!(parse_commands("RXL").is_err());
     }
 
     #[test]
@@ -208,20 +234,28 @@ mod tests {
             .filter_map(|(index, value)| if index < 2 { None } else { Some(*value) })
             .collect();
 
-        let result = parse_references(&references);
+        let result = parse_references(&references).unwrap();
         assert_eq!(result["AAA"], (String::from("BBB"), String::from("CCC")));
     }
     #[test]
-    fn test_part_1() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day08::part_01(&lines);
-        assert_eq!(result, 2);
+    fn test_examples() {
+        crate::solution::assert_examples::<Day08>();
     }
 
     #[test]
-    fn test_part_2() {
-        let lines: Vec<&str> = TEST_INPUT_2.lines().collect();
-        let result = Day08::part_02(&lines);
-        assert_eq!(result, 6);
+    fn test_errors() {
+        let lines: Vec<&str> = TEST_INPUT.lines().collect();
+        let (commands, mut references) = parse_input(&lines).unwrap();
+        references.remove("CCC");
+        assert!(matches!(
+            Day08::part_01(&(commands, references)),
+            Err(AocError::Unsolvable(_))
+        ));
+
+        let lines = vec!["RL", "", "AAA = (BBB, CCC)", "BBB -> DDD"];
+        assert!(matches!(
+            parse_input(&lines),
+            Err(AocError::Parse { line: 4, .. })
+        ));
     }
 }
@@ -1,49 +1,54 @@
-use std::{collections::VecDeque, time::Instant};
+use std::collections::VecDeque;
 
 use rayon::{
-    iter::{IntoParallelRefIterator, IntoParallelRefMutIterator, ParallelIterator},
+    iter::{
+        IndexedParallelIterator, IntoParallelRefIterator, IntoParallelRefMutIterator,
+        ParallelIterator,
+    },
     str::ParallelString,
 };
 
-use crate::file_handler::FileHandler;
+use crate::error::AocError;
+use crate::solution::{Example, Solution};
 
 pub struct Day09 {}
 
-impl Day09 {
-    pub fn run() -> (String, String, String, u128, u128) {
-        let first_input = FileHandler::read("./src/Y2023/inputs/day_09_1.txt");
+impl Solution for Day09 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 9;
 
-        let lines: Vec<&str> = first_input.split('\n').collect();
+    type Input<'a> = Vec<Vec<i128>>;
 
-        let start_1 = Instant::now();
-        let result_1 = Day09::part_01(&lines);
-        let time_calculation_1 = start_1.elapsed();
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let lines: Vec<&str> = input.split('\n').collect();
+        parse_input(&lines)
+    }
 
-        let start_2 = Instant::now();
-        let result_2 = Day09::part_02(&lines);
-        let time_calculation_2 = start_2.elapsed();
+    fn part_one(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day09::part_01(input).to_string())
+    }
 
-        (
-            "Day_09".to_owned(),
-            format!("{}", result_1),
-            format!("{}", result_2),
-            time_calculation_1.as_nanos(),
-            time_calculation_2.as_nanos(),
-        )
+    fn part_two(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day09::part_02(input).to_string())
     }
 
-    fn part_01(lines: &Vec<&str>) -> i128 {
-        let reports = parse_input(lines);
+    fn examples() -> Vec<Example> {
+        vec![
+            Example::new(1, TEST_INPUT, "114"),
+            Example::new(2, TEST_INPUT, "2"),
+        ]
+    }
+}
 
+impl Day09 {
+    fn part_01(reports: &Vec<Vec<i128>>) -> i128 {
         reports
             .iter()
             .map(|input| process_extrapolated(input))
             .sum()
     }
 
-    fn part_02(lines: &Vec<&str>) -> i128 {
-        let reports = parse_input(lines);
-
+    fn part_02(reports: &Vec<Vec<i128>>) -> i128 {
         reports
             .iter()
             .map(|input| process_extrapolated_backward(input))
@@ -118,8 +123,14 @@ fn find_extrapolated(prev: Option<Vec<i128>>, current: &mut Vec<i128>) {
     }
 }
 
-fn parse_input(input: &Vec<&str>) -> Vec<Vec<i128>> {
-    input.par_iter().map(|v| parse_line(*v)).collect()
+fn parse_input(input: &Vec<&str>) -> Result<Vec<Vec<i128>>, AocError> {
+    input
+        .par_iter()
+        .enumerate()
+        .map(|(index, v)| {
+            parse_line(v).ok_or_else(|| AocError::parse(index, v, "expected numbers"))
+        })
+        .collect()
 }
 
 fn find_different_step(numbers: &Vec<i128>) -> Vec<i128> {
@@ -129,17 +140,17 @@ fn find_different_step(numbers: &Vec<i128>) -> Vec<i128> {
         .collect()
 }
 
-fn parse_line(input: &str) -> Vec<i128> {
-    input
-        .split(" ")
-        .filter_map(|c| {
-            if let Ok(v) = c.parse::<i128>() {
-                Some(v)
-            } else {
-                None
-            }
-        })
-        .collect()
+/// The numbers of a history, `None` if it is empty or holds anything else.
+fn parse_line(input: &str) -> Option<Vec<i128>> {
+    let values: Vec<i128> = input
+        .split_whitespace()
+        .map(|c| c.parse::<i128>().ok())
+        .collect::<Option<Vec<i128>>>()?;
+    if values.is_empty() {
+        None
+    } else {
+        Some(values)
+    }
 }
 
 const TEST_INPUT: &str = "0 3 6 9 12 15
@@ -173,36 +184,27 @@ mod tests {
 
     #[test]
     fn test_parser() {
-        let result = parse_line("0 3 6 9 12 15");
+        let result = parse_line("0 3 6 9 12 15").unwrap();
         assert_eq!(result, vec![0, 3, 6, 9, 12, 15]);
     }
 
     #[test]
     fn test_different_steps() {
-        let input = parse_line("0 3 6 9 12 15");
+        let input = parse_line("0 3 6 9 12 15").unwrap();
         let result = find_different_step(&input);
         assert_eq!(result, vec![3, 3, 3, 3, 3]);
 
-        let input = parse_line("1 3 6 10 15 21");
+        let input = parse_line("1 3 6 10 15 21").unwrap();
         let result = find_different_step(&input);
         assert_eq!(result, vec![2, 3, 4, 5, 6]);
 
-        let input = parse_line("10 13 16 21 30 45");
+        let input = parse_line("10 13 16 21 30 45").unwrap();
         let result = find_different_step(&input);
         assert_eq!(result, vec![3, 3, 5, 9, 15]);
     }
 
     #[test]
-    fn test_part_1() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day09::part_01(&lines);
-        assert_eq!(result, 114);
-    }
-
-    #[test]
-    fn test_part_2() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day09::part_02(&lines);
-        assert_eq!(result, 2);
+    fn test_examples() {
+        crate::solution::assert_examples::<Day09>();
     }
 }
@@ -1,44 +1,49 @@
-use std::{collections::VecDeque, time::Instant};
+use std::collections::VecDeque;
 
 use geo::{polygon, Contains, LineString, Point, Polygon};
 use hashbrown::{HashMap, HashSet};
 use rayon::iter::{IndexedParallelIterator, IntoParallelRefIterator, ParallelIterator};
 use rayon::prelude::*;
 
-use crate::file_handler::FileHandler;
+use crate::error::AocError;
+use crate::solution::{Example, Solution};
 
 pub struct Day10 {}
 
-impl Day10 {
-    pub fn run() -> (String, String, String, u128, u128) {
-        let first_input = FileHandler::read("./src/Y2023/inputs/day_10_1.txt");
-
-        let lines: Vec<&str> = first_input.split('\n').collect();
-
-        let start_1 = Instant::now();
-        let result_1 = Day10::part_01(&lines);
-        let time_calculation_1 = start_1.elapsed();
-
-        let start_2 = Instant::now();
-        let result_2 = Day10::part_02(&lines);
-        let time_calculation_2 = start_2.elapsed();
-
-        (
-            "Day_10".to_owned(),
-            format!("{}", result_1),
-            format!("{}", result_2),
-            time_calculation_1.as_nanos(),
-            time_calculation_2.as_nanos(),
-        )
+impl Solution for Day10 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 10;
+
+    type Input<'a> = Grids;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let lines: Vec<&str> = input.split('\n').collect();
+        Grids::new(&lines)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day10::part_01(input).to_string())
     }
 
-    fn part_01(lines: &Vec<&str>) -> i32 {
-        let grid = Grids::new(lines);
+    fn part_two(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day10::part_02(input).to_string())
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![
+            Example::new(1, TEST_INPUT, "4"),
+            Example::new(2, TEST_INPUT, "1"),
+        ]
+    }
+}
+
+impl Day10 {
+    fn part_01(grid: &Grids) -> i32 {
         return grid.process();
     }
 
-    fn part_02(lines: &Vec<&str>) -> i32 {
-        let mut grid = Grids::new(lines);
+    fn part_02(grid: &Grids) -> i32 {
+        let mut grid = grid.clone();
         grid.travel_pipes();
 
         grid.stray_pipes
@@ -48,7 +53,8 @@ impl Day10 {
     }
 }
 
-struct Grids {
+#[derive(Clone)]
+pub struct Grids {
     width: usize,
     height: usize,
     starting_point: (i32, i32),
@@ -205,7 +211,20 @@ impl Grids {
         self.stray_pipes = stray_pipes;
     }
 
-    fn new(input: &Vec<&str>) -> Self {
+    fn new(input: &Vec<&str>) -> Result<Self, AocError> {
+        for (index, line) in input.iter().enumerate() {
+            if let Some(tile) = line.chars().find(|c| !"|-LJ7F.S".contains(*c)) {
+                return Err(AocError::parse(
+                    index,
+                    line,
+                    format!("unknown tile `{}`", tile),
+                ));
+            }
+        }
+        if !input.iter().any(|line| line.contains('S')) {
+            return Err(AocError::unsolvable("the map has no starting point `S`"));
+        }
+
         let height = input.len();
         let width = input[0].len();
         let mut units = HashMap::new();
@@ -243,7 +262,7 @@ impl Grids {
             units.insert(starting_point, node);
         }
 
-        Self {
+        Ok(Self {
             width: width,
             height: height,
             starting_point: starting_point,
@@ -251,7 +270,7 @@ impl Grids {
             connected_pipes: vec![],
             stray_pipes: vec![],
             polygon: None,
-        }
+        })
     }
 
     fn get_starting_point(&self) -> Option<&GridUnit> {
@@ -353,7 +372,7 @@ mod tests {
     #[test]
     fn test_enclose() {
         let input: Vec<&str> = TEST_INPUT.lines().collect();
-        let mut grid = Grids::new(&input);
+        let mut grid = Grids::new(&input).unwrap();
         grid.travel_pipes();
 
         for coord in grid.stray_pipes.iter() {
@@ -366,7 +385,7 @@ mod tests {
     #[test]
     fn test_parsing_input() {
         let input: Vec<&str> = TEST_INPUT.lines().collect();
-        let grid = Grids::new(&input);
+        let grid = Grids::new(&input).unwrap();
         assert_eq!(grid.starting_point, (1, 1));
 
         if let Some(node) = grid.get_starting_point() {
@@ -378,16 +397,7 @@ mod tests {
     }
 
     #[test]
-    fn test_part_1() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day10::part_01(&lines);
-        assert_eq!(result, 4);
-    }
-
-    #[test]
-    fn test_part_2() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day10::part_02(&lines);
-        assert_eq!(result, 1);
+    fn test_examples() {
+        crate::solution::assert_examples::<Day10>();
     }
 }
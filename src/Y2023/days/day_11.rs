@@ -1,37 +1,41 @@
-use std::time::Instant;
-
 use rayon::iter::{IndexedParallelIterator, IntoParallelRefIterator, ParallelIterator};
 use std::collections::{HashMap, HashSet};
 
-use crate::file_handler::FileHandler;
+use crate::error::AocError;
+use crate::solution::{Example, Solution};
 
 pub struct Day11 {}
 
-impl Day11 {
-    pub fn run() -> (String, String, String, u128, u128) {
-        let first_input = FileHandler::read("./src/Y2023/inputs/day_11_1.txt");
-
-        let lines: Vec<&str> = first_input.split('\n').collect();
-
-        let start_1 = Instant::now();
-        let result_1 = Day11::part_01(&lines);
-        let time_calculation_1 = start_1.elapsed();
-
-        let start_2 = Instant::now();
-        let result_2 = Day11::part_02(&lines);
-        let time_calculation_2 = start_2.elapsed();
-
-        (
-            "Day_11".to_owned(),
-            format!("{}", result_1),
-            format!("{}", result_2),
-            time_calculation_1.as_nanos(),
-            time_calculation_2.as_nanos(),
-        )
+impl Solution for Day11 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 11;
+
+    type Input<'a> = Universe;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let lines: Vec<&str> = input.split('\n').collect();
+        Ok(Universe::new(&lines))
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day11::part_01(input).to_string())
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day11::part_02(input).to_string())
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![
+            Example::new(1, TEST_INPUT, "374"),
+            Example::new(2, TEST_INPUT, "82000210"),
+        ]
     }
+}
 
-    fn part_01(lines: &Vec<&str>) -> i128 {
-        let mut universe = Universe::new(lines);
+impl Day11 {
+    fn part_01(universe: &Universe) -> i128 {
+        let mut universe = universe.clone();
         universe.expand();
 
         let pairs = universe.get_galaxy_pairs();
@@ -48,8 +52,8 @@ impl Day11 {
             .sum()
     }
 
-    fn part_02(lines: &Vec<&str>) -> i128 {
-        let mut universe = Universe::new(lines);
+    fn part_02(universe: &Universe) -> i128 {
+        let mut universe = universe.clone();
         universe.expand_in_name(1000000);
 
         let pairs = universe.get_galaxy_pairs();
@@ -67,7 +71,8 @@ impl Day11 {
     }
 }
 
-struct Universe {
+#[derive(Clone)]
+pub struct Universe {
     width: usize,
     height: usize,
     grids: HashMap<(i128, i128), String>,
@@ -413,16 +418,7 @@ mod tests {
     }
 
     #[test]
-    fn test_part_1() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day11::part_01(&lines);
-        assert_eq!(result, 374);
-    }
-
-    #[test]
-    fn test_part_2() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day11::part_02(&lines);
-        assert_eq!(result, 2286);
+    fn test_examples() {
+        crate::solution::assert_examples::<Day11>();
     }
 }
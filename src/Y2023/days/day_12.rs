@@ -1,97 +1,292 @@
-use crate::file_handler::FileHandler;
-use itertools::Itertools;
+use crate::error::AocError;
+use crate::solution::{configure_each, unknown_parameter, Example, Solution};
 use rayon::prelude::*;
-use std::collections::{HashMap, HashSet};
-use std::time::Instant;
+use std::collections::HashMap;
 
 pub struct Day12 {}
 
-impl Day12 {
-    pub fn run() -> (String, String, String, u128, u128) {
-        let first_input = FileHandler::read("./src/Y2023/inputs/day_12_1.txt");
-
-        let lines: Vec<&str> = first_input.split('\n').collect();
-
-        let start_1 = Instant::now();
-        let result_1 = Day12::part_01(&lines);
-        let time_calculation_1 = start_1.elapsed();
-
-        let start_2 = Instant::now();
-        let result_2 = Day12::part_02(&lines);
-        let time_calculation_2 = start_2.elapsed();
-
-        (
-            "Day_12".to_owned(),
-            format!("{}", result_1),
-            format!("{}", result_2),
-            time_calculation_1.as_nanos(),
-            time_calculation_2.as_nanos(),
-        )
-    }
+/// The records of the springs, and whether part one writes out their arrangements.
+#[derive(Debug)]
+pub struct Springs {
+    records: Vec<Record>,
+    enumerate: bool,
+}
 
-    fn part_01(lines: &Vec<&str>) -> i32 {
-        lines
-            .par_iter()
-            .map(|input| {
-                let (conditions, hints) = parse_line(input);
+impl Solution for Day12 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 12;
 
-                let value = filling_ex(conditions.clone(), &hints, 0, 0, conditions.len());
+    type Input<'a> = Springs;
 
-                value
+    fn parse(input: &str) -> Result<Springs, AocError> {
+        let records = input
+            .split('\n')
+            .enumerate()
+            .map(|(index, line)| {
+                parse_line(line)
+                    .ok_or_else(|| AocError::parse(index, line, "expected springs and group sizes"))
             })
-            .sum()
+            .collect::<Result<_, _>>()?;
+
+        Ok(Springs {
+            records,
+            enumerate: false,
+        })
     }
 
-    fn part_02(lines: &Vec<&str>) -> i32 {
-        lines
-            .par_iter()
-            .map(|input| {
-                let (conditions, hints) = parse_line(input);
-                let (conditions, hints) = unfold(&conditions, &hints);
+    fn part_one(springs: &Springs) -> Result<String, AocError> {
+        if springs.enumerate {
+            check_arrangements(&springs.records)?;
+        }
 
-                let value = filling_ex(conditions.clone(), &hints, 0, 0, conditions.len());
+        Ok(Day12::part_01(&springs.records).to_string())
+    }
 
-                value
-            })
-            .sum()
+    fn part_two(springs: &Springs) -> Result<String, AocError> {
+        Ok(Day12::part_02(&springs.records).to_string())
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![
+            Example::new(1, TEST_INPUT, "21"),
+            Example::new(2, TEST_INPUT, "525152"),
+        ]
+    }
+
+    /// `enumerate` set to 1 makes part one write every arrangement of the folded records to
+    /// stderr and check them against the counts. Unfolded records have far too many of them.
+    fn configure(springs: &mut Springs, params: &[(&str, i64)]) -> Result<(), AocError> {
+        configure_each(params, |name, value| {
+            match name {
+                "enumerate" => springs.enumerate = value != 0,
+                _ => return Err(unknown_parameter(name)),
+            }
+
+            Ok(())
+        })
     }
 }
 
-fn unfold(conditions: &Vec<ConditionType>, hints: &Vec<usize>) -> (Vec<ConditionType>, Vec<usize>) {
+impl Day12 {
+    fn part_01(records: &[Record]) -> u64 {
+        total_arrangements(records, 1)
+    }
+
+    fn part_02(records: &[Record]) -> u64 {
+        total_arrangements(records, 5)
+    }
+}
+
+/// The recorded condition of every spring of a row and the sizes of its damaged groups.
+pub type Record = (Vec<ConditionType>, Vec<usize>);
+
+/// The sum of the arrangements of every record once unfolded `factor` times.
+fn total_arrangements(records: &[Record], factor: usize) -> u64 {
+    records
+        .par_iter()
+        .map(|(conditions, hints)| {
+            let (conditions, hints) = unfold(conditions, hints, factor);
+            count_arrangements(&conditions, &hints)
+        })
+        .sum()
+}
+
+/// Repeats the conditions `factor` times joined by an unknown spring, and the hints with them.
+fn unfold(
+    conditions: &[ConditionType],
+    hints: &[usize],
+    factor: usize,
+) -> (Vec<ConditionType>, Vec<usize>) {
     let mut new_conditions = vec![];
-    (0..5).for_each(|index| {
+    (0..factor).for_each(|index| {
         if index > 0 {
-            new_conditions.append(&mut vec![ConditionType::Unknown]);
+            new_conditions.push(ConditionType::Unknown);
         }
 
-        new_conditions.append(&mut conditions.clone());
+        new_conditions.extend_from_slice(conditions);
     });
 
-    let mut new_hints = vec![];
-    (0..5).for_each(|_| new_hints.append(&mut hints.clone()));
+    let new_hints = hints.repeat(factor);
 
     (new_conditions, new_hints)
 }
 
-fn unfold_text(input: &str) {}
+/// How many ways the unknown springs can be filled in so the damaged runs match `hints`.
+fn count_arrangements(conditions: &[ConditionType], hints: &[usize]) -> u64 {
+    Arrangements::new(conditions, hints).count_from(0, 0, 0)
+}
+
+/// Every arrangement written out with `#` and `.`. Only branches that still have arrangements
+/// are followed, so it is as slow as their number.
+fn enumerate_arrangements(conditions: &[ConditionType], hints: &[usize]) -> Vec<String> {
+    let mut arrangements = Arrangements::new(conditions, hints);
+    let mut result = vec![];
+    let mut current = vec![];
+    arrangements.enumerate_from((0, 0, 0), &mut current, &mut result);
+
+    result
+}
+
+/// Writes the arrangements of every record to stderr, failing on one that does not match its
+/// hints or on a record whose count differs from the arrangements written out.
+fn check_arrangements(records: &[Record]) -> Result<(), AocError> {
+    for (conditions, hints) in records {
+        let arrangements = enumerate_arrangements(conditions, hints);
+        let record: String = conditions
+            .iter()
+            .map(|condition| condition.to_string())
+            .collect();
+        eprintln!("{} {:?}: {}", record, hints, arrangements.len());
+
+        for arrangement in &arrangements {
+            eprintln!("  {}", arrangement);
+            if !self_check_result(arrangement, hints) {
+                return Err(AocError::unsolvable(format!(
+                    "`{}` does not match the groups of `{}`",
+                    arrangement, record
+                )));
+            }
+        }
+
+        let count = count_arrangements(conditions, hints);
+        if count != arrangements.len() as u64 {
+            return Err(AocError::unsolvable(format!(
+                "`{}` has {} arrangements but {} were counted",
+                record,
+                arrangements.len(),
+                count
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// The arrangements of one record, memoised on the position, the complete hints and the
+/// length of the current damaged run.
+struct Arrangements<'a> {
+    conditions: &'a [ConditionType],
+    hints: &'a [usize],
+    /// The fewest springs the hints from each group on still need, see [`minimum_lengths`].
+    minimum_lengths: Vec<usize>,
+    cache: HashMap<(usize, usize, usize), u64>,
+}
+
+impl<'a> Arrangements<'a> {
+    fn new(conditions: &'a [ConditionType], hints: &'a [usize]) -> Self {
+        Arrangements {
+            conditions,
+            hints,
+            minimum_lengths: minimum_lengths(hints),
+            cache: HashMap::new(),
+        }
+    }
+
+    /// The arrangements of `conditions[position..]` when `group` hints are already complete
+    /// and the current damaged run is `run` springs long.
+    fn count_from(&mut self, position: usize, group: usize, run: usize) -> u64 {
+        if position == self.conditions.len() {
+            let hints = self.hints;
+            let finished = (group == hints.len() && run == 0)
+                || (group + 1 == hints.len() && hints[group] == run);
+            return finished as u64;
+        }
+
+        if let Some(count) = self.cache.get(&(position, group, run)) {
+            return *count;
+        }
+
+        if self.minimum_lengths[group] - run > self.conditions.len() - position {
+            return 0;
+        }
+
+        let count = [ConditionType::Operational, ConditionType::Damaged]
+            .into_iter()
+            .filter(|assumed| self.conditions[position].could_be(*assumed))
+            .filter_map(|assumed| next_run(self.hints, group, run, assumed))
+            .map(|(group, run)| self.count_from(position + 1, group, run))
+            .sum();
+
+        self.cache.insert((position, group, run), count);
+        count
+    }
+
+    fn enumerate_from(
+        &mut self,
+        (position, group, run): (usize, usize, usize),
+        current: &mut Vec<ConditionType>,
+        result: &mut Vec<String>,
+    ) {
+        if self.count_from(position, group, run) == 0 {
+            return;
+        }
+
+        if position == self.conditions.len() {
+            result.push(
+                current
+                    .iter()
+                    .map(|condition| condition.to_string())
+                    .collect(),
+            );
+            return;
+        }
+
+        for assumed in [ConditionType::Operational, ConditionType::Damaged] {
+            if !self.conditions[position].could_be(assumed) {
+                continue;
+            }
+            let Some((group, run)) = next_run(self.hints, group, run, assumed) else {
+                continue;
+            };
+
+            current.push(assumed);
+            self.enumerate_from((position + 1, group, run), current, result);
+            current.pop();
+        }
+    }
+}
+
+/// The group and run length after one more spring, `None` if the spring breaks the hints:
+/// a damaged one making the run longer than its hint, or an operational one ending a run
+/// shorter than it.
+fn next_run(
+    hints: &[usize],
+    group: usize,
+    run: usize,
+    assumed: ConditionType,
+) -> Option<(usize, usize)> {
+    match assumed {
+        ConditionType::Damaged if hints.get(group).is_some_and(|hint| run < *hint) => {
+            Some((group, run + 1))
+        }
+        ConditionType::Operational if run == 0 => Some((group, 0)),
+        ConditionType::Operational if hints.get(group) == Some(&run) => Some((group + 1, 0)),
+        _ => None,
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq)]
-enum ConditionType {
+pub enum ConditionType {
     Operational,
     Damaged,
     Unknown,
 }
 
 impl ConditionType {
-    fn from(input: &str) -> ConditionType {
+    fn from(input: char) -> Option<ConditionType> {
         match input {
-            "." => ConditionType::Operational,
-            "#" => ConditionType::Damaged,
-            "?" => ConditionType::Unknown,
-            _ => panic!("ConditionType::Incorrect data: {}", input),
+            '.' => Some(ConditionType::Operational),
+            '#' => Some(ConditionType::Damaged),
+            '?' => Some(ConditionType::Unknown),
+            _ => None,
         }
     }
 
+    /// Whether a spring recorded as `self` may actually be `assumed`.
+    fn could_be(&self, assumed: ConditionType) -> bool {
+        *self == ConditionType::Unknown || *self == assumed
+    }
+
     fn to_string(&self) -> String {
         match self {
             ConditionType::Damaged => "#".to_owned(),
@@ -114,233 +309,117 @@ mod tests {
 
     #[test]
     fn test_get_minimum_length() {
-        let input = parse_hint("1,1,3");
-        assert_eq!(get_minimum_length_of_conditions(&input, 0), 7);
+        let input = parse_hint("1,1,3").unwrap();
+        assert_eq!(minimum_lengths(&input), vec![7, 5, 3, 0]);
 
-        let input = parse_hint("1");
-        assert_eq!(get_minimum_length_of_conditions(&input, 0), 1);
+        let input = parse_hint("1").unwrap();
+        assert_eq!(minimum_lengths(&input), vec![1, 0]);
 
-        let input = parse_hint("1,3");
-        assert_eq!(get_minimum_length_of_conditions(&input, 0), 5);
+        let input = parse_hint("1,3").unwrap();
+        assert_eq!(minimum_lengths(&input), vec![5, 3, 0]);
 
-        let input = parse_hint("1,3,1,6");
-        assert_eq!(get_minimum_length_of_conditions(&input, 0), 14);
-    }
-
-    #[test]
-    fn test_look_for_filling_index_ex() {
-        let (conditions, _) = parse_line("???.### 1,1,3");
-        let result = look_for_filling_index_ex(1, &conditions, 0, conditions.len());
-        assert_eq!(result, Some(0));
-
-        let result = look_for_filling_index_ex(1, &conditions, 1, conditions.len());
-        assert_eq!(result, Some(1));
-
-        let result = look_for_filling_index_ex(1, &conditions, 2, conditions.len());
-        assert_eq!(result, Some(2));
-
-        let result = look_for_filling_index_ex(1, &conditions, 3, conditions.len());
-        assert_eq!(result, None);
-
-        let (conditions, _) = parse_line(".??..??...?##. 1,1,3");
-        let result = look_for_filling_index_ex(1, &conditions, 0, conditions.len());
-        assert_eq!(result, Some(1));
-
-        let result = look_for_filling_index_ex(1, &conditions, 1, conditions.len());
-        assert_eq!(result, Some(1));
-
-        let result = look_for_filling_index_ex(1, &conditions, 2, conditions.len());
-        assert_eq!(result, Some(2));
-
-        let result = look_for_filling_index_ex(1, &conditions, 3, conditions.len());
-        assert_eq!(result, Some(5));
-
-        let (conditions, _) = parse_line("?###???????? 3,2,1");
-        let result = look_for_filling_index_ex(2, &conditions, 0, conditions.len());
-        assert_eq!(result, Some(5));
-
-        let result = look_for_filling_index_ex(2, &conditions, 6, conditions.len());
-        assert_eq!(result, Some(6));
-
-        let result = look_for_filling_index_ex(2, &conditions, 7, conditions.len());
-        assert_eq!(result, Some(7));
+        let input = parse_hint("1,3,1,6").unwrap();
+        assert_eq!(minimum_lengths(&input)[0], 14);
     }
 
     #[test]
     fn test_filling_example() {
-        let (conditions, hints) = parse_line("???.### 1,1,3");
-        let result = filling_ex(conditions.clone(), &hints, 0, 0, conditions.len());
+        let (conditions, hints) = parse_line("???.### 1,1,3").unwrap();
+        let result = count_arrangements(&conditions, &hints);
         assert_eq!(result, 1);
 
-        let (conditions, hints) = parse_line(".??..??...?##. 1,1,3");
-        let result = filling_ex(conditions.clone(), &hints, 0, 0, conditions.len());
+        let (conditions, hints) = parse_line(".??..??...?##. 1,1,3").unwrap();
+        let result = count_arrangements(&conditions, &hints);
         assert_eq!(result, 4);
 
-        let (conditions, hints) = parse_line("?#?#?#?#?#?#?#? 1,3,1,6");
-        let result = filling_ex(conditions.clone(), &hints, 0, 0, conditions.len());
+        let (conditions, hints) = parse_line("?#?#?#?#?#?#?#? 1,3,1,6").unwrap();
+        let result = count_arrangements(&conditions, &hints);
         assert_eq!(result, 1);
 
-        let (conditions, hints) = parse_line("????.#...#... 4,1,1");
-        let result = filling_ex(conditions.clone(), &hints, 0, 0, conditions.len());
+        let (conditions, hints) = parse_line("????.#...#... 4,1,1").unwrap();
+        let result = count_arrangements(&conditions, &hints);
         assert_eq!(result, 1);
 
-        let (conditions, hints) = parse_line("????.######..#####. 1,6,5");
-        let result = filling_ex(conditions.clone(), &hints, 0, 0, conditions.len());
+        let (conditions, hints) = parse_line("????.######..#####. 1,6,5").unwrap();
+        let result = count_arrangements(&conditions, &hints);
         assert_eq!(result, 4);
 
-        let (conditions, hints) = parse_line("?###???????? 3,2,1");
-        let result = filling_ex(conditions.clone(), &hints, 0, 0, conditions.len());
+        let (conditions, hints) = parse_line("?###???????? 3,2,1").unwrap();
+        let result = count_arrangements(&conditions, &hints);
         assert_eq!(result, 10);
     }
 
     #[test]
     fn test_part_2_example() {
         let (conditions, hints) =
-            parse_line("???.###????.###????.###????.###????.### 1,1,3,1,1,3,1,1,3,1,1,3,1,1,3");
-        let result = filling_ex(conditions.clone(), &hints, 0, 0, conditions.len());
+            parse_line("???.###????.###????.###????.###????.### 1,1,3,1,1,3,1,1,3,1,1,3,1,1,3")
+                .unwrap();
+        let result = count_arrangements(&conditions, &hints);
         assert_eq!(result, 1);
     }
     #[test]
     fn test_filling() {
-        // This example has issue.
-        let (conditions, hints) = parse_line("?##?##??#?.#?#?? 8,3");
-        let result = filling_ex(conditions.clone(), &hints, 0, 0, conditions.len());
+        let (conditions, hints) = parse_line("?##?##??#?.#?#?? 8,3").unwrap();
+        let result = count_arrangements(&conditions, &hints);
         assert_eq!(result, 1);
 
         //
-        let (conditions, hints) = parse_line("??#?.#??.?? 2,1,1");
-        let result = filling_ex(conditions.clone(), &hints, 0, 0, conditions.len());
+        let (conditions, hints) = parse_line("??#?.#??.?? 2,1,1").unwrap();
+        let result = count_arrangements(&conditions, &hints);
         assert_eq!(result, 6);
     }
     #[test]
-    fn test_part_1() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day12::part_01(&lines);
-        assert_eq!(result, 21);
+    fn test_unfold() {
+        let (conditions, hints) = parse_line("???.### 1,1,3").unwrap();
+        let (conditions, hints) = unfold(&conditions, &hints, 5);
+        let (expected_conditions, expected_hints) =
+            parse_line("???.###????.###????.###????.###????.### 1,1,3,1,1,3,1,1,3,1,1,3,1,1,3")
+                .unwrap();
+        assert_eq!(conditions, expected_conditions);
+        assert_eq!(hints, expected_hints);
+
+        let (conditions, hints) = parse_line("?###???????? 3,2,1").unwrap();
+        let (conditions, hints) = unfold(&conditions, &hints, 5);
+        assert_eq!(count_arrangements(&conditions, &hints), 506250);
     }
 
     #[test]
-    fn test_part_2() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day12::part_02(&lines);
-        assert_eq!(result, 525152);
+    fn test_enumerate_arrangements() {
+        let (conditions, hints) = parse_line("?###???????? 3,2,1").unwrap();
+        let arrangements = enumerate_arrangements(&conditions, &hints);
+        assert_eq!(arrangements.len(), 10);
+        assert!(arrangements.contains(&".###.##.#...".to_owned()));
+        assert!(arrangements
+            .iter()
+            .all(|arrangement| self_check_result(arrangement, &hints)));
+
+        let (conditions, hints) = parse_line("??#?.#??.?? 2,1,1").unwrap();
+        assert_eq!(enumerate_arrangements(&conditions, &hints).len(), 6);
     }
-}
 
-fn filling_ex(
-    conditions: Vec<ConditionType>,
-    reference_hints: &Vec<usize>,
-    hints_index: usize,
-    left_border: usize,
-    right_border: usize,
-) -> i32 {
-    let minimum_length = get_minimum_length_of_conditions(reference_hints, hints_index);
-    let length = right_border - left_border;
-    if minimum_length > length {
-        // println!("Rejected: {:?}\n{:?}\n--", conditions, hints);
-        return 0;
+    #[test]
+    fn test_check_arrangements() {
+        let records = Day12::parse(TEST_INPUT).unwrap().records;
+        assert!(check_arrangements(&records).is_ok());
     }
 
-    if follow_conditions(&conditions, reference_hints, hints_index, left_border) == false {
-        return 0;
+    #[test]
+    fn test_parse_error() {
+        let error = Day12::parse("???.### 1,1,3\n.??..x?...?##. 1,1,3").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "cannot parse line 2 `.??..x?...?##. 1,1,3`: expected springs and group sizes"
+        );
     }
 
-    // handle predicted all damaged parts.
-    if hints_index >= reference_hints.len() {
-        let total_damaged_parts: i32 = conditions[left_border..right_border]
-            .par_iter()
-            .map(|v| {
-                if matches!(v, ConditionType::Damaged) {
-                    1
-                } else {
-                    0
-                }
-            })
-            .sum();
-
-        // It should not have any damage part in the conditions. to assume that it's correct record
-        if total_damaged_parts == 0 {
-            // let printed_text = print_debug(&String::new(), &conditions, 0, right_border, false);
-            // if self_check_result(&printed_text, reference_hints) == false
-            // {
-            //     panic!("Something wrong: {}\n{:?}\n\n{:?}",printed_text, conditions, reference_hints);
-            // }
-            return 1;
-        } else {
-            return 0;
-        }
-    } else {
-        let window_size = reference_hints[hints_index];
-        // find the index to split the predicted damaged part.
-        if let Some(start_index) =
-            look_for_filling_index_ex(window_size, &conditions, left_border, right_border)
-        {
-            let end_index = start_index + window_size;
-            // split until the last element of conditions
-            if end_index > conditions.len() {
-                // Not enough elements to match condition. This case is not possible.
-                panic!(
-                    "Impossible case: {} {}\n{:?}",
-                    end_index,
-                    conditions.len(),
-                    conditions
-                )
-            } else {
-                // Scenario: use this location as damaged part.
-                let marked_conditions = conditions
-                    .iter()
-                    .enumerate()
-                    .map(|(index, v)| {
-                        if index < start_index {
-                            assume_value(*v, false)
-                        } else if index >= start_index && index < end_index {
-                            assume_value(*v, true)
-                        } else {
-                            v.clone()
-                        }
-                    })
-                    .collect();
-                let result_2 = filling_ex(
-                    marked_conditions,
-                    reference_hints,
-                    hints_index + 1,
-                    end_index,
-                    right_border,
-                );
-
-                // Scenario: skip this location and try next one. It should check that it possible to try next one or not.
-                let unmarked_conditions = conditions
-                    .iter()
-                    .enumerate()
-                    .map(|(index, v)| {
-                        if index <= start_index {
-                            assume_value(*v, false)
-                        } else {
-                            v.clone()
-                        }
-                    })
-                    .collect();
-
-                let result_1 = filling_ex(
-                    unmarked_conditions,
-                    reference_hints,
-                    hints_index,
-                    start_index + 1,
-                    right_border,
-                );
-
-                return result_1 + result_2;
-            }
-        } else {
-            // No potential damage parts in the conditions. Reject the process.
-            return 0;
-        }
+    #[test]
+    fn test_examples() {
+        crate::solution::assert_examples::<Day12>();
     }
-
-    0
 }
 
-fn self_check_result(text: &String, reference_hints: &Vec<usize>) -> bool {
+/// Whether the damaged runs of a written out arrangement are the hints.
+fn self_check_result(text: &str, reference_hints: &[usize]) -> bool {
     let current_data: Vec<usize> = text
         .split(".")
         .filter_map(|v| if v.len() == 0 { None } else { Some(v.len()) })
@@ -349,266 +428,36 @@ fn self_check_result(text: &String, reference_hints: &Vec<usize>) -> bool {
     current_data == *reference_hints
 }
 
-fn follow_conditions(
-    conditions: &Vec<ConditionType>,
-    reference_hints: &Vec<usize>,
-    hints_index: usize,
-    maximum_left: usize,
-) -> bool {
-    let mut left_border = 0;
-    for index in 0..hints_index {
-        if let Some(window_size) = reference_hints.get(index).copied() {
-            if let Some(start_index) =
-                look_for_filling_index_ex(window_size, conditions, left_border, maximum_left)
-            {
-                let non_damage_parts = (left_border..start_index).all(|checking_index| {
-                    if let Some(value) = conditions.get(checking_index) {
-                        matches!(value, ConditionType::Operational | ConditionType::Unknown)
-                    } else {
-                        false
-                    }
-                });
-
-                if non_damage_parts == true {
-                    left_border = start_index + window_size + 1;
-                } else {
-                    return false;
-                }
-            }
-        } else {
-            return false;
-        }
-    }
-
-    let non_damage_parts = (left_border..maximum_left).all(|checking_index| {
-        if let Some(value) = conditions.get(checking_index) {
-            matches!(value, ConditionType::Operational | ConditionType::Unknown)
-        } else {
-            false
-        }
-    });
+/// The record on a line, `None` if the line is malformed.
+fn parse_line(input: &str) -> Option<Record> {
+    let (conditions, hint) = input.split_once(' ')?;
+    let conditions = parse_conditions(conditions)?;
+    let hint = parse_hint(hint)?;
 
-    non_damage_parts
+    Some((conditions, hint))
 }
 
-fn assume_value(v: ConditionType, assume_damage: bool) -> ConditionType {
-    if assume_damage == true {
-        if matches!(v, ConditionType::Unknown) {
-            return ConditionType::Damaged;
-        }
-    } else {
-        if matches!(v, ConditionType::Unknown) {
-            return ConditionType::Operational;
-        }
-    }
-
-    v
+fn parse_conditions(input: &str) -> Option<Vec<ConditionType>> {
+    input.par_chars().map(ConditionType::from).collect()
 }
 
-fn print_debug(
-    printed: &String,
-    conditions: &Vec<ConditionType>,
-    left_board: usize,
-    right_border: usize,
-    assume_damage: bool,
-) -> String {
-    let new_string: String = conditions[left_board..right_border]
-        .iter()
-        .map(|v| {
-            let v = assume_value(*v, assume_damage);
-            match v {
-                ConditionType::Damaged => "#".to_owned(),
-                ConditionType::Unknown => {
-                    if assume_damage == true {
-                        "#".to_owned()
-                    } else {
-                        ".".to_owned()
-                    }
-                }
-                ConditionType::Operational => ".".to_owned(),
-            }
-        })
-        .collect();
-
-    let result = format!("{}{}", printed, new_string);
-    println!("{}", result);
-    result
-}
-
-fn look_for_filling_index_ex(
-    window_size: usize,
-    conditions: &Vec<ConditionType>,
-    left_board: usize,
-    right_border: usize,
-) -> Option<usize> {
-    let length = right_border - left_board;
-    if window_size > length {
-        None
-    } else if window_size == length {
-        let matched = conditions[left_board..right_border]
-            .par_iter()
-            .all(|v| matches!(v, ConditionType::Damaged | ConditionType::Unknown));
-
-        if matched == true {
-            // check left
-            if left_board > 0 {
-                if let Some(v) = conditions.get(left_board - 1) {
-                    if matches!(v, ConditionType::Damaged) == true {
-                        return None;
-                    }
-                }
-            }
-            // check right
-            if right_border < conditions.len() {
-                if let Some(v) = conditions.get(right_border) {
-                    if matches!(v, ConditionType::Damaged) == true {
-                        return None;
-                    }
-                }
-            }
-
-            return Some(left_board);
-        } else {
-            None
-        }
-    } else if window_size < length {
-        let total_checking = length - window_size;
-        for window_index in 0..=total_checking {
-            let matched = (0..window_size).all(|index| {
-                let actual_index = left_board + window_index + index;
-                if let Some(value) = conditions.get(actual_index) {
-                    matches!(value, ConditionType::Damaged | ConditionType::Unknown)
-                } else {
-                    false
-                }
-            });
-
-            if matched == true {
-                let actual_index = left_board + window_index;
-                // check front
-                if actual_index == 0 {
-                    // pass
-                } else {
-                    let front_index = actual_index - 1;
-                    if let Some(value) = conditions.get(front_index) {
-                        if matches!(value, ConditionType::Damaged) {
-                            // not pass
-                            continue;
-                        }
-                    }
-                }
-
-                // check back
-                if actual_index + window_size == conditions.len() {
-                    return Some(actual_index);
-                } else if actual_index + window_size < conditions.len() {
-                    let last_condition_index = actual_index + window_size;
-                    if let Some(value) = conditions.get(last_condition_index) {
-                        if matches!(value, ConditionType::Operational | ConditionType::Unknown) {
-                            return Some(actual_index);
-                        }
-                    }
-                } else {
-                    panic!("Something wrong");
-                }
-            }
-        }
-
-        None
-    } else {
-        None
-    }
-}
-
-fn look_for_filling_index(window_size: usize, conditions: &Vec<ConditionType>) -> Option<usize> {
-    if window_size > conditions.len() {
-        None
-    } else if window_size == conditions.len() {
-        conditions
-            .par_iter()
-            .all(|v| matches!(v, ConditionType::Damaged | ConditionType::Unknown))
-            .then_some(0)
-    } else if window_size < conditions.len() {
-        let total_checking = conditions.len() - window_size;
-        for window_index in 0..=total_checking {
-            let matched = (0..window_size).all(|index| {
-                let actual_index = window_index + index;
-                if let Some(value) = conditions.get(actual_index) {
-                    matches!(value, ConditionType::Damaged | ConditionType::Unknown)
-                } else {
-                    false
-                }
-            });
-
-            if matched == true {
-                // check front
-                if window_index == 0 {
-                    // pass
-                } else {
-                    let front_index = window_index - 1;
-                    if let Some(value) = conditions.get(front_index) {
-                        if matches!(value, ConditionType::Damaged) {
-                            // not pass
-                            continue;
-                        }
-                    }
-                }
-
-                // check back
-                if window_index + window_size == conditions.len() {
-                    return Some(window_index);
-                } else {
-                    let last_condition_index = window_index + window_size;
-                    if let Some(value) = conditions.get(last_condition_index) {
-                        if matches!(value, ConditionType::Operational | ConditionType::Unknown) {
-                            return Some(window_index);
-                        }
-                    }
-                }
-            }
-        }
-
-        None
-    } else {
-        None
-    }
-}
-
-fn parse_line(input: &str) -> (Vec<ConditionType>, Vec<usize>) {
-    let values: Vec<&str> = input.split(" ").collect_vec();
-    let conditions = parse_conditions(values[0]);
-    let hint = parse_hint(values[1]);
-
-    (conditions, hint)
-}
-
-fn parse_conditions(input: &str) -> Vec<ConditionType> {
-    input
-        .par_chars()
-        .map(|c| ConditionType::from(&String::from(c)))
-        .collect()
-}
-
-fn parse_hint(input: &str) -> Vec<usize> {
+fn parse_hint(input: &str) -> Option<Vec<usize>> {
     let values: Vec<&str> = input.split(",").collect();
 
     values
         .par_iter()
-        .map(|v| (*v).parse::<usize>().unwrap())
+        .map(|v| (*v).parse::<usize>().ok())
         .collect()
 }
 
-fn get_minimum_length_of_conditions(hints: &Vec<usize>, hints_index: usize) -> usize {
-    let total_hints = hints.len() - hints_index;
-    hints[hints_index..]
-        .par_iter()
-        .enumerate()
-        .map(|(index, value)| {
-            if index == total_hints - 1 {
-                *value
-            } else {
-                *value + 1
-            }
-        })
-        .sum()
+/// The fewest springs the hints from each group on need, the runs and one operational spring
+/// between each two of them, with a last 0 once every hint is complete.
+fn minimum_lengths(hints: &[usize]) -> Vec<usize> {
+    let mut result = vec![0; hints.len() + 1];
+    for group in (0..hints.len()).rev() {
+        let separator = if group + 1 < hints.len() { 1 } else { 0 };
+        result[group] = hints[group] + separator + result[group + 1];
+    }
+
+    result
 }
@@ -1,35 +1,39 @@
-use crate::file_handler::FileHandler;
+use crate::error::AocError;
+use crate::solution::{Example, Solution};
 use rayon::prelude::*;
 use std::collections::{HashMap, HashSet};
-use std::time::Instant;
 
 pub struct Day13 {}
 
-impl Day13 {
-    pub fn run() -> (String, String, String, u128, u128) {
-        let first_input = FileHandler::read("./src/Y2023/inputs/day_13_1.txt");
-
-        let lines: Vec<&str> = first_input.split('\n').collect();
-
-        let start_1 = Instant::now();
-        let result_1 = Day13::part_01(&lines);
-        let time_calculation_1 = start_1.elapsed();
-
-        let start_2 = Instant::now();
-        let result_2 = Day13::part_02(&lines);
-        let time_calculation_2 = start_2.elapsed();
-
-        (
-            "Day_13".to_owned(),
-            format!("{}", result_1),
-            format!("{}", result_2),
-            time_calculation_1.as_nanos(),
-            time_calculation_2.as_nanos(),
-        )
+impl Solution for Day13 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 13;
+
+    type Input<'a> = Vec<Vec<Vec<PatternType>>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let lines: Vec<&str> = input.split('\n').collect();
+        parse_input(&lines)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day13::part_01(input)?.to_string())
     }
 
-    fn part_01(lines: &Vec<&str>) -> u128 {
-        let data = parse_input(lines);
+    fn part_two(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day13::part_02(input)?.to_string())
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![
+            Example::new(1, TEST_INPUT, "405"),
+            Example::new(2, TEST_INPUT, "400"),
+        ]
+    }
+}
+
+impl Day13 {
+    fn part_01(data: &Vec<Vec<Vec<PatternType>>>) -> Result<u128, AocError> {
         let patterns: Vec<Pattern> = data
             .par_iter()
             .map(|group| Pattern::new(group.clone()))
@@ -38,8 +42,7 @@ impl Day13 {
         patterns.iter().map(|node| node.process()).sum()
     }
 
-    fn part_02(lines: &Vec<&str>) -> u128 {
-        let data = parse_input(lines);
+    fn part_02(data: &Vec<Vec<Vec<PatternType>>>) -> Result<u128, AocError> {
         let mut patterns: Vec<Pattern> = data
             .par_iter()
             .map(|group| Pattern::new(group.clone()))
@@ -50,24 +53,17 @@ impl Day13 {
 }
 
 #[derive(Debug, Clone, PartialEq)]
-enum PatternType {
+pub enum PatternType {
     Rock,
     Ash,
 }
 
 impl PatternType {
-    fn from(value: &str) -> PatternType {
+    fn from(value: char) -> Option<PatternType> {
         match value {
-            "." => PatternType::Ash,
-            "#" => PatternType::Rock,
-            _ => panic!("PatternType::incorrect input: {}", value),
-        }
-    }
-
-    fn to_string(&self) -> String {
-        match self {
-            PatternType::Ash => ".".to_owned(),
-            PatternType::Rock => "#".to_owned(),
+            '.' => Some(PatternType::Ash),
+            '#' => Some(PatternType::Rock),
+            _ => None,
         }
     }
 
@@ -88,7 +84,7 @@ struct Pattern {
 
 impl Pattern {
     fn new(data: Vec<Vec<PatternType>>) -> Self {
-        let width = data.first().unwrap().len();
+        let width = data.first().map_or(0, |row| row.len());
         let vertical: Vec<Vec<PatternType>> = (0..width)
             .map(|column| {
                 (0..data.len())
@@ -103,81 +99,42 @@ impl Pattern {
         }
     }
 
-    fn process(&self) -> u128 {
-        if let Some((column, row)) = self.find_perfect_reflection(vec![]) {
-            Pattern::calculate_score_from_reflection(column, row)
-        } else {
-            self.print_pattern();
-
-            panic!("Pattern::process()::Something wrong:");
-        }
+    fn process(&self) -> Result<u128, AocError> {
+        let (column, row) = self
+            .find_perfect_reflection(vec![])
+            .ok_or_else(|| AocError::unsolvable("a pattern has no reflection"))?;
+        Ok(Pattern::calculate_score_from_reflection(column, row))
     }
 
-    fn process_smudge(&mut self) -> u128 {
+    /// Flips every cell in turn until the pattern reflects on another line than before.
+    fn process_smudge(&mut self) -> Result<u128, AocError> {
         let original_reflection = self.find_perfect_reflection(vec![]);
         let original_vertical_data = self.vertical.clone();
         let original_horizontal_data = self.horizontal.clone();
         let skipped = vec![original_reflection.unwrap_or((0, 0))];
-        let mut index = 0;
-        loop {
-            let column = index % original_vertical_data.len();
-            let row = index / original_vertical_data.len();
-
-            self.invert_data(column, row);
-
-            // if row == 8 && column == 10
-            // {
-            //     self.print_pattern();
-            //     println!("{} {}\n--", column, row);
-            // }
-
-            let new_reflection = self.find_perfect_reflection(skipped.clone());
-            if (original_reflection.is_some()
-                && new_reflection.is_some()
-                && new_reflection != original_reflection)
-                || (original_reflection.is_none() && new_reflection.is_some())
-            {
-                // found it
-                // self.print_pattern();
-                if let Some((r_column, r_row)) = new_reflection {
-                    return Pattern::calculate_score_from_reflection(r_column, r_row);
+        for row in 0..original_horizontal_data.len() {
+            for column in 0..original_vertical_data.len() {
+                self.invert_data(column, row);
+
+                let new_reflection = self.find_perfect_reflection(skipped.clone());
+                match new_reflection {
+                    Some((r_column, r_row)) if new_reflection != original_reflection => {
+                        return Ok(Pattern::calculate_score_from_reflection(r_column, r_row));
+                    }
+                    _ => {
+                        self.horizontal = original_horizontal_data.clone();
+                        self.vertical = original_vertical_data.clone();
+                    }
                 }
-
-                self.print_pattern();
-                println!("Original: {:?}", original_reflection);
-                println!("New: {:?}", new_reflection);
-                panic!("It should not reach here: {} {}", column, row);
-            } else {
-                self.horizontal = original_horizontal_data.clone();
-                self.vertical = original_vertical_data.clone();
-            }
-
-            index += 1;
-
-            if index > original_horizontal_data.len() * original_vertical_data.len() {
-                self.print_pattern();
-                panic!(
-                    "It should not reach here:\n{} {}\n{} {}",
-                    original_horizontal_data.len(),
-                    original_horizontal_data[0].len(),
-                    original_vertical_data[0].len(),
-                    original_vertical_data.len()
-                );
             }
         }
+
+        Err(AocError::unsolvable(
+            "no smudge gives a pattern another reflection",
+        ))
     }
 
     fn invert_data(&mut self, column: usize, row: usize) {
-        if row >= self.horizontal.len() || column >= self.vertical.len() {
-            self.print_pattern();
-            panic!(
-                "Index out of bound:\nColumn: {} / {}\nRow: {} / {}\n--",
-                column,
-                self.vertical.len(),
-                row,
-                self.horizontal.len()
-            );
-        }
         self.horizontal[row][column] = self.horizontal[row][column].opposite();
         self.vertical[column][row] = self.vertical[column][row].opposite();
     }
@@ -213,17 +170,6 @@ impl Pattern {
         row * 100 + column
     }
 
-    fn print_pattern(&self) {
-        self.horizontal.iter().for_each(|row| {
-            row.iter().for_each(|column| {
-                print!("{}", column.to_string());
-            });
-            println!("");
-        });
-
-        println!("---\n");
-    }
-
     fn is_perfect_horizontal_reflection(&self, start_index: usize) -> bool {
         (1..=start_index).rev().all(|index| {
             let actual_index = index - 1;
@@ -310,28 +256,36 @@ impl Pattern {
     }
 }
 
-fn parse_input(input: &Vec<&str>) -> Vec<Vec<Vec<PatternType>>> {
+fn parse_input(input: &Vec<&str>) -> Result<Vec<Vec<Vec<PatternType>>>, AocError> {
     let mut patterns: Vec<Vec<Vec<PatternType>>> = vec![];
-    let mut group = vec![];
-    input.iter().for_each(|data| {
+    let mut group: Vec<Vec<PatternType>> = vec![];
+    for (index, data) in input.iter().enumerate() {
         if data.len() > 0 {
             let row: Vec<PatternType> = (*data)
                 .par_chars()
-                .map(|c| PatternType::from(&String::from(c)))
-                .collect();
+                .map(PatternType::from)
+                .collect::<Option<_>>()
+                .ok_or_else(|| AocError::parse(index, data, "expected `.` or `#`"))?;
+            if group.first().is_some_and(|first| first.len() != row.len()) {
+                return Err(AocError::parse(
+                    index,
+                    data,
+                    "the rows of a pattern differ in length",
+                ));
+            }
             group.push(row);
-        } else {
+        } else if !group.is_empty() {
             patterns.push(group.clone());
             group.clear();
         }
-    });
+    }
 
     if group.len() > 0 {
         patterns.push(group.clone());
         group.clear();
     }
 
-    patterns
+    Ok(patterns)
 }
 
 const TEST_INPUT: &str = "#.##..##.
@@ -368,7 +322,7 @@ mod tests {
     fn test_specific_smudge() {
         // Issue It sill look for previous reflection after smudge instead of new one.
         let input: Vec<&str> = SPECIFIC_TEST.lines().collect();
-        let data = parse_input(&input);
+        let data = parse_input(&input).unwrap();
         let mut pattern = Pattern::new(data[0].clone());
         // let old_result = pattern.find_perfect_reflection(vec![]);
         // println!("{:?}", old_result);
@@ -376,27 +330,27 @@ mod tests {
         // pattern.invert_data(10, 8);
         // let result = pattern.find_perfect_reflection(skipped);
         // println!("{:?}", result);
-        let result = pattern.process_smudge();
+        let result = pattern.process_smudge().unwrap();
         assert_eq!(result, 800);
     }
 
     #[test]
     fn test_find_smudge() {
         let input: Vec<&str> = TEST_INPUT.lines().collect();
-        let data = parse_input(&input);
+        let data = parse_input(&input).unwrap();
 
         let mut pattern = Pattern::new(data[0].clone());
-        let result = pattern.process_smudge();
+        let result = pattern.process_smudge().unwrap();
         assert_eq!(result, 300);
 
         let mut pattern = Pattern::new(data[1].clone());
-        let result = pattern.process_smudge();
+        let result = pattern.process_smudge().unwrap();
         assert_eq!(result, 100);
     }
     #[test]
     fn test_perfect_horizontal_reflection() {
         let input: Vec<&str> = TEST_INPUT.lines().collect();
-        let data = parse_input(&input);
+        let data = parse_input(&input).unwrap();
 
         let pattern = Pattern::new(data[0].clone());
         let reflection = pattern.find_start_reflection_vertical();
@@ -410,17 +364,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_no_reflection() {
+        let data = parse_input(&vec!["#.#"]).unwrap();
+        assert_eq!(
+            Pattern::new(data[0].clone())
+                .process()
+                .unwrap_err()
+                .to_string(),
+            "no solution: a pattern has no reflection"
+        );
+        // Flipping the middle cell would give `###`, a single cell has no reflection at all.
+        let data = parse_input(&vec!["#"]).unwrap();
+        assert!(Pattern::new(data[0].clone()).process_smudge().is_err());
+        assert!(parse_input(&vec!["#.#", "#."]).is_err());
+    }
+
     #[test]
     fn test_parse_input() {
         let input: Vec<&str> = TEST_INPUT.lines().collect();
-        let data = parse_input(&input);
+        let data = parse_input(&input).unwrap();
         assert_eq!(data.len(), 2);
     }
 
     #[test]
     fn test_find_reflection() {
         let input: Vec<&str> = TEST_INPUT.lines().collect();
-        let data = parse_input(&input);
+        let data = parse_input(&input).unwrap();
 
         let pattern = Pattern::new(data[0].clone());
         let reflection = pattern.find_start_reflection_vertical();
@@ -434,17 +404,7 @@ mod tests {
     }
 
     #[test]
-    fn test_part_1() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day13::part_01(&lines);
-        assert_eq!(result, 405);
-    }
-
-    #[test]
-    fn test_part_2() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-
-        let result = Day13::part_02(&lines);
-        assert_eq!(result, 400);
+    fn test_examples() {
+        crate::solution::assert_examples::<Day13>();
     }
 }
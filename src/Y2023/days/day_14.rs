@@ -1,74 +1,76 @@
-use crate::file_handler::FileHandler;
-use rayon::prelude::*;
-use std::collections::{HashMap, HashSet};
-use std::time::Instant;
+use crate::cycle::find_cycle;
+use crate::error::AocError;
+use crate::grid::Grid;
+use crate::solution::{Example, Solution};
+use std::fmt;
 
 pub struct Day14 {}
 
-impl Day14 {
-    pub fn run() -> (String, String, String, u128, u128) {
-        let first_input = FileHandler::read("./src/Y2023/inputs/day_14_1.txt");
-
-        let lines: Vec<&str> = first_input.split('\n').collect();
-
-        let start_1 = Instant::now();
-        let result_1 = Day14::part_01(&lines);
-        let time_calculation_1 = start_1.elapsed();
-
-        let start_2 = Instant::now();
-        let result_2 = Day14::part_02(&lines);
-        let time_calculation_2 = start_2.elapsed();
-
-        (
-            "Day_14".to_owned(),
-            format!("{}", result_1),
-            format!("{}", result_2),
-            time_calculation_1.as_nanos(),
-            time_calculation_2.as_nanos(),
-        )
+const TOTAL_CYCLES: u64 = 1_000_000_000;
+
+impl Solution for Day14 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 14;
+
+    type Input<'a> = Grid<Cell>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let lines: Vec<&str> = input.split('\n').collect();
+        parse_input(&lines)
     }
 
-    fn part_01(lines: &Vec<&str>) -> i32 {
-        let (blocks, mut rocks, width, height) = parse_input(&lines);
+    fn part_one(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day14::part_01(input).to_string())
+    }
 
-        tilt_platform(&blocks, &mut rocks, width, height, TiltDirection::North);
+    fn part_two(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day14::part_02(input).to_string())
+    }
 
-        let score = calculate_load(&rocks, height);
-        score as i32
+    fn examples() -> Vec<Example> {
+        vec![
+            Example::new(1, TEST_INPUT, "136"),
+            Example::new(2, TEST_INPUT, "64"),
+        ]
     }
+}
 
-    fn part_02(lines: &Vec<&str>) -> usize {
-        let (blocks, mut rocks, width, height) = parse_input(&lines);
-        let mut cache: HashMap<usize, Vec<i32>> = HashMap::new();
-        for index in 0..1000 {
-            process_one_cycle(&blocks, &mut rocks, width, height);
+impl Day14 {
+    fn part_01(grid: &Grid<Cell>) -> i32 {
+        let mut grid = grid.clone();
 
-            let score = calculate_load(&rocks, height);
-            if cache.contains_key(&score) == true {
-                if let Some(list) = cache.get_mut(&score) {
-                    list.push(index);
-                }
-            } else {
-                cache.insert(score, vec![index]);
-            }
+        tilt_platform(&mut grid, TiltDirection::North);
 
-            if index > 0 {
-                println!("Loop: {}", index);
-                cache.iter().for_each(|(score, indexes)| {
-                    if indexes.len() >= 10 {
-                        println!("Score: {} Indexes: {}", score, indexes.len());
-                    }
-                });
-                println!("---\n");
-            }
-        }
+        let score = calculate_load(&grid);
+        score as i32
+    }
 
-        // cache.iter().for_each(|(score, indexes)|{
-        //     println!("Score: {} Indexes: {}", score, indexes.len());
-        // });
+    fn part_02(grid: &Grid<Cell>) -> usize {
+        let cycle = find_cycle(grid.clone(), |grid| {
+            let mut grid = grid.clone();
+            process_one_cycle(&mut grid);
+            grid
+        });
 
-        let score = calculate_load(&rocks, height);
-        score
+        calculate_load(cycle.state_at(TOTAL_CYCLES))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Cell {
+    Empty,
+    Rock,
+    Block,
+}
+
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let c = match self {
+            Cell::Empty => '.',
+            Cell::Rock => 'O',
+            Cell::Block => '#',
+        };
+        write!(f, "{}", c)
     }
 }
 
@@ -80,62 +82,24 @@ enum TiltDirection {
     West,
 }
 
-fn calculate_load(rocks: &HashSet<(usize, usize)>, height: usize) -> usize {
-    rocks
-        .par_iter()
-        .map(|(_, row)| {
-            let score = height - *row;
+fn calculate_load(grid: &Grid<Cell>) -> usize {
+    grid.iter()
+        .filter(|(_, cell)| **cell == Cell::Rock)
+        .map(|((_, row), _)| {
+            let score = grid.height() - row;
 
             score
         })
         .sum()
 }
 
-fn is_empty(
-    blocks: &HashSet<(usize, usize)>,
-    rocks: &HashSet<(usize, usize)>,
-    coord: (usize, usize),
-    width: usize,
-    height: usize,
-) -> bool {
-    if coord.0 >= width || coord.1 >= height {
-        return false;
-    }
-
-    blocks.contains(&coord) == false && rocks.contains(&coord) == false
-}
-
-fn parse_input(
-    input: &Vec<&str>,
-) -> (
-    HashSet<(usize, usize)>,
-    HashSet<(usize, usize)>,
-    usize,
-    usize,
-) {
-    let mut width = 0;
-    let mut height = input.len();
-
-    let mut blocks = HashSet::new();
-    let mut rocks = HashSet::new();
-
-    input.iter().enumerate().for_each(|(row, columns_data)| {
-        width = columns_data.len();
-        columns_data.chars().enumerate().for_each(|(column, c)| {
-            let coord = (column, row);
-            match c {
-                'O' => {
-                    rocks.insert(coord);
-                }
-                '#' => {
-                    blocks.insert(coord);
-                }
-                _ => {}
-            }
-        });
-    });
-
-    (blocks, rocks, width, height)
+fn parse_input(input: &Vec<&str>) -> Result<Grid<Cell>, AocError> {
+    Grid::parse(input, |c| match c {
+        'O' => Some(Cell::Rock),
+        '#' => Some(Cell::Block),
+        '.' => Some(Cell::Empty),
+        _ => None,
+    })
 }
 
 const TEST_INPUT: &str = "O....#....
@@ -155,59 +119,46 @@ mod tests {
     #[test]
     fn test_tilt_one_cycle() {
         let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let (blocks, mut rocks, width, height) = parse_input(&lines);
-        process_one_cycle(&blocks, &mut rocks, width, height);
-        print_board(&blocks, &rocks, width, height);
+        let mut grid = parse_input(&lines).unwrap();
+        process_one_cycle(&mut grid);
+        print_board(&grid);
 
-        process_one_cycle(&blocks, &mut rocks, width, height);
-        print_board(&blocks, &rocks, width, height);
+        process_one_cycle(&mut grid);
+        print_board(&grid);
 
-        process_one_cycle(&blocks, &mut rocks, width, height);
-        print_board(&blocks, &rocks, width, height);
+        process_one_cycle(&mut grid);
+        print_board(&grid);
     }
 
     #[test]
     fn test_move_rocks() {
         let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let (blocks, mut rocks, width, height) = parse_input(&lines);
+        let mut grid = parse_input(&lines).unwrap();
 
-        tilt_platform(&blocks, &mut rocks, width, height, TiltDirection::North);
+        tilt_platform(&mut grid, TiltDirection::North);
 
-        let score = calculate_load(&rocks, height);
+        let score = calculate_load(&grid);
         assert_eq!(score, 136);
     }
 
     #[test]
     fn test_parse_input() {
         let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let (blocks, rocks, width, height) = parse_input(&lines);
-        assert_eq!(18, rocks.len());
-        assert_eq!(17, blocks.len());
-        assert_eq!(10, height);
-        assert_eq!(10, width);
+        let grid = parse_input(&lines).unwrap();
+        let count = |kind: Cell| grid.iter().filter(|(_, cell)| **cell == kind).count();
+        assert_eq!(18, count(Cell::Rock));
+        assert_eq!(17, count(Cell::Block));
+        assert_eq!(10, grid.height());
+        assert_eq!(10, grid.width());
     }
 
     #[test]
-    fn test_part_1() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day14::part_01(&lines);
-        assert_eq!(result, 136);
-    }
-
-    #[test]
-    fn test_part_2() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day14::part_02(&lines);
-        assert_eq!(result, 64);
+    fn test_examples() {
+        crate::solution::assert_examples::<Day14>();
     }
 }
 
-fn process_one_cycle(
-    blocks: &HashSet<(usize, usize)>,
-    rocks: &mut HashSet<(usize, usize)>,
-    width: usize,
-    height: usize,
-) {
+fn process_one_cycle(grid: &mut Grid<Cell>) {
     let cycle_direction = [
         TiltDirection::North,
         TiltDirection::West,
@@ -216,162 +167,39 @@ fn process_one_cycle(
     ];
 
     cycle_direction.iter().for_each(|direction| {
-        tilt_platform(blocks, rocks, width, height, direction.clone());
+        tilt_platform(grid, direction.clone());
     });
 }
 
-fn print_board(
-    blocks: &HashSet<(usize, usize)>,
-    rocks: &HashSet<(usize, usize)>,
-    width: usize,
-    height: usize,
-) {
-    for row in 0..height {
-        for column in 0..width {
-            let coord = (column, row);
-            if blocks.contains(&coord) == true {
-                print!("#");
-            } else if rocks.contains(&coord) == true {
-                print!("O");
-            } else {
-                print!(".");
-            }
-        }
-        println!("");
-    }
-
+fn print_board(grid: &Grid<Cell>) {
+    println!("{}", grid);
     println!("\n---\n");
 }
 
-fn tilt_platform(
-    blocks: &HashSet<(usize, usize)>,
-    rocks: &mut HashSet<(usize, usize)>,
-    width: usize,
-    height: usize,
-    direction: TiltDirection,
-) {
-    loop {
-        let mut rocks_list: Vec<(usize, usize)> = rocks.iter().map(|v| *v).collect();
-
-        rocks_list.sort_by(|a, b| a.1.cmp(&b.1));
-
-        let mut not_move = true;
-        rocks_list.iter().for_each(|coord| {
-            match direction {
-                TiltDirection::North => {
-                    if move_rock_north(blocks, rocks, *coord, width, height) == true {
-                        // rock moved
-                        not_move = false;
-                    }
-                }
-                TiltDirection::South => {
-                    if move_rock_south(blocks, rocks, *coord, width, height) == true {
-                        // rock moved
-                        not_move = false;
-                    }
-                }
-                TiltDirection::East => {
-                    if move_rock_east(blocks, rocks, *coord, width, height) == true {
-                        // rock moved
-                        not_move = false;
-                    }
-                }
-                TiltDirection::West => {
-                    if move_rock_west(blocks, rocks, *coord, width, height) == true {
-                        // rock moved
-                        not_move = false;
-                    }
+/// Rolls every rock as far as it goes, one line of the grid at a time. Each line is walked
+/// from the edge the rocks roll towards, `free` being the next spot a rock would land on.
+fn tilt_platform(grid: &mut Grid<Cell>, direction: TiltDirection) {
+    let (width, height) = (grid.width(), grid.height());
+    let (starts, step): (Vec<(usize, usize)>, (isize, isize)) = match direction {
+        TiltDirection::North => ((0..width).map(|x| (x, 0)).collect(), (0, 1)),
+        TiltDirection::South => ((0..width).map(|x| (x, height - 1)).collect(), (0, -1)),
+        TiltDirection::West => ((0..height).map(|y| (0, y)).collect(), (1, 0)),
+        TiltDirection::East => ((0..height).map(|y| (width - 1, y)).collect(), (-1, 0)),
+    };
+
+    for start in starts {
+        let line: Vec<(usize, usize)> = grid.ray(start, step).collect();
+        let mut free = 0;
+        for (index, coord) in line.iter().enumerate() {
+            match grid[*coord] {
+                Cell::Block => free = index + 1,
+                Cell::Rock => {
+                    grid[*coord] = Cell::Empty;
+                    grid[line[free]] = Cell::Rock;
+                    free += 1;
                 }
+                Cell::Empty => {}
             }
-        });
-
-        if not_move == true {
-            break;
         }
     }
 }
-
-/// move rock from current coord up one step without checking that it available or not
-fn move_rock_north(
-    blocks: &HashSet<(usize, usize)>,
-    rocks: &mut HashSet<(usize, usize)>,
-    coord: (usize, usize),
-    width: usize,
-    height: usize,
-) -> bool {
-    let next_coord = (coord.0, coord.1.saturating_sub(1));
-    if is_empty(blocks, rocks, next_coord, width, height) == false {
-        return false;
-    }
-
-    if coord != next_coord && rocks.remove(&coord) == true {
-        rocks.insert(next_coord);
-
-        true
-    } else {
-        false
-    }
-}
-
-fn move_rock_south(
-    blocks: &HashSet<(usize, usize)>,
-    rocks: &mut HashSet<(usize, usize)>,
-    coord: (usize, usize),
-    width: usize,
-    height: usize,
-) -> bool {
-    let next_coord = (coord.0, coord.1.saturating_add(1));
-    if is_empty(blocks, rocks, next_coord, width, height) == false {
-        return false;
-    }
-
-    if coord != next_coord && rocks.remove(&coord) == true {
-        rocks.insert(next_coord);
-
-        true
-    } else {
-        false
-    }
-}
-
-fn move_rock_west(
-    blocks: &HashSet<(usize, usize)>,
-    rocks: &mut HashSet<(usize, usize)>,
-    coord: (usize, usize),
-    width: usize,
-    height: usize,
-) -> bool {
-    let next_coord = (coord.0.saturating_sub(1), coord.1);
-    if is_empty(blocks, rocks, next_coord, width, height) == false {
-        return false;
-    }
-
-    if coord != next_coord && rocks.remove(&coord) == true {
-        rocks.insert(next_coord);
-
-        true
-    } else {
-        false
-    }
-}
-
-fn move_rock_east(
-    blocks: &HashSet<(usize, usize)>,
-    rocks: &mut HashSet<(usize, usize)>,
-    coord: (usize, usize),
-    width: usize,
-    height: usize,
-) -> bool {
-    let next_coord = (coord.0.saturating_add(1), coord.1);
-    if is_empty(blocks, rocks, next_coord, width, height) == false {
-        return false;
-    }
-
-    if coord != next_coord && rocks.remove(&coord) == true {
-        rocks.insert(next_coord);
-
-        true
-    } else {
-        false
-    }
-}
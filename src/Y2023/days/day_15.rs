@@ -1,48 +1,59 @@
-use crate::file_handler::FileHandler;
+use crate::error::AocError;
+use crate::solution::{Example, Solution};
 use rayon::prelude::*;
 use std::collections::{HashMap, HashSet};
-use std::time::Instant;
 
 pub struct Day15 {}
 
-impl Day15 {
-    pub fn run() -> (String, String, String, u128, u128) {
-        let first_input = FileHandler::read("./src/Y2023/inputs/day_15_1.txt");
-
-        let lines: Vec<&str> = first_input.split('\n').collect();
-
-        let start_1 = Instant::now();
-        let result_1 = Day15::part_01(&lines);
-        let time_calculation_1 = start_1.elapsed();
-
-        let start_2 = Instant::now();
-        let result_2 = Day15::part_02(&lines);
-        let time_calculation_2 = start_2.elapsed();
-
-        (
-            "Day_15".to_owned(),
-            format!("{}", result_1),
-            format!("{}", result_2),
-            time_calculation_1.as_nanos(),
-            time_calculation_2.as_nanos(),
-        )
+/// The labels in every box in order, and the focal length of every lens by label.
+type Boxes = HashMap<i32, (Vec<String>, HashMap<String, i32>)>;
+
+/// The operation (`1` for `=`, `-1` for `-`), label, label characters and focal length of a
+/// step.
+type Step = (i32, String, Vec<i32>, i32);
+
+impl Solution for Day15 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 15;
+
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Vec<&str>, AocError> {
+        Ok(input.split('\n').collect())
+    }
+
+    fn part_one(lines: &Vec<&str>) -> Result<String, AocError> {
+        Ok(Day15::part_01(lines).to_string())
+    }
+
+    fn part_two(lines: &Vec<&str>) -> Result<String, AocError> {
+        Ok(Day15::part_02(lines)?.to_string())
     }
 
+    fn examples() -> Vec<Example> {
+        vec![
+            Example::new(1, TEST_INPUT, "1320"),
+            Example::new(2, TEST_INPUT, "145"),
+        ]
+    }
+}
+
+impl Day15 {
     fn part_01(lines: &Vec<&str>) -> i32 {
         let data = parse_input(lines);
 
         data.iter().map(|list| process_hash(list)).sum()
     }
 
-    fn part_02(lines: &Vec<&str>) -> i32 {
-        let data = do_lense_boxing(lines[0]);
-        calculate_box_scores(&data)
+    fn part_02(lines: &Vec<&str>) -> Result<i32, AocError> {
+        let data = do_lense_boxing(lines[0])?;
+        Ok(calculate_box_scores(&data))
     }
 }
 
-fn do_lense_boxing(input: &str) -> HashMap<i32, (Vec<String>, HashMap<String, i32>)> {
-    let mut boxes: HashMap<i32, (Vec<String>, HashMap<String, i32>)> = HashMap::new();
-    let data = parse_input_with_commands(input);
+fn do_lense_boxing(input: &str) -> Result<Boxes, AocError> {
+    let mut boxes: Boxes = HashMap::new();
+    let data = parse_input_with_commands(input)?;
     data.iter()
         .for_each(|(operation, label, label_value, focal_length)| {
             let box_number = process_hash(label_value);
@@ -77,10 +88,10 @@ fn do_lense_boxing(input: &str) -> HashMap<i32, (Vec<String>, HashMap<String, i3
             }
         });
 
-    boxes
+    Ok(boxes)
 }
 
-fn calculate_box_scores(boxes: &HashMap<i32, (Vec<String>, HashMap<String, i32>)>) -> i32 {
+fn calculate_box_scores(boxes: &Boxes) -> i32 {
     boxes
         .par_iter()
         .map(|(box_number, (slots, lens))| {
@@ -108,23 +119,19 @@ fn parse_input(input: &Vec<&str>) -> Vec<Vec<i32>> {
         .collect()
 }
 
-fn parse_input_with_commands(input: &str) -> Vec<(i32, String, Vec<i32>, i32)> {
+fn parse_input_with_commands(input: &str) -> Result<Vec<Step>, AocError> {
     input
         .split(",")
         .map(|value| {
-            if value.contains("=") {
-                let sub_values: Vec<&str> = value.split("=").collect();
-                let label = sub_values[0].to_owned();
-                let label_values = parse_string(sub_values[0]);
-                let focal_length = sub_values[1].parse::<i32>().unwrap();
-                (1, label, label_values, focal_length)
-            } else if value.contains("-") {
-                let sub_values: Vec<&str> = value.split("-").collect();
-                let label = sub_values[0].to_owned();
-                let label_values = parse_string(sub_values[0]);
-                (-1, label, label_values, 0)
+            if let Some((label, focal_length)) = value.split_once("=") {
+                let focal_length = focal_length
+                    .parse::<i32>()
+                    .map_err(|_| AocError::parse(0, value, "invalid focal length"))?;
+                Ok((1, label.to_owned(), parse_string(label), focal_length))
+            } else if let Some(label) = value.strip_suffix("-") {
+                Ok((-1, label.to_owned(), parse_string(label), 0))
             } else {
-                unreachable!("It should not be here: {}", value);
+                Err(AocError::parse(0, value, "expected `=` or `-` operation"))
             }
         })
         .collect()
@@ -158,7 +165,7 @@ mod tests {
     #[test]
     fn test_parse_input_with_commands() {
         let (operation, label, label_value, focal_length) =
-            parse_input_with_commands("rn=1")[0].clone();
+            parse_input_with_commands("rn=1").unwrap()[0].clone();
         assert_eq!(operation, 1);
         assert_eq!(label, "rn".to_owned());
         assert_eq!(label_value, vec![114, 110]);
@@ -195,16 +202,7 @@ mod tests {
     }
 
     #[test]
-    fn test_part_1() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day15::part_01(&lines);
-        assert_eq!(result, 1320);
-    }
-
-    #[test]
-    fn test_part_2() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day15::part_02(&lines);
-        assert_eq!(result, 145);
+    fn test_examples() {
+        crate::solution::assert_examples::<Day15>();
     }
 }
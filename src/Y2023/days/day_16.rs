@@ -1,63 +1,73 @@
-use crate::file_handler::FileHandler;
+use crate::error::AocError;
+use crate::point::Direction;
+use crate::solution::{Example, Solution};
 use itertools::Itertools;
 use rayon::prelude::*;
 use std::collections::{HashMap, HashSet, VecDeque};
 use std::io::{stdout, Write};
 use std::thread;
-use std::time::{Duration, Instant};
+use std::time::Duration;
 
 pub struct Day16 {}
 
-impl Day16 {
-    pub fn run() -> (String, String, String, u128, u128) {
-        let first_input = FileHandler::read("./src/Y2023/inputs/day_16_1.txt");
-
-        let lines: Vec<&str> = first_input.split('\n').collect();
-
-        let start_1 = Instant::now();
-        let result_1 = Day16::part_01(&lines);
-        let time_calculation_1 = start_1.elapsed();
-
-        let start_2 = Instant::now();
-        let result_2 = Day16::part_02(&lines);
-        let time_calculation_2 = start_2.elapsed();
-
-        (
-            "Day_16".to_owned(),
-            format!("{}", result_1),
-            format!("{}", result_2),
-            time_calculation_1.as_nanos(),
-            time_calculation_2.as_nanos(),
-        )
+/// The mirrors and splitters by position, and the width and height of the contraption.
+pub type Contraption = (HashMap<(usize, usize), String>, usize, usize);
+
+impl Solution for Day16 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 16;
+
+    type Input<'a> = Contraption;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let lines: Vec<&str> = input.split('\n').collect();
+        parse_input(&lines)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day16::part_01(input).to_string())
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day16::part_02(input).to_string())
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![
+            Example::new(1, TEST_INPUT, "46"),
+            Example::new(2, TEST_INPUT, "51"),
+        ]
     }
+}
 
-    fn part_01(lines: &Vec<&str>) -> usize {
-        let (data, width, height) = parse_input(&lines);
+impl Day16 {
+    fn part_01(input: &Contraption) -> usize {
+        let &(ref data, width, height) = input;
 
-        let result = process_light(&data, width, height, (0, 0), BeamDirection::Right);
+        let result = process_light(data, width, height, (0, 0), Direction::Right);
 
         result.len()
     }
 
-    fn part_02(lines: &Vec<&str>) -> usize {
-        let (data, width, height) = parse_input(&lines);
-        let mut top: Vec<((usize, usize), BeamDirection)> = (0..width)
-            .map(|column| ((column, 0), BeamDirection::Down))
+    fn part_02(input: &Contraption) -> usize {
+        let &(ref data, width, height) = input;
+        let mut top: Vec<((usize, usize), Direction)> = (0..width)
+            .map(|column| ((column, 0), Direction::Down))
             .collect();
 
         let mut bottom = (0..width)
-            .map(|column| ((column, height - 1), BeamDirection::Up))
+            .map(|column| ((column, height - 1), Direction::Up))
             .collect();
 
         let mut left = (0..height)
-            .map(|row| ((0, row), BeamDirection::Right))
+            .map(|row| ((0, row), Direction::Right))
             .collect();
 
         let mut right = (0..height)
-            .map(|row| ((width - 1, row), BeamDirection::Left))
+            .map(|row| ((width - 1, row), Direction::Left))
             .collect();
 
-        let mut all_start_node: Vec<((usize, usize), BeamDirection)> = vec![];
+        let mut all_start_node: Vec<((usize, usize), Direction)> = vec![];
         all_start_node.append(&mut top);
         all_start_node.append(&mut bottom);
         all_start_node.append(&mut left);
@@ -66,7 +76,7 @@ impl Day16 {
         all_start_node
             .par_iter()
             .map(|(start_coord, start_beam)| {
-                process_light(&data, width, height, *start_coord, *start_beam).len()
+                process_light(data, width, height, *start_coord, *start_beam).len()
             })
             .max()
             .unwrap()
@@ -78,12 +88,12 @@ fn process_light(
     width: usize,
     height: usize,
     start_coord: (usize, usize),
-    start_beam: BeamDirection,
-) -> HashMap<(usize, usize), Vec<BeamDirection>> {
+    start_beam: Direction,
+) -> HashMap<(usize, usize), Vec<Direction>> {
     let mut queue = VecDeque::new();
     let mut duplicated_light = HashSet::new();
     queue.push_back((start_coord, start_beam));
-    let mut energized: HashMap<(usize, usize), Vec<BeamDirection>> = HashMap::new();
+    let mut energized: HashMap<(usize, usize), Vec<Direction>> = HashMap::new();
     loop {
         if let Some(node) = queue.pop_back() {
             let current_coord = node.0;
@@ -142,112 +152,69 @@ fn can_travel_next(
     width: usize,
     height: usize,
     coord: (usize, usize),
-    beam: BeamDirection,
+    beam: Direction,
 ) -> (bool, (usize, usize)) {
-    let column = coord.0;
-    let row = coord.1;
-    match beam {
-        BeamDirection::Left => {
-            if column == 0 {
-                (false, coord)
-            } else {
-                (true, (column.saturating_sub(1), row))
-            }
-        }
-        BeamDirection::Right => {
-            if column == width - 1 {
-                (false, coord)
-            } else {
-                (true, (column.saturating_add(1), row))
-            }
-        }
-        BeamDirection::Up => {
-            if row == 0 {
-                (false, coord)
-            } else {
-                (true, (column, row.saturating_sub(1)))
-            }
-        }
-        BeamDirection::Down => {
-            if row == height - 1 {
-                (false, coord)
-            } else {
-                (true, (column, row.saturating_add(1)))
-            }
-        }
+    match beam.step_within(coord, width, height) {
+        Some(next_coord) => (true, next_coord),
+        None => (false, coord),
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-enum BeamDirection {
-    Up,
-    Down,
-    Right,
-    Left,
-}
-
-impl BeamDirection {
-    fn to_string(&self) -> String {
-        match self {
-            BeamDirection::Down => "V".to_owned(),
-            BeamDirection::Up => "^".to_owned(),
-            BeamDirection::Left => "<".to_owned(),
-            BeamDirection::Right => ">".to_owned(),
-        }
-    }
-}
-
-fn mirror_reflect(beam: BeamDirection, mirror: &str) -> Vec<BeamDirection> {
+fn mirror_reflect(beam: Direction, mirror: &str) -> Vec<Direction> {
     match beam {
-        BeamDirection::Right => match mirror {
-            "/" => vec![BeamDirection::Up],
-            "\\" => vec![BeamDirection::Down],
-            "|" => vec![BeamDirection::Up, BeamDirection::Down],
+        Direction::Right => match mirror {
+            "/" => vec![Direction::Up],
+            "\\" => vec![Direction::Down],
+            "|" => vec![Direction::Up, Direction::Down],
             "." | "-" => vec![beam],
             _ => unreachable!("It should not be here: {}", mirror),
         },
 
-        BeamDirection::Left => match mirror {
-            "/" => vec![BeamDirection::Down],
-            "\\" => vec![BeamDirection::Up],
-            "|" => vec![BeamDirection::Up, BeamDirection::Down],
+        Direction::Left => match mirror {
+            "/" => vec![Direction::Down],
+            "\\" => vec![Direction::Up],
+            "|" => vec![Direction::Up, Direction::Down],
             "." | "-" => vec![beam],
             _ => unreachable!("It should not be here: {}", mirror),
         },
 
-        BeamDirection::Up => match mirror {
-            "/" => vec![BeamDirection::Right],
-            "\\" => vec![BeamDirection::Left],
-            "-" => vec![BeamDirection::Left, BeamDirection::Right],
+        Direction::Up => match mirror {
+            "/" => vec![Direction::Right],
+            "\\" => vec![Direction::Left],
+            "-" => vec![Direction::Left, Direction::Right],
             "." | "|" => vec![beam],
             _ => unreachable!("It should not be here: {}", mirror),
         },
 
-        BeamDirection::Down => match mirror {
-            "/" => vec![BeamDirection::Left],
-            "\\" => vec![BeamDirection::Right],
-            "-" => vec![BeamDirection::Left, BeamDirection::Right],
+        Direction::Down => match mirror {
+            "/" => vec![Direction::Left],
+            "\\" => vec![Direction::Right],
+            "-" => vec![Direction::Left, Direction::Right],
             "." | "|" => vec![beam],
             _ => unreachable!("It should not be here: {}", mirror),
         },
+
+        _ => unreachable!("Beams only travel in cardinal directions: {:?}", beam),
     }
 }
 
-fn parse_input(input: &Vec<&str>) -> (HashMap<(usize, usize), String>, usize, usize) {
+fn parse_input(input: &Vec<&str>) -> Result<Contraption, AocError> {
     let mut width = 0;
     let height = input.len();
     let mut data = HashMap::new();
-    input.iter().enumerate().for_each(|(row, list)| {
+    for (row, list) in input.iter().enumerate() {
         width = list.len();
-        list.chars().enumerate().for_each(|(column, c)| {
+        for (column, c) in list.chars().enumerate() {
             let coord = (column, row);
             if c == '\\' || c == '/' || c == '-' || c == '|' {
                 data.insert(coord, String::from(c));
+            } else if c != '.' {
+                return Err(AocError::parse(row, list, format!("unknown tile `{}`", c)));
             }
-        });
-    });
+        }
+    }
 
-    (data, width, height)
+    Ok((data, width, height))
 }
 
 const TEST_INPUT: &str = ".|...\\....
@@ -268,91 +235,91 @@ mod tests {
     #[test]
     fn test_can_travel() {
         let coord = (0, 0);
-        let (can, next_coord) = can_travel_next(10, 10, coord, BeamDirection::Left);
+        let (can, next_coord) = can_travel_next(10, 10, coord, Direction::Left);
         assert_eq!(can, false);
         assert_eq!(next_coord, coord);
 
-        let (can, next_coord) = can_travel_next(10, 10, coord, BeamDirection::Up);
+        let (can, next_coord) = can_travel_next(10, 10, coord, Direction::Up);
         assert_eq!(can, false);
         assert_eq!(next_coord, coord);
 
-        let (can, next_coord) = can_travel_next(10, 10, coord, BeamDirection::Right);
+        let (can, next_coord) = can_travel_next(10, 10, coord, Direction::Right);
         assert_eq!(can, true);
         assert_eq!(next_coord, (1, 0));
 
-        let (can, next_coord) = can_travel_next(10, 10, coord, BeamDirection::Down);
+        let (can, next_coord) = can_travel_next(10, 10, coord, Direction::Down);
         assert_eq!(can, true);
         assert_eq!(next_coord, (0, 1));
     }
 
     #[test]
     fn test_reflected() {
-        let start_beam = BeamDirection::Right;
+        let start_beam = Direction::Right;
         let result = mirror_reflect(start_beam, "-");
-        assert_eq!(result, vec![BeamDirection::Right]);
+        assert_eq!(result, vec![Direction::Right]);
         let result = mirror_reflect(start_beam, "/");
-        assert_eq!(result, vec![BeamDirection::Up]);
+        assert_eq!(result, vec![Direction::Up]);
         let result = mirror_reflect(start_beam, "\\");
-        assert_eq!(result, vec![BeamDirection::Down]);
+        assert_eq!(result, vec![Direction::Down]);
         let result = mirror_reflect(start_beam, "|");
-        assert_eq!(result, vec![BeamDirection::Up, BeamDirection::Down]);
+        assert_eq!(result, vec![Direction::Up, Direction::Down]);
 
-        let start_beam = BeamDirection::Left;
+        let start_beam = Direction::Left;
         let result = mirror_reflect(start_beam, "-");
-        assert_eq!(result, vec![BeamDirection::Left]);
+        assert_eq!(result, vec![Direction::Left]);
         let result = mirror_reflect(start_beam, "/");
-        assert_eq!(result, vec![BeamDirection::Down]);
+        assert_eq!(result, vec![Direction::Down]);
         let result = mirror_reflect(start_beam, "\\");
-        assert_eq!(result, vec![BeamDirection::Up]);
+        assert_eq!(result, vec![Direction::Up]);
         let result = mirror_reflect(start_beam, "|");
-        assert_eq!(result, vec![BeamDirection::Up, BeamDirection::Down]);
+        assert_eq!(result, vec![Direction::Up, Direction::Down]);
 
-        let start_beam = BeamDirection::Up;
+        let start_beam = Direction::Up;
         let result = mirror_reflect(start_beam, "-");
-        assert_eq!(result, vec![BeamDirection::Left, BeamDirection::Right]);
+        assert_eq!(result, vec![Direction::Left, Direction::Right]);
         let result = mirror_reflect(start_beam, "/");
-        assert_eq!(result, vec![BeamDirection::Right]);
+        assert_eq!(result, vec![Direction::Right]);
         let result = mirror_reflect(start_beam, "\\");
-        assert_eq!(result, vec![BeamDirection::Left]);
+        assert_eq!(result, vec![Direction::Left]);
         let result = mirror_reflect(start_beam, "|");
-        assert_eq!(result, vec![BeamDirection::Up]);
+        assert_eq!(result, vec![Direction::Up]);
 
-        let start_beam = BeamDirection::Down;
+        let start_beam = Direction::Down;
         let result = mirror_reflect(start_beam, "-");
-        assert_eq!(result, vec![BeamDirection::Left, BeamDirection::Right]);
+        assert_eq!(result, vec![Direction::Left, Direction::Right]);
         let result = mirror_reflect(start_beam, "/");
-        assert_eq!(result, vec![BeamDirection::Left]);
+        assert_eq!(result, vec![Direction::Left]);
         let result = mirror_reflect(start_beam, "\\");
-        assert_eq!(result, vec![BeamDirection::Right]);
+        assert_eq!(result, vec![Direction::Right]);
         let result = mirror_reflect(start_beam, "|");
-        assert_eq!(result, vec![BeamDirection::Down]);
+        assert_eq!(result, vec![Direction::Down]);
     }
 
     #[test]
     fn test_light_travel() {
         let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let (data, width, height) = parse_input(&lines);
+        let (data, width, height) = parse_input(&lines).unwrap();
 
-        let result = process_light(&data, width, height, (0, 0), BeamDirection::Right);
+        let result = process_light(&data, width, height, (0, 0), Direction::Right);
         assert_eq!(result.len(), 46);
 
         let lines: Vec<&str> = ".|...\\....".lines().collect();
-        let (data, width, height) = parse_input(&lines);
+        let (data, width, height) = parse_input(&lines).unwrap();
 
-        let result = process_light(&data, width, height, (0, 0), BeamDirection::Right);
+        let result = process_light(&data, width, height, (0, 0), Direction::Right);
         assert_eq!(result.len(), 2);
 
         let lines: Vec<&str> = "..........".lines().collect();
-        let (data, width, height) = parse_input(&lines);
+        let (data, width, height) = parse_input(&lines).unwrap();
 
-        let result = process_light(&data, width, height, (0, 0), BeamDirection::Right);
+        let result = process_light(&data, width, height, (0, 0), Direction::Right);
         assert_eq!(result.len(), width);
     }
 
     #[test]
     fn test_parse_input() {
         let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let (data, width, height) = parse_input(&lines);
+        let (data, width, height) = parse_input(&lines).unwrap();
         let coord = (4, 1);
         assert_eq!(data.get(&coord).unwrap(), &"\\".to_owned());
         let coord = (5, 1);
@@ -363,17 +330,8 @@ mod tests {
     }
 
     #[test]
-    fn test_part_1() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day16::part_01(&lines);
-        assert_eq!(result, 46);
-    }
-
-    #[test]
-    fn test_part_2() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day16::part_02(&lines);
-        assert_eq!(result, 51);
+    fn test_examples() {
+        crate::solution::assert_examples::<Day16>();
     }
 }
 
@@ -381,7 +339,7 @@ fn print_direction_table(
     width: usize,
     height: usize,
     data: &HashMap<(usize, usize), String>,
-    energized: &HashMap<(usize, usize), Vec<BeamDirection>>,
+    energized: &HashMap<(usize, usize), Vec<Direction>>,
 ) {
     for row in 0..height {
         for column in 0..width {
@@ -413,7 +371,7 @@ fn print_table(
     width: usize,
     height: usize,
     data: &HashMap<(usize, usize), String>,
-    energized: &HashMap<(usize, usize), Vec<BeamDirection>>,
+    energized: &HashMap<(usize, usize), Vec<Direction>>,
 ) {
     for row in 0..height {
         for column in 0..width {
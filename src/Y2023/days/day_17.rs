@@ -1,415 +1,305 @@
-use crate::file_handler::FileHandler;
-use geo::coord;
-use rayon::prelude::*;
-use std::collections::{HashMap, HashSet, VecDeque};
-use std::time::Instant;
+use crate::error::AocError;
+use crate::grid::Grid;
+use crate::point::Direction;
+use crate::search::{self, SearchResult};
+use crate::solution::{configure_each, unknown_parameter, Example, Solution};
+use std::collections::HashMap;
 
 pub struct Day17 {}
 
-impl Day17 {
-    pub fn run() -> (String, String, String, u128, u128) {
-        let first_input = FileHandler::read("./src/Y2023/inputs/day_17_1.txt");
-
-        let lines: Vec<&str> = first_input.split('\n').collect();
-
-        let start_1 = Instant::now();
-        let result_1 = Day17::part_01(&lines);
-        let time_calculation_1 = start_1.elapsed();
-
-        let start_2 = Instant::now();
-        let result_2 = Day17::part_02(&lines);
-        let time_calculation_2 = start_2.elapsed();
-
-        (
-            "Day_17".to_owned(),
-            format!("{}", result_1),
-            format!("{}", result_2),
-            time_calculation_1.as_nanos(),
-            time_calculation_2.as_nanos(),
-        )
+/// The city blocks and the crucibles of both parts.
+pub struct City {
+    grid: Grid<i32>,
+    crucible: CrucibleRules,
+    ultra_crucible: CrucibleRules,
+    /// Whether the parts write the path they chose to stderr.
+    show_path: bool,
+}
+
+impl Solution for Day17 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 17;
+
+    type Input<'a> = City;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let lines: Vec<&str> = input.split('\n').collect();
+        Ok(City {
+            grid: parse_input(&lines)?,
+            crucible: CrucibleRules::CRUCIBLE,
+            ultra_crucible: CrucibleRules::ULTRA_CRUCIBLE,
+            show_path: false,
+        })
+    }
+
+    fn part_one(city: &Self::Input<'_>) -> Result<String, AocError> {
+        Day17::part_01(city)
+            .map(|heat_loss| heat_loss.to_string())
+            .ok_or_else(|| AocError::unsolvable("the crucible cannot reach the factory"))
     }
 
-    fn part_01(lines: &Vec<&str>) -> i32 {
-        return 0;
+    fn part_two(city: &Self::Input<'_>) -> Result<String, AocError> {
+        Day17::part_02(city)
+            .map(|heat_loss| heat_loss.to_string())
+            .ok_or_else(|| AocError::unsolvable("the crucible cannot reach the factory"))
     }
 
-    fn part_02(lines: &Vec<&str>) -> i32 {
-        return 0;
+    fn examples() -> Vec<Example> {
+        vec![
+            Example::new(1, TEST_INPUT, "102"),
+            Example::new(1, TEST_INPUT_2, "7"),
+            Example::new(1, TEST_INPUT, "94")
+                .with("min_straight", 4)
+                .with("max_straight", 10),
+            Example::new(2, TEST_INPUT, "94"),
+            Example::new(2, TEST_INPUT_3, "71"),
+        ]
+    }
+
+    /// `min_straight` and `max_straight` replace that limit of the crucibles of both parts,
+    /// `path` set to 1 writes the chosen path of every part to stderr.
+    fn configure(city: &mut Self::Input<'_>, params: &[(&str, i64)]) -> Result<(), AocError> {
+        configure_each(params, |name, value| {
+            match name {
+                "min_straight" if value < 1 => {
+                    return Err(AocError::unsolvable(
+                        "a crucible has to go at least 1 block straight",
+                    ))
+                }
+                "min_straight" => {
+                    city.crucible.min_straight = value as i32;
+                    city.ultra_crucible.min_straight = value as i32;
+                }
+                "max_straight" => {
+                    city.crucible.max_straight = value as i32;
+                    city.ultra_crucible.max_straight = value as i32;
+                }
+                "path" => city.show_path = value != 0,
+                _ => return Err(unknown_parameter(name)),
+            }
+
+            Ok(())
+        })?;
+
+        for rules in [city.crucible, city.ultra_crucible] {
+            if rules.max_straight < rules.min_straight {
+                return Err(AocError::unsolvable(format!(
+                    "a crucible cannot go at least {} but at most {} blocks straight",
+                    rules.min_straight, rules.max_straight
+                )));
+            }
+        }
+
+        Ok(())
     }
 }
 
-fn parse_input(
-    input: &Vec<&str>,
-) -> (
-    usize,
-    usize,
-    HashMap<(usize, usize), i32>,
-    Vec<Vec<((usize, usize), i32)>>,
-) {
-    let height = input.len();
-    let mut width = 0;
-    let data: Vec<Vec<((usize, usize), i32)>> = (input)
-        .par_iter()
-        .enumerate()
-        .map(|(row, columns_data)| {
-            let row: Vec<((usize, usize), i32)> = columns_data
-                .chars()
-                .enumerate()
-                .map(|(column, c)| {
-                    let coord = (column, row);
-                    if c.is_digit(10) {
-                        (coord, c.to_digit(10).unwrap() as i32)
-                    } else {
-                        unreachable!("It should not be here");
-                    }
-                })
-                .collect();
+impl Day17 {
+    fn part_01(city: &City) -> Option<i32> {
+        Day17::least_heat_loss(city, &city.crucible)
+    }
 
-            row
-        })
-        .collect();
+    fn part_02(city: &City) -> Option<i32> {
+        Day17::least_heat_loss(city, &city.ultra_crucible)
+    }
 
-    let mut grid = HashMap::new();
-    data.iter().for_each(|list| {
-        width = list.len();
-        list.iter().for_each(|(coord, value)| {
-            grid.insert(coord.clone(), *value);
-        });
-    });
+    fn least_heat_loss(city: &City, rules: &CrucibleRules) -> Option<i32> {
+        let (heat_loss, paths, directions) = travel(&city.grid, rules)?;
+        if city.show_path {
+            eprintln!(
+                "{}",
+                print_paths_direction(
+                    city.grid.width(),
+                    city.grid.height(),
+                    |coord| city.grid.get(coord).copied(),
+                    &paths,
+                    &directions,
+                )
+            );
+        }
 
-    (width, height, grid, data)
+        Some(heat_loss)
+    }
+}
+
+fn parse_input(input: &Vec<&str>) -> Result<Grid<i32>, AocError> {
+    let grid = Grid::parse(input, |c| c.to_digit(10).map(|value| value as i32))?;
+    if grid.width() == 0 || grid.height() == 0 {
+        return Err(AocError::unsolvable("the map has no city blocks"));
+    }
+
+    Ok(grid)
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
-enum TravelDirection {
-    Up(i32),
-    Down(i32),
-    Left(i32),
-    Right(i32),
-    None,
+/// The direction the crucible last moved in and how many blocks it went straight that way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct TravelDirection {
+    direction: Option<Direction>,
+    steps: i32,
 }
 
 impl TravelDirection {
-    fn get_value(&self) -> i32 {
-        match self {
-            TravelDirection::Up(value) => *value,
-            TravelDirection::Down(value) => *value,
-            TravelDirection::Left(value) => *value,
-            TravelDirection::Right(value) => *value,
-            TravelDirection::None => 0,
+    const NONE: TravelDirection = TravelDirection {
+        direction: None,
+        steps: 0,
+    };
+
+    fn new(direction: Direction, steps: i32) -> Self {
+        TravelDirection {
+            direction: Some(direction),
+            steps,
         }
     }
 
-    fn to_string(&self) -> String {
-        match self {
-            TravelDirection::Up(value) => "U".to_owned(),
-            TravelDirection::Down(value) => "D".to_owned(),
-            TravelDirection::Left(value) => "L".to_owned(),
-            TravelDirection::Right(value) => "R".to_owned(),
-            TravelDirection::None => "".to_owned(),
+    /// The heading and the blocks gone straight, e.g. `>3`.
+    fn label(&self) -> String {
+        match self.direction {
+            Some(direction) => format!("{}{}", direction, self.steps),
+            None => String::new(),
         }
     }
 }
 
-fn dijkstra(
-    width: usize,
-    height: usize,
-    grids: &HashMap<(usize, usize), i32>,
-) -> (
-    HashMap<(usize, usize), i32>,
-    HashMap<(usize, usize), (usize, usize)>,
-) {
-    let starting_point = (0, 0);
-    let mut shortest_path = vec![];
-    let mut heat_loss: HashMap<(usize, usize), i32> = HashMap::new();
-    let mut directions = HashMap::new();
-    let mut paths: HashMap<(usize, usize), (usize, usize)> = HashMap::new();
-    heat_loss.insert(starting_point, 0);
-    directions.insert(starting_point, TravelDirection::None);
-    loop {
-        let mut candidate: Vec<((usize, usize), i32)> = heat_loss
-            .iter()
-            .filter_map(|(coord, value)| {
-                if shortest_path.contains(coord) == false {
-                    Some((*coord, *value))
-                } else {
-                    None
-                }
-            })
-            .collect();
-
-        candidate.sort_by(|a, b| a.1.cmp(&b.1));
-
-        if let Some((current_coord, current_heat_loss)) = candidate.first() {
-            shortest_path.push(*current_coord);
-            if let Some(travel_direction) = directions.get(current_coord) {
-                let neighbours =
-                    find_neighbour(width, height, grids, *current_coord, *travel_direction);
-
-                neighbours
-                    .iter()
-                    .for_each(|(next_coord, next_direction, next_heat_loss)| {
-                        let (combined_heat_lost, direction) =
-                            if let Some(existing_heat_loss) = heat_loss.get(next_coord) {
-                                if current_heat_loss + next_heat_loss <= *existing_heat_loss {
-                                    // update with a new heat loss value
-                                    (current_heat_loss + next_heat_loss, *next_direction)
-                                } else {
-                                    return;
-                                }
-                            } else {
-                                (current_heat_loss + next_heat_loss, *next_direction)
-                            };
-
-                        paths.insert(*next_coord, *current_coord);
-                        heat_loss.insert(*next_coord, combined_heat_lost);
-                        directions.insert(*next_coord, direction);
-                    });
-            } else {
-                break;
-            }
+/// How far a crucible has to go straight before it may turn or stop, and how far it may go
+/// straight at most.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct CrucibleRules {
+    min_straight: i32,
+    max_straight: i32,
+}
 
-            print_paths_direction(width, height, &heat_loss, &paths, &directions);
-        } else {
-            break;
-        }
-    }
+impl CrucibleRules {
+    const CRUCIBLE: CrucibleRules = CrucibleRules {
+        min_straight: 1,
+        max_straight: 3,
+    };
 
-    (heat_loss, paths)
+    const ULTRA_CRUCIBLE: CrucibleRules = CrucibleRules {
+        min_straight: 4,
+        max_straight: 10,
+    };
 }
 
-fn travel(
-    width: usize,
-    height: usize,
-    grids: &HashMap<(usize, usize), i32>,
-) -> (
-    HashMap<(usize, usize), i32>,
+/// A crucible on its way: where it is, where it is heading and how long it went straight.
+type Crucible = ((usize, usize), TravelDirection);
+
+/// Searches the cheapest way from the top-left block to the bottom-right one, the crucible
+/// having to go at least `min_straight` blocks straight before it can stop there.
+fn dijkstra(grid: &Grid<i32>, rules: &CrucibleRules) -> SearchResult<Crucible, i32> {
+    let starting_point = ((0, 0), TravelDirection::NONE);
+    let end_point = (grid.width() - 1, grid.height() - 1);
+    search::dijkstra(
+        [starting_point],
+        |&(coord, direction)| {
+            find_neighbour(grid, coord, direction, rules)
+                .into_iter()
+                .map(|(next_coord, next_direction, next_heat_loss)| {
+                    ((next_coord, next_direction), next_heat_loss)
+                })
+        },
+        |(coord, direction)| *coord == end_point && direction.steps >= rules.min_straight,
+    )
+}
+
+/// The heat lost, the path taken (Destination -> Source) and the direction the crucible
+/// entered each of its blocks with.
+type ChosenPath = (
+    i32,
     HashMap<(usize, usize), (usize, usize)>,
     HashMap<(usize, usize), TravelDirection>,
-) {
-    let mut visited: HashSet<(usize, usize)> = HashSet::new();
-    let mut heat_loss: HashMap<(usize, usize), i32> = HashMap::new();
-    let mut queue = VecDeque::new();
-    let mut directions: HashMap<(usize, usize), TravelDirection> = HashMap::new();
-    // (Destination -> Source)
-    let mut paths: HashMap<(usize, usize), (usize, usize)> = HashMap::new();
-    let starting_point = (0, 0);
-
-    queue.push_back((starting_point, TravelDirection::None));
-    heat_loss.insert(starting_point, 0);
-    visited.insert(starting_point);
-    directions.insert(starting_point, TravelDirection::None);
-    loop {
-        if queue.len() == 0 {
-            break;
-        }
-
-        let mut unsorted_data: Vec<(i32, usize)> = queue
-            .iter()
-            .enumerate()
-            .map(|(index, (coord, direction))| {
-                let heat_loss = heat_loss.get(coord).copied().unwrap_or(0);
-                (heat_loss, index)
-            })
-            .collect();
-        unsorted_data.sort_by(|a, b| a.0.cmp(&b.0));
-
-        let index = unsorted_data.first().unwrap();
-
-        if let Some((current_coord, _)) = queue.remove(index.1) {
-            let current_direction = directions
-                .get(&current_coord)
-                .copied()
-                .unwrap_or(TravelDirection::None);
-            let neighbours = find_neighbour(width, height, grids, current_coord, current_direction);
-            let current_heat_loss = heat_loss.get(&current_coord).copied().unwrap();
-
-            neighbours
-                .iter()
-                .for_each(|(next_coord, next_direction, next_heat_loss)| {
-                    let combined_heat_loss = current_heat_loss + next_heat_loss;
-                    if *next_coord == (11, 7) {
-                        println!("{:?} = {}", next_coord, combined_heat_loss);
-                    }
-
-                    // new node here
-                    if visited.contains(next_coord) == false {
-                        visited.insert(*next_coord);
-                        heat_loss.insert(*next_coord, combined_heat_loss);
-                        paths.insert(*next_coord, current_coord);
-                        queue.push_back((*next_coord, *next_direction));
-                        directions.insert(*next_coord, *next_direction);
-                    } else {
-                        // check for better path with less heat_loss
-                        if let Some(visited_heat_loss) = heat_loss.get(next_coord) {
-                            if combined_heat_loss <= *visited_heat_loss {
-                                // It need to check that it cant travel more than 3 blocks with the same direction here
-                                if let Some(travel_direction) = directions.get(next_coord) {
-                                    if std::mem::discriminant(travel_direction)
-                                        == std::mem::discriminant(next_direction)
-                                    {
-                                        if travel_direction.get_value() + next_direction.get_value()
-                                            >= 3
-                                        {
-                                            return;
-                                        }
-                                    }
-                                }
-
-                                heat_loss.insert(*next_coord, combined_heat_loss);
-                                paths.insert(*next_coord, current_coord);
-                                queue.push_back((*next_coord, *next_direction));
-                                directions.insert(*next_coord, *next_direction);
-                            }
-                        }
-                    }
-                });
-
-            print_paths_direction(width, height, &heat_loss, &paths, &directions);
-        } else {
-            break;
-        }
-    }
+);
+
+/// The least heat loss to the bottom-right block and the path that achieves it.
+fn travel(grid: &Grid<i32>, rules: &CrucibleRules) -> Option<ChosenPath> {
+    let result = dijkstra(grid, rules);
+    let end = result.goal.as_ref()?;
+
+    let path = result.path_to(end);
+    let paths = path.windows(2).map(|pair| (pair[1].0, pair[0].0)).collect();
+    let directions = path
+        .iter()
+        .map(|(coord, direction)| (*coord, *direction))
+        .collect();
 
-    (heat_loss, paths, directions)
+    Some((result.goal_distance()?, paths, directions))
 }
 
+/// Draws the grid with the heat loss of every block, the blocks of the path in brackets with
+/// the heading the crucible entered them with and how far it had gone straight.
 fn print_paths_direction(
     width: usize,
     height: usize,
-    grids: &HashMap<(usize, usize), i32>,
+    values: impl Fn((usize, usize)) -> Option<i32>,
     paths: &HashMap<(usize, usize), (usize, usize)>,
     directions: &HashMap<(usize, usize), TravelDirection>,
-) -> i32 {
+) -> String {
     let mut next_path = (width - 1, height - 1);
-    let mut total_heat_loss_values = grids.get(&next_path).copied().unwrap_or(0);
     let mut shortest_path = vec![];
     shortest_path.push(next_path);
 
-    loop {
-        if let Some(path) = paths.get(&next_path) {
-            shortest_path.push(*path);
-            next_path = *path;
-            total_heat_loss_values += grids.get(&next_path).copied().unwrap_or(0);
-        } else {
-            break;
-        }
+    while let Some(path) = paths.get(&next_path) {
+        shortest_path.push(*path);
+        next_path = *path;
     }
 
+    let mut result = String::new();
     for row in 0..height {
         for column in 0..width {
             let coord = (column, row);
-            if shortest_path.contains(&coord) == true {
-                if let Some(direction) = directions.get(&coord) {
-                    print!(" [{}{}]", direction.to_string(), direction.get_value());
-                } else if let Some(value) = grids.get(&coord) {
-                    print!("[{:>3}]", value);
-                } else {
-                    print!("   # ");
-                }
-            } else if let Some(value) = grids.get(&coord) {
-                if let Some(direction) = directions.get(&coord) {
-                    print!("  {}{} ", direction.to_string(), direction.get_value());
-                } else {
-                    print!(" {: >3} ", value);
+            let cell = if shortest_path.contains(&coord) {
+                match (directions.get(&coord), values(coord)) {
+                    // The starting block is never entered, so it has no heading.
+                    (Some(direction), _) if direction.direction.is_some() => {
+                        format!(" [{}]", direction.label())
+                    }
+                    (_, Some(value)) => format!("[{:>3}]", value),
+                    _ => "   # ".to_owned(),
                 }
+            } else if let Some(value) = values(coord) {
+                format!(" {: >3} ", value)
             } else {
-                print!("   - ");
-            }
+                "   - ".to_owned()
+            };
+            result.push_str(&cell);
         }
-        println!("");
+        result.push('\n');
     }
 
-    println!("\n");
-
-    total_heat_loss_values
+    result
 }
 
+/// The blocks the crucible can move to next, with the direction it moves in and the heat
+/// it loses entering the block. It never reverses and only turns once it went `min_straight`.
 fn find_neighbour(
-    width: usize,
-    height: usize,
-    grids: &HashMap<(usize, usize), i32>,
+    grid: &Grid<i32>,
     current_coord: (usize, usize),
     previous_moved_direction: TravelDirection,
+    rules: &CrucibleRules,
 ) -> Vec<((usize, usize), TravelDirection, i32)> {
     let mut result: Vec<((usize, usize), TravelDirection, i32)> = vec![];
-    // up
-    if current_coord.1 > 0 {
-        let next_coord = (current_coord.0, current_coord.1 - 1);
-        if matches!(previous_moved_direction, TravelDirection::Up(_)) == false {
-            result.push((
-                next_coord,
-                TravelDirection::Up(1),
-                *grids.get(&next_coord).unwrap(),
-            ));
-        } else if let TravelDirection::Up(travel_value) = previous_moved_direction {
-            if travel_value < 3 {
-                result.push((
-                    next_coord,
-                    TravelDirection::Up(travel_value + 1),
-                    *grids.get(&next_coord).unwrap(),
-                ));
-            }
-        }
-    }
-
-    // down
-    if current_coord.1 < height - 1 {
-        let next_coord = (current_coord.0, current_coord.1 + 1);
-        if matches!(previous_moved_direction, TravelDirection::Down(_)) == false {
-            result.push((
-                next_coord,
-                TravelDirection::Down(1),
-                *grids.get(&next_coord).unwrap(),
-            ));
-        } else if let TravelDirection::Down(travel_value) = previous_moved_direction {
-            if travel_value < 3 {
-                result.push((
-                    next_coord,
-                    TravelDirection::Down(travel_value + 1),
-                    *grids.get(&next_coord).unwrap(),
-                ));
-            }
-        }
-    }
-
-    // left
-    if current_coord.0 > 0 {
-        let next_coord = (current_coord.0 - 1, current_coord.1);
-        if matches!(previous_moved_direction, TravelDirection::Left(_)) == false {
-            result.push((
-                next_coord,
-                TravelDirection::Left(1),
-                *grids.get(&next_coord).unwrap(),
-            ));
-        } else if let TravelDirection::Left(travel_value) = previous_moved_direction {
-            if travel_value < 3 {
-                result.push((
-                    next_coord,
-                    TravelDirection::Left(travel_value + 1),
-                    *grids.get(&next_coord).unwrap(),
-                ));
-            }
+    for direction in [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ] {
+        let travel_value = match previous_moved_direction.direction {
+            Some(previous) if previous == direction => previous_moved_direction.steps + 1,
+            Some(previous) if previous.turn_around() == direction => continue,
+            Some(_) if previous_moved_direction.steps < rules.min_straight => continue,
+            _ => 1,
+        };
+
+        if travel_value > rules.max_straight {
+            continue;
         }
-    }
 
-    // right
-    if current_coord.0 < width - 1 {
-        let next_coord = (current_coord.0 + 1, current_coord.1);
-        if matches!(previous_moved_direction, TravelDirection::Right(_)) == false {
+        if let Some(next_coord) = grid.step(current_coord, direction) {
             result.push((
                 next_coord,
-                TravelDirection::Right(1),
-                *grids.get(&next_coord).unwrap(),
+                TravelDirection::new(direction, travel_value),
+                grid[next_coord],
             ));
-        } else if let TravelDirection::Right(travel_value) = previous_moved_direction {
-            if travel_value < 3 {
-                result.push((
-                    next_coord,
-                    TravelDirection::Right(travel_value + 1),
-                    *grids.get(&next_coord).unwrap(),
-                ));
-            }
         }
     }
     result
@@ -433,194 +323,169 @@ const TEST_INPUT_2: &str = "12999
 11111
 99991";
 
+const TEST_INPUT_3: &str = "111111111111
+999999999991
+999999999991
+999999999991
+999999999991";
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_dijkstra() {
+        let lines: Vec<&str> = TEST_INPUT.lines().collect();
+        let grid = parse_input(&lines).unwrap();
+        let result = dijkstra(&grid, &CrucibleRules::CRUCIBLE);
+        assert_eq!(result.goal_distance(), Some(102));
+
+        let result = dijkstra(&grid, &CrucibleRules::ULTRA_CRUCIBLE);
+        assert_eq!(result.goal_distance(), Some(94));
+
         let lines: Vec<&str> = TEST_INPUT_2.lines().collect();
-        let (width, height, grids, list) = parse_input(&lines);
-        let (heat_loss, paths) = dijkstra(width, height, &grids);
-        let end_point = (width - 1, height - 1);
+        let grid = parse_input(&lines).unwrap();
+        let result = dijkstra(&grid, &CrucibleRules::CRUCIBLE);
+        assert_eq!(result.goal_distance(), Some(7));
 
-        print_paths(width, height, &heat_loss, &paths);
-        // print_djk(width, height, &heat_loss);
+        // Too short for an ultra crucible to turn down and still go 4 blocks straight.
+        let result = dijkstra(&grid, &CrucibleRules::ULTRA_CRUCIBLE);
+        assert_eq!(result.goal_distance(), None);
+    }
 
-        assert_eq!(heat_loss.get(&end_point).unwrap().clone(), 102);
+    #[test]
+    fn test_parse_empty() {
+        assert!(parse_input(&vec![""]).is_err());
     }
 
     #[test]
     fn test_travel() {
+        let lines: Vec<&str> = TEST_INPUT.lines().collect();
+        let grid = parse_input(&lines).unwrap();
+        let (heat_loss, paths, _) = travel(&grid, &CrucibleRules::ULTRA_CRUCIBLE).unwrap();
+        assert_eq!(heat_loss, 94);
+
+        // The heat lost on every block of the path but the start.
+        let mut block = (grid.width() - 1, grid.height() - 1);
+        let mut total = 0;
+        while let Some(previous) = paths.get(&block) {
+            total += grid[block];
+            block = *previous;
+        }
+        assert_eq!(total, 94);
+    }
+
+    #[test]
+    fn test_print_paths_direction() {
         let lines: Vec<&str> = TEST_INPUT_2.lines().collect();
-        let (width, height, grids, list) = parse_input(&lines);
-        let (heat_loss, paths, directions) = travel(width, height, &grids);
+        let grid = parse_input(&lines).unwrap();
+        let (_, paths, directions) = travel(&grid, &CrucibleRules::CRUCIBLE).unwrap();
+        let result = print_paths_direction(
+            grid.width(),
+            grid.height(),
+            |coord| grid.get(coord).copied(),
+            &paths,
+            &directions,
+        );
+        assert_eq!(
+            result,
+            "[  1] [>1]   9    9    9 \n   1  [v1] [>1] [>2] [>3]\n   9    9    9    9  [v1]\n"
+        );
+    }
 
-        let mut next_path = *paths.get(&(12, 12)).unwrap();
-        println!("{:?}", next_path);
+    #[test]
+    fn test_configure() {
+        let mut city = Day17::parse(TEST_INPUT).unwrap();
+        assert!(Day17::configure(&mut city, &[("min_straight", 0)]).is_err());
 
-        print_paths_direction(width, height, &grids, &paths, &directions);
+        let mut city = Day17::parse(TEST_INPUT).unwrap();
+        assert!(Day17::configure(&mut city, &[("max_straight", 3), ("min_straight", 4)]).is_err());
 
-        let result = print_paths(width, height, &grids, &paths);
-        assert_eq!(result, 102);
+        let mut city = Day17::parse(TEST_INPUT).unwrap();
+        assert!(Day17::configure(&mut city, &[("path", 1)]).is_ok());
+        assert!(city.show_path);
     }
 
     #[test]
     fn test_find_neighbours() {
         let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let (width, height, grids, list) = parse_input(&lines);
+        let grid = parse_input(&lines).unwrap();
         let current_coord = (0, 0);
-        let previous_moved_direction = TravelDirection::None;
+        let previous_moved_direction = TravelDirection::NONE;
         let result = find_neighbour(
-            width,
-            height,
-            &grids,
+            &grid,
             current_coord,
             previous_moved_direction,
+            &CrucibleRules::CRUCIBLE,
         );
         assert_eq!(result.len(), 2);
         assert_eq!(result[0].0, (0, 1));
-        assert_eq!(result[0].1, TravelDirection::Down(1));
+        assert_eq!(result[0].1, TravelDirection::new(Direction::Down, 1));
         assert_eq!(result[0].2, 3);
 
         assert_eq!(result[1].0, (1, 0));
-        assert_eq!(result[1].1, TravelDirection::Right(1));
+        assert_eq!(result[1].1, TravelDirection::new(Direction::Right, 1));
         assert_eq!(result[1].2, 4);
 
-        let previous_moved_direction = TravelDirection::Down(2);
+        let previous_moved_direction = TravelDirection::new(Direction::Down, 2);
         let result = find_neighbour(
-            width,
-            height,
-            &grids,
+            &grid,
             current_coord,
             previous_moved_direction,
+            &CrucibleRules::CRUCIBLE,
         );
         assert_eq!(result.len(), 2);
         assert_eq!(result[0].0, (0, 1));
-        assert_eq!(result[0].1, TravelDirection::Down(3));
+        assert_eq!(result[0].1, TravelDirection::new(Direction::Down, 3));
         assert_eq!(result[0].2, 3);
 
         assert_eq!(result[1].0, (1, 0));
-        assert_eq!(result[1].1, TravelDirection::Right(1));
+        assert_eq!(result[1].1, TravelDirection::new(Direction::Right, 1));
         assert_eq!(result[1].2, 4);
 
-        let previous_moved_direction = TravelDirection::Right(2);
+        let previous_moved_direction = TravelDirection::new(Direction::Right, 2);
         let result = find_neighbour(
-            width,
-            height,
-            &grids,
+            &grid,
             current_coord,
             previous_moved_direction,
+            &CrucibleRules::CRUCIBLE,
         );
         assert_eq!(result.len(), 2);
         assert_eq!(result[0].0, (0, 1));
-        assert_eq!(result[0].1, TravelDirection::Down(1));
+        assert_eq!(result[0].1, TravelDirection::new(Direction::Down, 1));
         assert_eq!(result[0].2, 3);
 
         assert_eq!(result[1].0, (1, 0));
-        assert_eq!(result[1].1, TravelDirection::Right(3));
+        assert_eq!(result[1].1, TravelDirection::new(Direction::Right, 3));
         assert_eq!(result[1].2, 4);
 
-        let previous_moved_direction = TravelDirection::Right(3);
+        let previous_moved_direction = TravelDirection::new(Direction::Right, 3);
         let result = find_neighbour(
-            width,
-            height,
-            &grids,
+            &grid,
             current_coord,
             previous_moved_direction,
+            &CrucibleRules::CRUCIBLE,
         );
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].0, (0, 1));
-        assert_eq!(result[0].1, TravelDirection::Down(1));
+        assert_eq!(result[0].1, TravelDirection::new(Direction::Down, 1));
         assert_eq!(result[0].2, 3);
 
-        let previous_moved_direction = TravelDirection::Down(3);
+        let previous_moved_direction = TravelDirection::new(Direction::Down, 3);
         let result = find_neighbour(
-            width,
-            height,
-            &grids,
+            &grid,
             current_coord,
             previous_moved_direction,
+            &CrucibleRules::CRUCIBLE,
         );
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].0, (1, 0));
-        assert_eq!(result[0].1, TravelDirection::Right(1));
+        assert_eq!(result[0].1, TravelDirection::new(Direction::Right, 1));
         assert_eq!(result[0].2, 4);
     }
 
     #[test]
-    fn test_part_1() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day17::part_01(&lines);
-        assert_eq!(result, 102);
-    }
-
-    #[test]
-    fn test_part_2() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day17::part_02(&lines);
-        assert_eq!(result, 2286);
+    fn test_examples() {
+        crate::solution::assert_examples::<Day17>();
     }
 }
-
-fn print_djk(width: usize, height: usize, grids: &HashMap<(usize, usize), i32>) {
-    let mut next_path = (width - 1, height - 1);
-
-    for row in 0..height {
-        for column in 0..width {
-            let coord = (column, row);
-            if let Some(value) = grids.get(&coord) {
-                print!("{: >3}", value);
-            } else {
-                print!(" - ");
-            }
-        }
-        println!("");
-    }
-
-    println!("\n");
-}
-
-fn print_paths(
-    width: usize,
-    height: usize,
-    grids: &HashMap<(usize, usize), i32>,
-    paths: &HashMap<(usize, usize), (usize, usize)>,
-) -> i32 {
-    let mut next_path = (width - 1, height - 1);
-    let mut total_heat_loss_values = grids.get(&next_path).copied().unwrap_or(0);
-    let mut shortest_path = vec![];
-    shortest_path.push(next_path);
-
-    loop {
-        if let Some(path) = paths.get(&next_path) {
-            shortest_path.push(*path);
-            next_path = *path;
-            total_heat_loss_values += grids.get(&next_path).copied().unwrap_or(0);
-        } else {
-            break;
-        }
-    }
-
-    for row in 0..height {
-        for column in 0..width {
-            let coord = (column, row);
-            if shortest_path.contains(&coord) == true {
-                if let Some(value) = grids.get(&coord) {
-                    print!("[{: >3}]", value);
-                } else {
-                    print!("   # ");
-                }
-            } else if let Some(value) = grids.get(&coord) {
-                print!(" {: >3} ", value);
-            } else {
-                print!("   - ");
-            }
-        }
-        println!("");
-    }
-
-    println!("\n");
-
-    total_heat_loss_values
-}
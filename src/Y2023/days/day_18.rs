@@ -1,115 +1,93 @@
-use crate::file_handler::FileHandler;
+use crate::error::AocError;
+use crate::point::Direction;
+use crate::solution::{Example, Solution};
 use geo::polygon;
 use geo::Area;
 use geo::{Contains, LineString, Point, Polygon};
 use rayon::prelude::*;
 use std::collections::{HashMap, HashSet};
-use std::time::Instant;
 
 pub struct Day18 {}
 
-impl Day18 {
-    pub fn run() -> (String, String, String, u128, u128) {
-        let first_input = FileHandler::read("./src/Y2023/inputs/day_18_1.txt");
-
-        let lines: Vec<&str> = first_input.split('\n').collect();
-
-        let start_1 = Instant::now();
-        let result_1 = Day18::part_01(&lines);
-        let time_calculation_1 = start_1.elapsed();
-
-        let start_2 = Instant::now();
-        let result_2 = Day18::part_02(&lines);
-        let time_calculation_2 = start_2.elapsed();
-
-        (
-            "Day_18".to_owned(),
-            format!("{}", result_1),
-            format!("{}", result_2),
-            time_calculation_1.as_nanos(),
-            time_calculation_2.as_nanos(),
-        )
-    }
+/// The direction and the meters of every step of a dig plan.
+pub type DigPlan = Vec<(Direction, i64)>;
 
-    fn part_01(lines: &Vec<&str>) -> i64 {
-        let data = parse_input(&lines);
-        let (top, left, bottom, right, digs) = dig(&data);
-        let filled = fill(top, left, bottom, right, &digs);
-        filled.len() as i64
+impl Solution for Day18 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 18;
+
+    /// The dig plan read from the directions, and read from the colours for part two.
+    type Input<'a> = (DigPlan, DigPlan);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let lines: Vec<&str> = input.split('\n').collect();
+        Ok((parse_input(&lines)?, parse_input_hex(&lines)?))
     }
 
-    fn part_02(lines: &Vec<&str>) -> i64 {
-        let data = parse_input_hex(&lines);
-        let (top, left, bottom, right, digs) = dig(&data);
-        // let filled = fill(top, left, bottom, right, &digs);
+    fn part_one(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day18::part_01(&input.0).to_string())
+    }
 
-        magic_shovel(&data)
+    fn part_two(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day18::part_02(&input.1).to_string())
     }
-}
 
-#[derive(Debug, Clone, PartialEq)]
-enum DigDirection {
-    Up,
-    Down,
-    Left,
-    Right,
+    fn examples() -> Vec<Example> {
+        vec![
+            Example::new(1, TEST_INPUT, "62"),
+            Example::new(2, TEST_INPUT, "952408144115"),
+        ]
+    }
 }
 
-impl DigDirection {
-    fn from_str(input: &str) -> Self {
-        match input {
-            "R" => DigDirection::Right,
-            "L" => DigDirection::Left,
-            "U" => DigDirection::Up,
-            "D" => DigDirection::Down,
-            _ => unreachable!("It should not be here"),
-        }
+impl Day18 {
+    fn part_01(data: &DigPlan) -> i64 {
+        let (top, left, bottom, right, digs) = dig(data);
+        let filled = fill(top, left, bottom, right, &digs);
+        filled.len() as i64
     }
 
-    fn from_i64(input: i64) -> Self {
-        match input {
-            0 => DigDirection::Right,
-            1 => DigDirection::Down,
-            2 => DigDirection::Left,
-            3 => DigDirection::Up,
+    fn part_02(data: &DigPlan) -> i64 {
+        let (top, left, bottom, right, digs) = dig(data);
+        // let filled = fill(top, left, bottom, right, &digs);
 
-            _ => unreachable!("It should not be here"),
-        }
+        magic_shovel(data)
     }
 }
 
 /// Ideas from Advent of rust discord channel.
 /// Note: Dig to Left/right is define width.Dig up and down is paint or undo the paint.
 
-fn magic_shovel(commands: &Vec<(DigDirection, i64)>) -> i64 {
+fn magic_shovel(commands: &Vec<(Direction, i64)>) -> i64 {
     let mut column = 0;
     let mut total_hole = 1;
     commands
         .iter()
         .for_each(|(direction, total_meter)| match direction {
             // Increase width of the paint
-            DigDirection::Right => {
+            Direction::Right => {
                 column += total_meter;
                 total_hole += total_meter;
             }
             // Paint with the same width
-            DigDirection::Down => {
+            Direction::Down => {
                 total_hole += total_meter * (column + 1);
             }
             // Adjust width
-            DigDirection::Left => {
+            Direction::Left => {
                 column -= total_meter;
             }
             // Undo the paint.
-            DigDirection::Up => {
+            Direction::Up => {
                 total_hole -= column * total_meter;
             }
+            _ => unreachable!("It should not be here"),
         });
 
     total_hole
 }
 
-fn dig(commands: &Vec<(DigDirection, i64)>) -> (i64, i64, i64, i64, Vec<(i64, i64)>) {
+fn dig(commands: &Vec<(Direction, i64)>) -> (i64, i64, i64, i64, Vec<(i64, i64)>) {
     let (mut top, mut left) = (0, 0);
     let (mut bottom, mut right) = (0, 0);
     let mut start_coord = (0, 0);
@@ -118,12 +96,8 @@ fn dig(commands: &Vec<(DigDirection, i64)>) -> (i64, i64, i64, i64, Vec<(i64, i6
     digs.push(start_coord);
     commands.iter().for_each(|(direction, total_meters)| {
         (1..=*total_meters).for_each(|index| {
-            let dif_offset = match direction {
-                DigDirection::Up => (0, -index),
-                DigDirection::Down => (0, index),
-                DigDirection::Left => (-index, 0),
-                DigDirection::Right => (index, 0),
-            };
+            let (dx, dy) = direction.delta();
+            let dif_offset = (dx as i64 * index, dy as i64 * index);
 
             let actual_dig_coord = (start_coord.0 + dif_offset.0, start_coord.1 + dif_offset.1);
             digs.push(actual_dig_coord);
@@ -176,29 +150,44 @@ fn print_table(top: i64, left: i64, bottom: i64, right: i64, digs: &Vec<(i64, i6
     println!("---");
 }
 
-fn parse_input(input: &Vec<&str>) -> Vec<(DigDirection, i64)> {
-    input
-        .iter()
-        .map(|text| {
-            let values: Vec<&str> = text.split(" ").collect();
-            (
-                DigDirection::from_str(values[0]),
-                values[1].parse::<i64>().unwrap(),
-            )
-        })
-        .collect()
+fn parse_input(input: &Vec<&str>) -> Result<DigPlan, AocError> {
+    parse_plan(input, |values| {
+        let direction = values
+            .first()?
+            .chars()
+            .next()
+            .and_then(Direction::from_char)?;
+        Some((direction, values.get(1)?.parse::<i64>().ok()?))
+    })
 }
 
-fn parse_input_hex(input: &Vec<&str>) -> Vec<(DigDirection, i64)> {
+fn parse_input_hex(input: &Vec<&str>) -> Result<DigPlan, AocError> {
+    parse_plan(input, |values| {
+        let raw_hex = values.get(2)?.strip_prefix("(#")?.strip_suffix(")")?;
+        if raw_hex.len() != 6 || !raw_hex.is_ascii() {
+            return None;
+        }
+        let digit = i64::from_str_radix(&raw_hex[0..5], 16).ok()?;
+        let direction = raw_hex[5..]
+            .chars()
+            .next()
+            .and_then(Direction::from_hex_digit)?;
+
+        Some((direction, digit))
+    })
+}
+
+/// Reads every line of the dig plan with `step`, which sees the line split on spaces.
+fn parse_plan(
+    input: &Vec<&str>,
+    step: impl Fn(&[&str]) -> Option<(Direction, i64)>,
+) -> Result<DigPlan, AocError> {
     input
         .iter()
-        .map(|text| {
+        .enumerate()
+        .map(|(index, text)| {
             let values: Vec<&str> = text.split(" ").collect();
-            let raw_hex = values[2].replace("(", "").replace(")", "").replace("#", "");
-            let digit = i64::from_str_radix(&raw_hex[0..5].to_owned(), 16).unwrap();
-            let direction = raw_hex[5..].to_owned().parse::<i64>().unwrap();
-
-            (DigDirection::from_i64(direction), digit)
+            step(&values).ok_or_else(|| AocError::parse(index, text, "invalid dig step"))
         })
         .collect()
 }
@@ -225,17 +214,23 @@ mod tests {
     #[test]
     fn test_parse_input_hex() {
         let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = parse_input_hex(&lines);
-        assert_eq!(result[0].0, DigDirection::Right);
+        let result = parse_input_hex(&lines).unwrap();
+        assert_eq!(result[0].0, Direction::Right);
         assert_eq!(result[0].1, 461937);
 
-        assert_eq!(result[1].0, DigDirection::Down);
+        assert_eq!(result[1].0, Direction::Down);
         assert_eq!(result[1].1, 56407);
+
+        let error = parse_input_hex(&vec!["R 6 (#70c71)"]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "cannot parse line 1 `R 6 (#70c71)`: invalid dig step"
+        );
     }
     #[test]
     fn test_parse_input() {
         let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = parse_input(&lines);
+        let result = parse_input(&lines).unwrap();
         assert_eq!(result.len(), 14);
 
         let (top, left, bottom, right, digs) = dig(&result);
@@ -243,16 +238,7 @@ mod tests {
     }
 
     #[test]
-    fn test_part_1() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day18::part_01(&lines);
-        assert_eq!(result, 62);
-    }
-
-    #[test]
-    fn test_part_2() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day18::part_02(&lines);
-        assert_eq!(result, 952408144115);
+    fn test_examples() {
+        crate::solution::assert_examples::<Day18>();
     }
 }
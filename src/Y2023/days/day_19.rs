@@ -1,36 +1,72 @@
-use crate::file_handler::FileHandler;
-use itertools::Itertools;
-use rayon::prelude::*;
+use crate::error::AocError;
+use crate::interval::Interval;
+use crate::solution::{configure_each, unknown_parameter, Example, Solution};
 use std::collections::{HashMap, HashSet};
-use std::time::Instant;
 
 pub struct Day19 {}
 
-impl Day19 {
-    pub fn run() -> (String, String, String, u128, u128) {
-        let first_input = FileHandler::read("./src/Y2023/inputs/day_19_1.txt");
-
-        let lines: Vec<&str> = first_input.split('\n').collect();
-
-        let start_1 = Instant::now();
-        let result_1 = Day19::part_01(&lines);
-        let time_calculation_1 = start_1.elapsed();
-
-        let start_2 = Instant::now();
-        let result_2 = Day19::part_02(&lines);
-        let time_calculation_2 = start_2.elapsed();
-
-        (
-            "Day_19".to_owned(),
-            format!("{}", result_1),
-            format!("{}", result_2),
-            time_calculation_1.as_nanos(),
-            time_calculation_2.as_nanos(),
-        )
+/// The workflows by name, the ratings of every part, and whether part two writes out the
+/// boxes of ratings it accepts.
+#[derive(Debug)]
+pub struct System {
+    rules: HashMap<String, Rule>,
+    parts: Vec<(i32, i32, i32, i32)>,
+    trace: bool,
+}
+
+impl Solution for Day19 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 19;
+
+    type Input<'a> = System;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let lines: Vec<&str> = input.split('\n').collect();
+        let (rules, parts) = parse_input_ex(&lines)?;
+        Ok(System {
+            rules,
+            parts,
+            trace: false,
+        })
     }
 
-    fn part_01(lines: &Vec<&str>) -> i64 {
-        let (rules, parts) = parse_input_ex(lines);
+    fn part_one(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day19::part_01(&input.rules, &input.parts).to_string())
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<String, AocError> {
+        if input.trace {
+            trace_accepted(&input.rules)
+                .iter()
+                .for_each(|line| eprintln!("{}", line));
+        }
+
+        Ok(Day19::part_02(&input.rules).to_string())
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![
+            Example::new(1, TEST_INPUT, "19114"),
+            Example::new(2, TEST_INPUT, "167409079868000"),
+        ]
+    }
+
+    /// `trace` set to 1 makes part two write every accepted box of ratings to stderr, see
+    /// [`trace_accepted`].
+    fn configure(input: &mut Self::Input<'_>, params: &[(&str, i64)]) -> Result<(), AocError> {
+        configure_each(params, |name, value| {
+            match name {
+                "trace" => input.trace = value != 0,
+                _ => return Err(unknown_parameter(name)),
+            }
+
+            Ok(())
+        })
+    }
+}
+
+impl Day19 {
+    fn part_01(rules: &HashMap<String, Rule>, parts: &[(i32, i32, i32, i32)]) -> i64 {
         parts
             .iter()
             .map(|part| {
@@ -52,119 +88,173 @@ impl Day19 {
             .sum()
     }
 
-    fn part_02(lines: &Vec<&str>) -> i64 {
-        let (rules, parts) = parse_input_ex(lines);
+    fn part_02(rules: &HashMap<String, Rule>) -> u64 {
+        accepted_ranges(rules)
+            .iter()
+            .map(|(ranges, _)| count_combinations(ranges))
+            .sum()
+    }
+}
 
-        // let total_index: i64 = 4000 * 4000 * 4000 * 4000;
-        let total_index = 1;
-        (0..=total_index)
-            .into_par_iter()
-            .map(|index| {
-                let s = index % 4000;
-                let a = (index / 4000) % 4000;
-                let m = ((index / 4000) / 4000) % 4000;
-                let ex = (((index / 4000) / 4000) / 4000);
+const MIN_RATING: i64 = 1;
+const MAX_RATING: i64 = 4000;
+
+/// The ratings of the x, m, a and s categories of a box of parts, in `PartType::to_usize`
+/// order.
+pub type PartRanges = [Interval; 4];
+
+/// Every box of ratings the workflows accept, each with the workflows it went through from
+/// `in` to `A`. Each condition splits the box reaching it into the part that matches and
+/// the part that goes on to the next condition, so the boxes never overlap.
+pub fn accepted_ranges(rules: &HashMap<String, Rule>) -> Vec<(PartRanges, Vec<String>)> {
+    let ratings = Interval::inclusive(MIN_RATING, MAX_RATING);
+    let mut accepted = vec![];
+    let mut pending = vec![([ratings; 4], vec!["in".to_owned()])];
+    while let Some((ranges, path)) = pending.pop() {
+        let label = path.last().unwrap();
+        if label == "A" {
+            accepted.push((ranges, path));
+        } else if let Some(rule) = rules.get(label) {
+            for (next_ranges, go_to) in rule.split_ranges(ranges) {
+                let mut next_path = path.clone();
+                next_path.push(go_to);
+                pending.push((next_ranges, next_path));
+            }
+        }
+    }
 
-                let mut start_rules = "in".to_owned();
-                let mut value = 0;
-                let part = (ex as i32 + 1, m as i32 + 1, a as i32 + 1, s as i32 + 1);
-                // loop
-                // {
-                //     if let Some(rule) = rules.get(&start_rules)
-                //     {
-                //         start_rules = rule.check_ex(part);
-                //     }
-                //     else
-                //     {
-                //         if start_rules == "A"
-                //         {
-                //             value = 1;
-                //         }
-                //         break;
-                //     }
-                // }
+    accepted
+}
 
-                value as i64
-            })
-            .sum()
-    }
+fn count_combinations(ranges: &PartRanges) -> u64 {
+    ranges.iter().map(|range| range.len()).product()
+}
+
+/// Every accepted box of ratings on a line with the workflows it went through and how many
+/// combinations it holds, e.g. `{x=1..4001,...} in -> px -> A: 20576430000000`, sorted so
+/// the lines of two runs can be compared.
+pub fn trace_accepted(rules: &HashMap<String, Rule>) -> Vec<String> {
+    let mut lines: Vec<String> = accepted_ranges(rules)
+        .iter()
+        .map(|(ranges, path)| {
+            format!(
+                "{} {}: {}",
+                format_ranges(ranges),
+                path.join(" -> "),
+                count_combinations(ranges)
+            )
+        })
+        .collect();
+    lines.sort();
+
+    lines
 }
 
-fn parse_input(input: &Vec<&str>) -> (HashMap<String, Rule>, Vec<Vec<(PartType, i32)>>) {
-    let index = input
+/// Writes a box of ratings the way the puzzle writes parts.
+fn format_ranges(ranges: &PartRanges) -> String {
+    let names = ["x", "m", "a", "s"];
+    let ranges = names
         .iter()
-        .find_position(|line| line.len() == 0)
-        .unwrap()
-        .0;
+        .zip(ranges.iter())
+        .map(|(name, range)| format!("{}={}", name, range))
+        .collect::<Vec<_>>()
+        .join(",");
+    format!("{{{}}}", ranges)
+}
+
+fn parse_input(
+    input: &Vec<&str>,
+) -> Result<(HashMap<String, Rule>, Vec<Vec<(PartType, i32)>>), AocError> {
+    let index = find_blank_line(input)?;
     let rules_inputs = input[..index].iter().map(|v| *v).collect();
-    let rules = parse_rules(&rules_inputs);
+    let rules = parse_rules(&rules_inputs)?;
     let parts_input = input[(index + 1)..].iter().map(|v| *v).collect();
-    let parts = parse_parts(&parts_input);
+    let parts = parse_parts(&parts_input, index + 1)?;
 
-    (rules, parts)
+    Ok((rules, parts))
 }
 
-fn parse_input_ex(input: &Vec<&str>) -> (HashMap<String, Rule>, Vec<(i32, i32, i32, i32)>) {
-    let index = input
-        .iter()
-        .find_position(|line| line.len() == 0)
-        .unwrap()
-        .0;
+fn parse_input_ex(
+    input: &Vec<&str>,
+) -> Result<(HashMap<String, Rule>, Vec<(i32, i32, i32, i32)>), AocError> {
+    let index = find_blank_line(input)?;
     let rules_inputs = input[..index].iter().map(|v| *v).collect();
-    let rules = parse_rules(&rules_inputs);
+    let rules = parse_rules(&rules_inputs)?;
     let parts_input = input[(index + 1)..].iter().map(|v| *v).collect();
-    let parts = parse_parts_ex(&parts_input);
+    let parts = parse_parts_ex(&parts_input, index + 1)?;
+
+    Ok((rules, parts))
+}
 
-    (rules, parts)
+/// The index of the blank line between the workflows and the part ratings.
+fn find_blank_line(input: &Vec<&str>) -> Result<usize, AocError> {
+    input
+        .iter()
+        .position(|line| line.len() == 0)
+        .ok_or_else(|| AocError::unsolvable("no blank line between the workflows and the ratings"))
 }
 
-fn parse_rules(input: &Vec<&str>) -> HashMap<String, Rule> {
-    let rules: Vec<Rule> = input.iter().map(|text| Rule::from_str(*&text)).collect();
-    rules
+fn parse_rules(input: &Vec<&str>) -> Result<HashMap<String, Rule>, AocError> {
+    input
         .iter()
-        .map(|r| (r.entrance.to_owned(), r.clone()))
+        .enumerate()
+        .map(|(index, text)| {
+            let rule = Rule::from_str(text)
+                .ok_or_else(|| AocError::parse(index, text, "invalid workflow"))?;
+            Ok((rule.entrance.to_owned(), rule))
+        })
         .collect()
 }
 
-fn parse_parts(input: &Vec<&str>) -> Vec<Vec<(PartType, i32)>> {
+/// The ratings of every part, `first_index` being the index of the first line of `input`.
+fn parse_parts(
+    input: &Vec<&str>,
+    first_index: usize,
+) -> Result<Vec<Vec<(PartType, i32)>>, AocError> {
     //{x=787,m=2655,a=1222,s=2876}
     input
         .iter()
-        .map(|text| {
-            let text = text.replace("{", "").replace("}", "");
-            text.split(",")
-                .map(|v| {
-                    let (part, _, value) = PartType::from_str(v);
-                    (part, value)
+        .enumerate()
+        .map(|(index, text)| {
+            text.strip_prefix("{")
+                .and_then(|text| text.strip_suffix("}"))
+                .and_then(|text| {
+                    text.split(",")
+                        .map(|v| match PartType::from_str(v)? {
+                            (part, 0, value) => Some((part, value)),
+                            _ => None,
+                        })
+                        .collect::<Option<Vec<_>>>()
                 })
-                .collect()
+                .ok_or_else(|| AocError::parse(first_index + index, text, "invalid part ratings"))
         })
         .collect()
 }
 
-fn parse_parts_ex(input: &Vec<&str>) -> Vec<(i32, i32, i32, i32)> {
-    //{x=787,m=2655,a=1222,s=2876}
-    input
+/// The x, m, a and s ratings of every part, `first_index` being the index of the first line of
+/// `input`.
+fn parse_parts_ex(
+    input: &Vec<&str>,
+    first_index: usize,
+) -> Result<Vec<(i32, i32, i32, i32)>, AocError> {
+    let parts = parse_parts(input, first_index)?;
+    Ok(parts
         .iter()
-        .map(|text| {
+        .map(|ratings| {
             let mut ex = 0;
             let mut m = 0;
             let mut a = 0;
             let mut s = 0;
-            let text = text.replace("{", "").replace("}", "");
-            text.split(",").for_each(|v| {
-                let (part, _, value) = PartType::from_str(v);
-                match part {
-                    PartType::Ex => ex = value,
-                    PartType::Musical => m = value,
-                    PartType::Aero => a = value,
-                    PartType::Shiny => s = value,
-                    _ => {}
-                }
+            ratings.iter().for_each(|(part, value)| match part {
+                PartType::Ex => ex = *value,
+                PartType::Musical => m = *value,
+                PartType::Aero => a = *value,
+                PartType::Shiny => s = *value,
+                _ => {}
             });
             (ex, m, a, s)
         })
-        .collect()
+        .collect())
 }
 
 fn calculate_rating(part: &Vec<(PartType, i32)>) -> i32 {
@@ -198,68 +288,65 @@ impl PartType {
         false
     }
 
-    fn from_str(input: &str) -> (PartType, i32, i32) {
-        let operand_index = input
-            .to_string()
-            .find(|c| c == '<' || c == '>' || c == '=')
-            .unwrap();
-
-        let p_type = input[..operand_index].to_owned();
-        let operand = match input[operand_index..(operand_index + 1)]
-            .to_owned()
-            .as_str()
-        {
+    /// The category, operand and value of a condition such as `a<2006` or a rating such as
+    /// `x=787`, `None` if it is malformed.
+    fn from_str(input: &str) -> Option<(PartType, i32, i32)> {
+        let operand_index = input.find(|c| c == '<' || c == '>' || c == '=')?;
+
+        let p_type = &input[..operand_index];
+        let operand = match &input[operand_index..(operand_index + 1)] {
             "<" => -1,
             ">" => 1,
-            "=" => 0,
-            _ => unreachable!("It should not be here"),
+            _ => 0,
         };
-        let value = input[(operand_index + 1)..]
-            .to_string()
-            .parse::<i32>()
-            .unwrap();
-
-        match p_type.as_str() {
-            "x" => (PartType::Ex, operand, value),
-            "s" => (PartType::Shiny, operand, value),
-            "m" => (PartType::Musical, operand, value),
-            "a" => (PartType::Aero, operand, value),
-            _ => unreachable!("It should not be here"),
+        let value = input[(operand_index + 1)..].parse::<i32>().ok()?;
+
+        match p_type {
+            "x" => Some((PartType::Ex, operand, value)),
+            "s" => Some((PartType::Shiny, operand, value)),
+            "m" => Some((PartType::Musical, operand, value)),
+            "a" => Some((PartType::Aero, operand, value)),
+            _ => None,
         }
     }
 }
 
 #[derive(Debug, Clone, PartialEq)]
-struct Rule {
+pub struct Rule {
     // entrance label. It is the rule name
     entrance: String,
 
     conditions: Vec<((PartType, i32, i32), String)>,
 }
 impl Rule {
-    fn from_str(input: &str) -> Self {
-        let split: Vec<&str> = input.split("{").collect();
-        let label = split[0].to_owned();
-        let conditions_text = split[1].to_owned().replace("}", "");
+    /// The workflow on a line, `None` if it is malformed.
+    fn from_str(input: &str) -> Option<Self> {
+        let (label, conditions_text) = input.split_once("{")?;
+        let conditions_text = conditions_text.strip_suffix("}")?;
         let split: Vec<&str> = conditions_text.split(",").collect();
-        let conditions = split
+        let conditions: Vec<((PartType, i32, i32), String)> = split
             .iter()
             .map(|v| {
                 let text: Vec<&str> = v.split(":").collect();
                 if text.len() == 2 {
-                    let part_type = PartType::from_str(text[0]);
+                    let part_type =
+                        PartType::from_str(text[0]).filter(|(_, operand, _)| *operand != 0)?;
                     let go_to = text[1].to_owned();
-                    (part_type, go_to)
+                    Some((part_type, go_to))
                 } else {
                     let go_to = text[0].to_owned();
-                    ((PartType::Exit, 0, 0), go_to)
+                    Some(((PartType::Exit, 0, 0), go_to))
                 }
             })
-            .collect();
-
-        Self {
-            entrance: label,
-            conditions: conditions,
+            .collect::<Option<_>>()?;
+
+        // Every workflow ends with the rule that applies when no condition matches.
+        match conditions.last() {
+            Some(((PartType::Exit, _, _), _)) => Some(Self {
+                entrance: label.to_owned(),
+                conditions: conditions,
+            }),
+            _ => None,
         }
     }
 
@@ -304,6 +391,44 @@ impl Rule {
 
         self.conditions.last().unwrap().1.to_owned()
     }
+
+    /// Splits a box of ratings the way `check_ex` sends the parts in it, giving each piece
+    /// with the workflow it goes to.
+    fn split_ranges(&self, ranges: PartRanges) -> Vec<(PartRanges, String)> {
+        let mut result = vec![];
+        let mut remaining = ranges;
+        for ((condition, operand, cond_value), go_to) in self.conditions.iter() {
+            if matches!(condition, PartType::Exit) {
+                result.push((remaining, go_to.to_owned()));
+                return result;
+            }
+
+            let condition_index = condition.to_usize();
+            let range = remaining[condition_index];
+            let (matched, rest) = if *operand < 0 {
+                range.split_at(*cond_value as i64)
+            } else if *operand > 0 {
+                let (below, above) = range.split_at(*cond_value as i64 + 1);
+                (above, below)
+            } else {
+                continue;
+            };
+
+            if let Some(matched) = matched {
+                let mut matched_ranges = remaining;
+                matched_ranges[condition_index] = matched;
+                result.push((matched_ranges, go_to.to_owned()));
+            }
+
+            match rest {
+                Some(rest) => remaining[condition_index] = rest,
+                None => return result,
+            }
+        }
+
+        result.push((remaining, self.conditions.last().unwrap().1.to_owned()));
+        result
+    }
 }
 
 const TEST_INPUT: &str = "px{a<2006:qkq,m>2090:A,rfg}
@@ -331,7 +456,7 @@ mod tests {
     #[test]
     fn test_parse_input() {
         let input: Vec<&str> = TEST_INPUT.lines().collect();
-        let (rules, parts) = parse_input(&input);
+        let (rules, parts) = parse_input(&input).unwrap();
         assert_eq!(rules.len(), 11);
         assert_eq!(rules["px"].entrance, "px".to_owned());
 
@@ -358,16 +483,92 @@ mod tests {
     }
 
     #[test]
-    fn test_part_1() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day19::part_01(&lines);
-        assert_eq!(result, 19114);
+    fn test_parse_error() {
+        let error = Day19::parse("in{s<1351:px,qqz}\n\n{x=787,m=2655,a=1222,s>2876}").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "cannot parse line 3 `{x=787,m=2655,a=1222,s>2876}`: invalid part ratings"
+        );
+    }
+
+    #[test]
+    fn test_split_ranges() {
+        let rule = Rule::from_str("px{a<2006:qkq,m>2090:A,rfg}").unwrap();
+        let ratings = [Interval::inclusive(1, 4000); 4];
+        let split = rule.split_ranges(ratings);
+        assert_eq!(split.len(), 3);
+        assert_eq!(split[0].0[2], Interval::inclusive(1, 2005));
+        assert_eq!(split[0].1, "qkq");
+        assert_eq!(split[1].0[1], Interval::inclusive(2091, 4000));
+        assert_eq!(split[1].0[2], Interval::inclusive(2006, 4000));
+        assert_eq!(split[2].0[1], Interval::inclusive(1, 2090));
+        assert_eq!(split[2].1, "rfg");
+
+        // Nothing is left for the fallback once a condition takes the whole range.
+        let split = rule.split_ranges([Interval::inclusive(1, 100); 4]);
+        assert_eq!(split.len(), 1);
+    }
+
+    #[test]
+    fn test_accepted_ranges() {
+        let input: Vec<&str> = TEST_INPUT.lines().collect();
+        let (rules, parts) = parse_input_ex(&input).unwrap();
+        let accepted = accepted_ranges(&rules);
+        let total: u64 = accepted
+            .iter()
+            .map(|(ranges, _)| count_combinations(ranges))
+            .sum();
+        assert_eq!(total, 167409079868000);
+        assert!(accepted
+            .iter()
+            .all(|(_, path)| path.first().unwrap() == "in" && path.last().unwrap() == "A"));
+
+        // The same parts are accepted as when they go through the workflows one by one.
+        let accepted_parts = [true, false, true, false, true];
+        for (part, expected) in parts.iter().zip(accepted_parts) {
+            let values = [part.0, part.1, part.2, part.3];
+            let inside = accepted.iter().any(|(ranges, _)| {
+                ranges
+                    .iter()
+                    .zip(values)
+                    .all(|(range, value)| range.contains(value as i64))
+            });
+            assert_eq!(inside, expected, "{:?}", part);
+        }
+    }
+
+    #[test]
+    fn test_trace_accepted() {
+        let system = Day19::parse(TEST_INPUT).unwrap();
+        let lines = trace_accepted(&system.rules);
+        assert_eq!(lines.len(), accepted_ranges(&system.rules).len());
+        assert_eq!(lines.len(), 9);
+        assert_eq!(
+            lines[0],
+            "{x=1..1416,m=1..4001,a=1..2006,s=1..1351} in -> px -> qkq -> A: 15320205000000"
+        );
+        assert!(lines.contains(
+            &"{x=1..4001,m=2091..4001,a=2006..4001,s=1..1351} in -> px -> A: 20576430000000"
+                .to_owned()
+        ));
+    }
+
+    #[test]
+    fn test_format_ranges() {
+        let ranges = [
+            Interval::inclusive(1, 4000),
+            Interval::inclusive(2091, 4000),
+            Interval::inclusive(1, 2005),
+            Interval::inclusive(1, 1350),
+        ];
+        assert_eq!(
+            format_ranges(&ranges),
+            "{x=1..4001,m=2091..4001,a=1..2006,s=1..1351}"
+        );
     }
 
     #[test]
-    fn test_part_2() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day19::part_02(&lines);
-        assert_eq!(result, 167409079868000);
+    fn test_examples() {
+        crate::solution::assert_examples::<Day19>();
     }
 }
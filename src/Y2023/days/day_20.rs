@@ -1,38 +1,65 @@
-use crate::file_handler::FileHandler;
+use crate::error::AocError;
+use crate::parsing::arrow_list;
+use crate::solution::{configure_each, unknown_parameter, Example, Solution};
 use itertools::Itertools;
+use num::Integer;
 use rayon::prelude::*;
 use std::collections::{HashMap, HashSet, VecDeque};
-use std::time::Instant;
 
 pub struct Day20 {}
 
-impl Day20 {
-    pub fn run() -> (String, String, String, u128, u128) {
-        let first_input = FileHandler::read("./src/Y2023/inputs/day_20_1.txt");
-
-        let lines: Vec<&str> = first_input.split('\n').collect();
-
-        let start_1 = Instant::now();
-        let result_1 = Day20::part_01(&lines);
-        let time_calculation_1 = start_1.elapsed();
-
-        let start_2 = Instant::now();
-        let result_2 = Day20::part_02(&lines);
-        let time_calculation_2 = start_2.elapsed();
-
-        (
-            "Day_20".to_owned(),
-            format!("{}", result_1),
-            format!("{}", result_2),
-            time_calculation_1.as_nanos(),
-            time_calculation_2.as_nanos(),
-        )
+/// The modules by name, and whether part two writes them out as a graph.
+pub struct Network {
+    modules: HashMap<String, Module>,
+    graphviz: bool,
+}
+
+impl Solution for Day20 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 20;
+
+    type Input<'a> = Network;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let lines: Vec<&str> = input.split('\n').collect();
+        Ok(Network {
+            modules: prepare_data(&parse_input(&lines)?),
+            graphviz: false,
+        })
     }
 
-    fn part_01(lines: &Vec<&str>) -> i128 {
-        let data = parse_input(&lines);
+    fn part_one(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day20::part_01(&input.modules).to_string())
+    }
 
-        let mut data = prepare_data(&data);
+    fn part_two(input: &Self::Input<'_>) -> Result<String, AocError> {
+        if input.graphviz {
+            eprint!("{}", to_graphviz(&input.modules));
+        }
+
+        Ok(Day20::part_02(&input.modules)?.to_string())
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![Example::new(1, TEST_INPUT, "32000000")]
+    }
+
+    /// `graphviz` set to 1 makes part two write the network to stderr, see [`to_graphviz`].
+    fn configure(input: &mut Self::Input<'_>, params: &[(&str, i64)]) -> Result<(), AocError> {
+        configure_each(params, |name, value| {
+            match name {
+                "graphviz" => input.graphviz = value != 0,
+                _ => return Err(unknown_parameter(name)),
+            }
+
+            Ok(())
+        })
+    }
+}
+
+impl Day20 {
+    fn part_01(data: &HashMap<String, Module>) -> i128 {
+        let mut data = data.clone();
         let mut total_low_pulse = 0;
         let mut total_high_pulse = 0;
         (0..1000).for_each(|_| {
@@ -44,38 +71,155 @@ impl Day20 {
         total_low_pulse * total_high_pulse
     }
 
-    fn part_02(lines: &Vec<&str>) -> i32 {
-        return 0;
+    /// Far too many presses to simulate, but `rx` is fed by a single conjunction whose inputs
+    /// each end an independent counter. It sends `rx` a low pulse once all of them send it a
+    /// high one during the same press, so after the LCM of the counters' periods.
+    fn part_02(data: &HashMap<String, Module>) -> Result<u64, AocError> {
+        let feeder = find_feeder(data, "rx")
+            .ok_or_else(|| AocError::unsolvable("no conjunction feeds `rx`"))?;
+
+        let circuits = sub_circuits(data, &feeder);
+        let independent = circuits
+            .iter()
+            .tuple_combinations()
+            .all(|(a, b)| a.1.is_disjoint(&b.1));
+        if circuits.is_empty() || !independent {
+            return Err(AocError::unsolvable(format!(
+                "the inputs of `{}` are not independent counters",
+                feeder
+            )));
+        }
+
+        let outputs: Vec<String> = circuits.into_iter().map(|(output, _)| output).collect();
+        let periods = find_periods(data, &feeder, &outputs)?;
+
+        Ok(periods
+            .into_iter()
+            .fold(1, |total, period| total.lcm(&period)))
+    }
+}
+
+/// How many presses it takes each of `outputs` to send `target` a high pulse. Gives up once
+/// the counters would be wider than 16 bits.
+fn find_periods(
+    data: &HashMap<String, Module>,
+    target: &str,
+    outputs: &[String],
+) -> Result<Vec<u64>, AocError> {
+    let mut data = data.clone();
+    let mut periods: HashMap<String, u64> = HashMap::new();
+    for press in 1..=(1 << 16) {
+        send_pulse_with(&mut data, |from, to, pulse| {
+            if to == target && pulse == 1 && outputs.iter().any(|output| output == from) {
+                periods.entry(from.to_owned()).or_insert(press);
+            }
+        });
+
+        if periods.len() == outputs.len() {
+            return Ok(outputs.iter().map(|output| periods[output]).collect());
+        }
+    }
+
+    Err(AocError::unsolvable(format!(
+        "the inputs of `{}` never all sent a high pulse",
+        target
+    )))
+}
+
+/// The modules sending pulses to `name`.
+fn inputs_of(modules: &HashMap<String, Module>, name: &str) -> Vec<String> {
+    modules
+        .values()
+        .filter(|module| module.destinations.iter().any(|label| label == name))
+        .map(|module| module.name.to_owned())
+        .sorted()
+        .collect()
+}
+
+/// The only conjunction sending pulses to `name`.
+fn find_feeder(modules: &HashMap<String, Module>, name: &str) -> Option<String> {
+    let inputs = inputs_of(modules, name);
+    match &inputs[..] {
+        [feeder] if modules[feeder].module_type == ModuleType::Conjunction => Some(feeder.clone()),
+        _ => None,
+    }
+}
+
+/// Each input of `feeder` with every module it depends on, up to but without the broadcaster.
+fn sub_circuits(modules: &HashMap<String, Module>, feeder: &str) -> Vec<(String, HashSet<String>)> {
+    inputs_of(modules, feeder)
+        .into_iter()
+        .map(|output| {
+            let mut circuit = HashSet::from([output.clone()]);
+            let mut queue = VecDeque::from([output.clone()]);
+            while let Some(name) = queue.pop_front() {
+                for input in inputs_of(modules, &name) {
+                    if modules[&input].module_type != ModuleType::BroadCast
+                        && circuit.insert(input.clone())
+                    {
+                        queue.push_back(input);
+                    }
+                }
+            }
+
+            (output, circuit)
+        })
+        .collect()
+}
+
+/// The module network in the Graphviz dot format, e.g. for `dot -Tsvg`, to look at the
+/// counters of an input while working on part two.
+pub fn to_graphviz(modules: &HashMap<String, Module>) -> String {
+    let mut result = String::from("digraph modules {\n");
+    for name in modules.keys().sorted() {
+        let module = &modules[name];
+        let shape = match module.module_type {
+            ModuleType::FlipFlop => "box",
+            ModuleType::Conjunction => "diamond",
+            ModuleType::BroadCast => "doublecircle",
+        };
+        result.push_str(&format!("    \"{}\" [shape={}];\n", name, shape));
+        for destination in module.destinations.iter() {
+            result.push_str(&format!("    \"{}\" -> \"{}\";\n", name, destination));
+        }
     }
+    result.push_str("}\n");
+
+    result
 }
 
 fn send_pulse(modules: &mut HashMap<String, Module>) -> (i128, i128) {
     let mut total_low_pulse = 0;
     let mut total_high_pulse = 0;
-    let mut queue = VecDeque::new();
-    queue.push_back(("button".to_owned(), "broadcaster".to_owned(), 0));
-    loop {
-        if let Some((from, to, pulse)) = queue.pop_front() {
-            // println!("{} -{}-> {}", from, pulse, to);
-            if pulse == 0 {
-                total_low_pulse += 1;
-            } else {
-                total_high_pulse += 1;
-            }
-            if let Some(module) = modules.get_mut(&to) {
-                let new_pulses = module.process(&from, pulse);
-                new_pulses.iter().for_each(|data| {
-                    queue.push_back(data.clone());
-                })
-            }
+    send_pulse_with(modules, |_, _, pulse| {
+        if pulse == 0 {
+            total_low_pulse += 1;
         } else {
-            break;
+            total_high_pulse += 1;
         }
-    }
+    });
 
     (total_low_pulse, total_high_pulse)
 }
 
+/// Presses the button once, telling `on_pulse` about every pulse as `(from, to, pulse)`.
+fn send_pulse_with(
+    modules: &mut HashMap<String, Module>,
+    mut on_pulse: impl FnMut(&str, &str, i32),
+) {
+    let mut queue = VecDeque::new();
+    queue.push_back(("button".to_owned(), "broadcaster".to_owned(), 0));
+    while let Some((from, to, pulse)) = queue.pop_front() {
+        on_pulse(&from, &to, pulse);
+        if let Some(module) = modules.get_mut(&to) {
+            let new_pulses = module.process(&from, pulse);
+            new_pulses.iter().for_each(|data| {
+                queue.push_back(data.clone());
+            })
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 enum ModuleType {
     FlipFlop,
@@ -84,7 +228,7 @@ enum ModuleType {
 }
 
 #[derive(Debug, Clone, PartialEq)]
-struct Module {
+pub struct Module {
     module_type: ModuleType,
     name: String,
     destinations: Vec<String>,
@@ -190,30 +334,27 @@ fn prepare_data(input: &Vec<(ModuleType, String, Vec<String>)>) -> HashMap<Strin
         .collect()
 }
 
-fn parse_input(input: &Vec<&str>) -> Vec<(ModuleType, String, Vec<String>)> {
+fn parse_input(input: &Vec<&str>) -> Result<Vec<(ModuleType, String, Vec<String>)>, AocError> {
     input
         .iter()
-        .filter_map(|text| {
-            let split: Vec<&str> = text.split(" -> ").collect_vec();
-            let destinations: Vec<String> = split[1]
-                .replace(" ", "")
-                .split(",")
-                .map(|v| v.to_string())
-                .collect();
-
-            let module = split[0].to_string();
-            if module.contains("%") == true {
-                Some((ModuleType::FlipFlop, module[1..].to_string(), destinations))
-            } else if module.contains("&") == true {
-                Some((
-                    ModuleType::Conjunction,
-                    module[1..].to_string(),
-                    destinations,
-                ))
-            } else if module.contains("broadcaster") == true {
-                Some((ModuleType::BroadCast, module.to_string(), destinations))
+        .enumerate()
+        .map(|(index, text)| {
+            let (module, destinations) = arrow_list(text)
+                .ok_or_else(|| AocError::parse(index, text, "expected `module -> destinations`"))?;
+            let destinations: Vec<String> = destinations.iter().map(|v| v.to_string()).collect();
+
+            if let Some(name) = module.strip_prefix("%") {
+                Ok((ModuleType::FlipFlop, name.to_string(), destinations))
+            } else if let Some(name) = module.strip_prefix("&") {
+                Ok((ModuleType::Conjunction, name.to_string(), destinations))
+            } else if module == "broadcaster" {
+                Ok((ModuleType::BroadCast, module.to_string(), destinations))
             } else {
-                None
+                Err(AocError::parse(
+                    index,
+                    text,
+                    format!("unknown module `{}`", module),
+                ))
             }
         })
         .collect()
@@ -231,6 +372,18 @@ const TEST_INPUT_2: &str = "broadcaster -> a
 %b -> con
 &con -> output";
 
+/// Two counters feeding `rx` through `out`, each inverted on its way there: `cx` sees both
+/// flip-flops on at the third press, `cy` sees `b1` on at the first.
+const TEST_INPUT_3: &str = "broadcaster -> a1, b1
+%a1 -> a2, cx
+%a2 -> cx
+&cx -> ix
+&ix -> out
+%b1 -> cy
+&cy -> iy
+&iy -> out
+&out -> rx";
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -238,7 +391,7 @@ mod tests {
     #[test]
     fn test_send_pulse_1000_1() {
         let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let data = parse_input(&lines);
+        let data = parse_input(&lines).unwrap();
         assert_eq!(data.len(), 5);
 
         let mut data = prepare_data(&data);
@@ -256,7 +409,7 @@ mod tests {
     #[test]
     fn test_send_pulse_1000_2() {
         let lines: Vec<&str> = TEST_INPUT_2.lines().collect();
-        let data = parse_input(&lines);
+        let data = parse_input(&lines).unwrap();
         assert_eq!(data.len(), 5);
 
         let mut data = prepare_data(&data);
@@ -274,7 +427,7 @@ mod tests {
     #[test]
     fn test_parse_input() {
         let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let data = parse_input(&lines);
+        let data = parse_input(&lines).unwrap();
         assert_eq!(data.len(), 5);
 
         let data = prepare_data(&data);
@@ -283,16 +436,42 @@ mod tests {
     }
 
     #[test]
-    fn test_part_1() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day20::part_01(&lines);
-        assert_eq!(result, 32000000);
+    fn test_sub_circuits() {
+        let lines: Vec<&str> = TEST_INPUT_3.lines().collect();
+        let data = prepare_data(&parse_input(&lines).unwrap());
+        assert_eq!(find_feeder(&data, "rx"), Some("out".to_owned()));
+
+        let circuits = sub_circuits(&data, "out");
+        assert_eq!(circuits.len(), 2);
+        assert_eq!(circuits[0].0, "ix");
+        let expected: HashSet<String> = ["ix", "cx", "a1", "a2"]
+            .iter()
+            .map(|name| name.to_string())
+            .collect();
+        assert_eq!(circuits[0].1, expected);
+
+        let periods = find_periods(&data, "out", &["ix".to_owned(), "iy".to_owned()]).unwrap();
+        assert_eq!(periods, vec![3, 1]);
+        assert_eq!(Day20::part_02(&data).unwrap(), 3);
     }
 
     #[test]
-    fn test_part_2() {
+    fn test_to_graphviz() {
         let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day20::part_02(&lines);
-        assert_eq!(result, 2286);
+        let data = prepare_data(&parse_input(&lines).unwrap());
+        let graph = to_graphviz(&data);
+        assert!(graph.starts_with("digraph modules {\n"));
+        assert!(graph.contains("    \"inv\" [shape=diamond];\n"));
+        assert!(graph.contains("    \"broadcaster\" -> \"c\";\n"));
+        assert!(graph.ends_with("}\n"));
+
+        let mut network = Day20::parse(TEST_INPUT).unwrap();
+        Day20::configure(&mut network, &[("graphviz", 1)]).unwrap();
+        assert!(network.graphviz);
+    }
+
+    #[test]
+    fn test_examples() {
+        crate::solution::assert_examples::<Day20>();
     }
 }
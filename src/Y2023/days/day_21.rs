@@ -1,45 +1,197 @@
-use crate::file_handler::FileHandler;
+use crate::error::AocError;
+use crate::solution::{configure_each, unknown_parameter, Example, Solution};
 use rayon::{prelude::*, result};
-use std::collections::{HashMap, HashSet};
-use std::time::Instant;
+use std::collections::{HashMap, HashSet, VecDeque};
 
 pub struct Day21 {}
 
+/// The garden, the steps part two walks and up to which step count it checks its counts
+/// against a walk of every step, 0 for none.
+pub struct Walk {
+    garden: Garden,
+    steps: u64,
+    check: u64,
+}
+
+impl Solution for Day21 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 21;
+
+    type Input<'a> = Walk;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let lines: Vec<&str> = input.split('\n').collect();
+        Ok(Walk {
+            garden: parse_input(&lines)?,
+            steps: PART_TWO_STEPS,
+            check: 0,
+        })
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day21::part_01(&input.garden).to_string())
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<String, AocError> {
+        if input.check > 0 {
+            check_reachable_counts(&input.garden, input.check as usize)?;
+        }
+
+        Ok(Day21::part_02(&input.garden, input.steps)?.to_string())
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![
+            Example::new(1, TEST_INPUT, "42"),
+            Example::new(2, TEST_INPUT, "16").with("steps", 6),
+            Example::new(2, TEST_INPUT, "6536").with("steps", 100),
+            Example::new(2, TEST_INPUT, "668697").with("steps", 1000),
+            Example::new(2, TEST_INPUT, "16733044").with("steps", 5000),
+            Example::new(2, TEST_INPUT, "1594")
+                .with("steps", 50)
+                .with("check", 50),
+        ]
+    }
+
+    /// `steps` is how far part two walks, `check` makes part two first compare the counts of
+    /// every step count up to it with a walk of every step.
+    fn configure(input: &mut Self::Input<'_>, params: &[(&str, i64)]) -> Result<(), AocError> {
+        configure_each(params, |name, value| {
+            match name {
+                "steps" => input.steps = value as u64,
+                "check" => input.check = value as u64,
+                _ => return Err(unknown_parameter(name)),
+            }
+
+            Ok(())
+        })
+    }
+}
+
 impl Day21 {
-    pub fn run() -> (String, String, String, u128, u128) {
-        let first_input = FileHandler::read("./src/Y2023/inputs/day_21_1.txt");
-
-        let lines: Vec<&str> = first_input.split('\n').collect();
-
-        let start_1 = Instant::now();
-        let result_1 = Day21::part_01(&lines);
-        let time_calculation_1 = start_1.elapsed();
-
-        let start_2 = Instant::now();
-        let result_2 = Day21::part_02(&lines);
-        let time_calculation_2 = start_2.elapsed();
-
-        (
-            "Day_21".to_owned(),
-            format!("{}", result_1),
-            format!("{}", result_2),
-            time_calculation_1.as_nanos(),
-            time_calculation_2.as_nanos(),
-        )
+    fn part_01(garden: &Garden) -> usize {
+        let &(ref grids, start_coord, width, height) = garden;
+        step(grids, start_coord, width, height, 64)
+    }
+
+    fn part_02(garden: &Garden, steps: u64) -> Result<u64, AocError> {
+        reachable_plots(garden, steps)
     }
+}
+
+/// The rocks, the start, the width and the height of the garden.
+type Garden = (HashMap<(i32, i32), SpaceType>, (i32, i32), i32, i32);
+
+const PART_TWO_STEPS: u64 = 26_501_365;
+
+/// How many strides of the infinite garden are walked for real before the counts are
+/// extrapolated, enough for the growth to settle on the examples and the puzzle input.
+const SAMPLE_STRIDES: usize = 6;
+
+/// The plots reachable in exactly `steps` steps on the garden repeated forever.
+///
+/// Every tile is the same, so past a few tiles the frontier crosses a new ring of copies
+/// every `2 * width` steps, the doubled stride keeping the parity of odd sized tiles, and
+/// the count grows as a quadratic of the rings crossed. A single search gives the exact
+/// count of every step count up to a few strides, and the quadratic is fitted on three of
+/// them once their second difference settles.
+fn reachable_plots(garden: &Garden, steps: u64) -> Result<u64, AocError> {
+    let &(_, _, width, height) = garden;
+    if width != height || width == 0 {
+        return Err(AocError::unsolvable(format!(
+            "the garden is {}x{} and not square",
+            width, height
+        )));
+    }
+
+    let stride = 2 * width as u64;
+    let remainder = steps % stride;
+    let limit = remainder + stride * SAMPLE_STRIDES as u64;
+    let counts = reachable_counts(garden, limit.min(steps) as usize);
+    if steps <= limit {
+        return Ok(counts[steps as usize]);
+    }
+
+    let samples: Vec<i128> = (0..=SAMPLE_STRIDES)
+        .map(|stride_index| counts[(remainder + stride * stride_index as u64) as usize] as i128)
+        .collect();
+
+    // The first three samples whose second difference stays the same for the next one.
+    let first = (0..samples.len() - 3)
+        .find(|&index| {
+            let window = &samples[index..index + 4];
+            window[3] - 3 * window[2] + 3 * window[1] - window[0] == 0
+        })
+        .ok_or_else(|| AocError::unsolvable("the reachable plots never grow quadratically"))?;
+
+    let base = samples[first];
+    let first_difference = samples[first + 1] - base;
+    let second_difference = samples[first + 2] - 2 * samples[first + 1] + base;
+    let strides = ((steps - remainder) / stride) as i128 - first as i128;
+    let count = base + strides * first_difference + strides * (strides - 1) / 2 * second_difference;
+
+    u64::try_from(count).map_err(|_| AocError::unsolvable("the extrapolated count overflows"))
+}
 
-    fn part_01(lines: &Vec<&str>) -> usize {
-        let (grids, start_coord, width, height) = parse_input(lines);
-        step(&grids, start_coord, width, height, 64)
+/// The plots reachable in exactly `steps` steps on the garden repeated forever, for every
+/// `steps` up to `max_steps`, from a single breadth first search.
+///
+/// A plot at distance `d` is reachable in `steps` exactly when `d <= steps` and both have
+/// the same parity, as the walk can step back and forth to waste two steps.
+fn reachable_counts(garden: &Garden, max_steps: usize) -> Vec<u64> {
+    let &(ref grids, start_coord, width, height) = garden;
+    let side = 2 * max_steps + 1;
+    let origin = (
+        start_coord.0 - max_steps as i32,
+        start_coord.1 - max_steps as i32,
+    );
+    let is_rock = |column: usize, row: usize| {
+        let coord = (origin.0 + column as i32, origin.1 + row as i32);
+        grids.contains_key(&convert_to_infinity(coord, width, height))
+    };
+
+    let mut seen = vec![false; side * side];
+    let mut histogram = vec![0u64; max_steps + 1];
+    let mut queue = VecDeque::new();
+    seen[max_steps * side + max_steps] = true;
+    queue.push_back((max_steps, max_steps, 0));
+
+    while let Some((column, row, distance)) = queue.pop_front() {
+        histogram[distance] += 1;
+        if distance == max_steps {
+            continue;
+        }
+
+        let neighbours = [
+            (column.wrapping_sub(1), row),
+            (column + 1, row),
+            (column, row.wrapping_sub(1)),
+            (column, row + 1),
+        ];
+        for (next_column, next_row) in neighbours {
+            if next_column >= side || next_row >= side {
+                continue;
+            }
+
+            let index = next_row * side + next_column;
+            if !seen[index] && !is_rock(next_column, next_row) {
+                seen[index] = true;
+                queue.push_back((next_column, next_row, distance + 1));
+            }
+        }
     }
 
-    fn part_02(lines: &Vec<&str>) -> i32 {
-        return 0;
+    let mut counts = vec![0u64; max_steps + 1];
+    for steps in 0..=max_steps {
+        let previous = if steps >= 2 { counts[steps - 2] } else { 0 };
+        counts[steps] = previous + histogram[steps];
     }
+
+    counts
 }
 
 #[derive(Debug, Clone, PartialEq)]
-enum SpaceType {
+pub enum SpaceType {
     PlotGarden,
     Rock,
 }
@@ -89,6 +241,25 @@ fn step(
     }
 }
 
+/// Compares `reachable_counts` with `step_infinity` for every step count up to `max_steps`.
+fn check_reachable_counts(garden: &Garden, max_steps: usize) -> Result<(), AocError> {
+    let &(ref grids, start_coord, width, height) = garden;
+    let counts = reachable_counts(garden, max_steps);
+    for (steps, count) in counts.iter().enumerate().skip(1) {
+        let walked = step_infinity(grids, start_coord, width, height, steps as i32) as u64;
+        if *count != walked {
+            return Err(AocError::unsolvable(format!(
+                "{} plots are counted in {} steps but {} are walked to",
+                count, steps, walked
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// The plots reachable in exactly `steps` steps, walking every step on the repeated garden.
+/// Only fast enough for small step counts, `check_reachable_counts` uses it.
 fn step_infinity(
     grids: &HashMap<(i32, i32), SpaceType>,
     start_coord: (i32, i32),
@@ -177,21 +348,7 @@ fn get_neighbours(
 }
 
 fn convert_to_infinity(coord: (i32, i32), width: i32, height: i32) -> (i32, i32) {
-    let mut column = coord.0;
-    let mut row = coord.1;
-    if column < 0 {
-        column = width - (column.abs() % width);
-    } else {
-        column = column % width;
-    }
-
-    if row < 0 {
-        row = height - (row.abs() % height);
-    } else {
-        row = row % height;
-    }
-
-    (column, row)
+    (coord.0.rem_euclid(width), coord.1.rem_euclid(height))
 }
 
 fn get_neighbours_infinity(
@@ -239,34 +396,32 @@ fn get_neighbours_infinity(
 
     result
 }
-fn parse_input(input: &Vec<&str>) -> (HashMap<(i32, i32), SpaceType>, (i32, i32), i32, i32) {
+fn parse_input(input: &Vec<&str>) -> Result<Garden, AocError> {
     let height = input.len();
     let mut width = 0;
     let mut start_coord = (0, 0);
-    let data: Vec<Vec<((i32, i32), SpaceType)>> = input
-        .iter()
-        .enumerate()
-        .map(|(row, column_data)| {
-            width = column_data.len();
-            column_data
-                .chars()
-                .enumerate()
-                .filter_map(|(column, value)| {
-                    let coord = (column as i32, row as i32);
-
-                    match value {
-                        '.' => None,
-                        '#' => Some((coord, SpaceType::Rock)),
-                        'S' => {
-                            start_coord = coord;
-                            None
-                        }
-                        _ => unreachable!("It should not be here"),
-                    }
-                })
-                .collect()
-        })
-        .collect();
+    let mut data: Vec<Vec<((i32, i32), SpaceType)>> = vec![];
+    for (row, column_data) in input.iter().enumerate() {
+        width = column_data.len();
+        let mut rocks = vec![];
+        for (column, value) in column_data.chars().enumerate() {
+            let coord = (column as i32, row as i32);
+
+            match value {
+                '.' => {}
+                '#' => rocks.push((coord, SpaceType::Rock)),
+                'S' => start_coord = coord,
+                _ => {
+                    return Err(AocError::parse(
+                        row,
+                        column_data,
+                        format!("unknown tile `{}`", value),
+                    ))
+                }
+            }
+        }
+        data.push(rocks);
+    }
 
     let mut hash: HashMap<(i32, i32), SpaceType> = HashMap::new();
     data.iter().for_each(|list| {
@@ -275,7 +430,7 @@ fn parse_input(input: &Vec<&str>) -> (HashMap<(i32, i32), SpaceType>, (i32, i32)
         });
     });
 
-    (hash, start_coord, width as i32, height as i32)
+    Ok((hash, start_coord, width as i32, height as i32))
 }
 
 const TEST_INPUT: &str = "...........
@@ -297,7 +452,7 @@ mod tests {
     #[test]
     fn test_step_infinity() {
         let input: Vec<&str> = TEST_INPUT.lines().collect();
-        let (grids, start_coord, width, height) = parse_input(&input);
+        let (grids, start_coord, width, height) = parse_input(&input).unwrap();
         let total_steps = step_infinity(&grids, start_coord, width, height, 1);
         assert_eq!(total_steps, 2);
 
@@ -326,10 +481,23 @@ mod tests {
         assert_eq!(total_steps, 16733044);
     }
 
+    #[test]
+    fn test_reachable_counts() {
+        let input: Vec<&str> = TEST_INPUT.lines().collect();
+        let garden = parse_input(&input).unwrap();
+        assert!(check_reachable_counts(&garden, 60).is_ok());
+    }
+
+    #[test]
+    fn test_reachable_plots_not_square() {
+        let input = vec!["...", ".S.", "...", "..."];
+        assert!(reachable_plots(&parse_input(&input).unwrap(), 1000).is_err());
+    }
+
     #[test]
     fn test_step() {
         let input: Vec<&str> = TEST_INPUT.lines().collect();
-        let (grids, start_coord, width, height) = parse_input(&input);
+        let (grids, start_coord, width, height) = parse_input(&input).unwrap();
         let total_steps = step(&grids, start_coord, width, height, 1);
         assert_eq!(total_steps, 2);
 
@@ -343,7 +511,7 @@ mod tests {
     #[test]
     fn test_parse_input() {
         let input: Vec<&str> = TEST_INPUT.lines().collect();
-        let (hash, start_coord, width, height) = parse_input(&input);
+        let (hash, start_coord, width, height) = parse_input(&input).unwrap();
         assert_eq!(start_coord, (5, 5));
         assert_eq!(hash.len(), 40);
         assert_eq!(width, 11);
@@ -351,16 +519,7 @@ mod tests {
     }
 
     #[test]
-    fn test_part_1() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day21::part_01(&lines);
-        assert_eq!(result, 42);
-    }
-
-    #[test]
-    fn test_part_2() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day21::part_02(&lines);
-        assert_eq!(result, 2286);
+    fn test_examples() {
+        crate::solution::assert_examples::<Day21>();
     }
 }
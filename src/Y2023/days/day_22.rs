@@ -1,42 +1,172 @@
-use crate::file_handler::FileHandler;
-use rayon::prelude::*;
-use std::collections::{HashMap, HashSet};
-use std::time::Instant;
+use crate::error::AocError;
+use crate::parsing::signed_integers;
+use crate::solution::{Example, Solution};
+use std::collections::{HashMap, HashSet, VecDeque};
 
 pub struct Day22 {}
 
+impl Solution for Day22 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 22;
+
+    type Input<'a> = Vec<Brick>;
+
+    fn parse(input: &str) -> Result<Vec<Brick>, AocError> {
+        let lines: Vec<&str> = input.split('\n').collect();
+        parse_input(&lines)
+    }
+
+    fn part_one(bricks: &Vec<Brick>) -> Result<String, AocError> {
+        Ok(Day22::part_01(bricks).to_string())
+    }
+
+    fn part_two(bricks: &Vec<Brick>) -> Result<String, AocError> {
+        Ok(Day22::part_02(bricks).to_string())
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![
+            Example::new(1, TEST_INPUT, "5"),
+            Example::new(2, TEST_INPUT, "7"),
+        ]
+    }
+}
+
 impl Day22 {
-    pub fn run() -> (String, String, String, u128, u128) {
-        let first_input = FileHandler::read("./src/Y2023/inputs/day_22_1.txt");
-
-        let lines: Vec<&str> = first_input.split('\n').collect();
-
-        let start_1 = Instant::now();
-        let result_1 = Day22::part_01(&lines);
-        let time_calculation_1 = start_1.elapsed();
-
-        let start_2 = Instant::now();
-        let result_2 = Day22::part_02(&lines);
-        let time_calculation_2 = start_2.elapsed();
-
-        (
-            "Day_22".to_owned(),
-            format!("{}", result_1),
-            format!("{}", result_2),
-            time_calculation_1.as_nanos(),
-            time_calculation_2.as_nanos(),
-        )
+    /// A brick can go when every brick it holds up also rests on another one.
+    fn part_01(bricks: &[Brick]) -> usize {
+        let (_, supports) = settle(bricks);
+        (0..bricks.len())
+            .filter(|index| {
+                supports.supporting[*index]
+                    .iter()
+                    .all(|above| supports.supported_by[*above].len() > 1)
+            })
+            .count()
+    }
+
+    fn part_02(bricks: &[Brick]) -> usize {
+        let (_, supports) = settle(bricks);
+        (0..bricks.len())
+            .map(|index| count_falling(&supports, index))
+            .sum()
+    }
+}
+
+/// A straight line of cubes from `start` to `end`, both included, `start` being the lowest
+/// corner on every axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Brick {
+    start: (usize, usize, usize),
+    end: (usize, usize, usize),
+}
+
+impl Brick {
+    fn new(a: (usize, usize, usize), b: (usize, usize, usize)) -> Brick {
+        Brick {
+            start: (a.0.min(b.0), a.1.min(b.1), a.2.min(b.2)),
+            end: (a.0.max(b.0), a.1.max(b.1), a.2.max(b.2)),
+        }
     }
 
-    fn part_01(lines: &Vec<&str>) -> i32 {
-        return 0;
+    /// The columns the brick covers seen from above.
+    fn footprint(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (self.start.0..=self.end.0)
+            .flat_map(move |x| (self.start.1..=self.end.1).map(move |y| (x, y)))
     }
 
-    fn part_02(lines: &Vec<&str>) -> i32 {
-        return 0;
+    fn drop_to(&self, bottom: usize) -> Brick {
+        let height = self.end.2 - self.start.2;
+        Brick {
+            start: (self.start.0, self.start.1, bottom),
+            end: (self.end.0, self.end.1, bottom + height),
+        }
     }
 }
 
+/// Who rests on whom once every brick settled, both indexed like the input bricks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Supports {
+    supported_by: Vec<HashSet<usize>>,
+    supporting: Vec<HashSet<usize>>,
+}
+
+fn parse_input(lines: &[&str]) -> Result<Vec<Brick>, AocError> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(index, line)| match signed_integers::<usize>(line)[..] {
+            [x1, y1, z1, x2, y2, z2] if line.contains('~') => {
+                Ok(Brick::new((x1, y1, z1), (x2, y2, z2)))
+            }
+            _ => Err(AocError::parse(index, line, "expected `x,y,z~x,y,z`")),
+        })
+        .collect()
+}
+
+/// Lets every brick fall, lowest first, onto the highest cube below its footprint, keeping
+/// the top of every column and the brick it belongs to in a height map.
+fn settle(bricks: &[Brick]) -> (Vec<Brick>, Supports) {
+    let mut order: Vec<usize> = (0..bricks.len()).collect();
+    order.sort_by_key(|index| bricks[*index].start.2);
+
+    let mut settled = bricks.to_vec();
+    let mut supports = Supports {
+        supported_by: vec![HashSet::new(); bricks.len()],
+        supporting: vec![HashSet::new(); bricks.len()],
+    };
+    // (x, y) -> (top z, brick)
+    let mut heights: HashMap<(usize, usize), (usize, usize)> = HashMap::new();
+
+    for index in order {
+        let brick = bricks[index];
+        let rest = brick
+            .footprint()
+            .filter_map(|column| heights.get(&column).map(|(top, _)| *top))
+            .max()
+            .unwrap_or(0);
+
+        for column in brick.footprint() {
+            if let Some((top, below)) = heights.get(&column) {
+                if *top == rest {
+                    supports.supported_by[index].insert(*below);
+                    supports.supporting[*below].insert(index);
+                }
+            }
+        }
+
+        let fallen = brick.drop_to(rest + 1);
+        for column in fallen.footprint() {
+            heights.insert(column, (fallen.end.2, index));
+        }
+        settled[index] = fallen;
+    }
+
+    (settled, supports)
+}
+
+/// How many other bricks fall when `removed` is taken out, a brick falling once everything
+/// it rested on has fallen.
+fn count_falling(supports: &Supports, removed: usize) -> usize {
+    let mut fallen: HashSet<usize> = HashSet::from([removed]);
+    let mut queue = VecDeque::from([removed]);
+
+    while let Some(index) = queue.pop_front() {
+        for above in supports.supporting[index].iter() {
+            if !fallen.contains(above)
+                && supports.supported_by[*above]
+                    .iter()
+                    .all(|below| fallen.contains(below))
+            {
+                fallen.insert(*above);
+                queue.push_back(*above);
+            }
+        }
+    }
+
+    fallen.len() - 1
+}
+
 const TEST_INPUT: &str = "1,0,1~1,2,1
 0,0,2~2,0,2
 0,2,3~2,2,3
@@ -50,16 +180,31 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_part_1() {
+    fn test_parse_input() {
         let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day22::part_01(&lines);
-        assert_eq!(result, 8);
+        let bricks = parse_input(&lines).unwrap();
+        assert_eq!(bricks.len(), 7);
+        assert_eq!(bricks[6], Brick::new((1, 1, 8), (1, 1, 9)));
+        assert!(parse_input(&["1,0,1~1,2"]).is_err());
     }
 
     #[test]
-    fn test_part_2() {
+    fn test_settle() {
         let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day22::part_02(&lines);
-        assert_eq!(result, 2286);
+        let bricks = parse_input(&lines).unwrap();
+        let (settled, supports) = settle(&bricks);
+
+        assert_eq!(settled[1].start.2, 2);
+        assert_eq!(settled[2].start.2, 2);
+        assert_eq!(settled[6], Brick::new((1, 1, 5), (1, 1, 6)));
+        assert_eq!(supports.supporting[0], HashSet::from([1, 2]));
+        assert_eq!(supports.supported_by[5], HashSet::from([3, 4]));
+        assert_eq!(count_falling(&supports, 0), 6);
+        assert_eq!(count_falling(&supports, 5), 1);
+    }
+
+    #[test]
+    fn test_examples() {
+        crate::solution::assert_examples::<Day22>();
     }
 }
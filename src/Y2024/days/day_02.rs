@@ -1,44 +1,47 @@
-use crate::file_handler::FileHandler;
+use crate::error::AocError;
+use crate::solution::{Example, Solution};
 use rayon::prelude::*;
 use std::collections::{HashMap, HashSet};
-use std::time::Instant;
 
 pub struct Day02 {}
 
-impl Day02 {
-    pub fn run() -> (String, String, String, u128, u128) {
-        let first_input = FileHandler::read("./src/Y2024/inputs/day_02_1.txt");
-
-        let lines: Vec<&str> = first_input.split('\n').collect();
-
-        let start_1 = Instant::now();
-        let result_1 = Day02::part_01(&lines);
-        let time_calculation_1 = start_1.elapsed();
-
-        let start_2 = Instant::now();
-        let result_2 = Day02::part_02(&lines);
-        let time_calculation_2 = start_2.elapsed();
-
-        (
-            "Day_02".to_owned(),
-            format!("{}", result_1),
-            format!("{}", result_2),
-            time_calculation_1.as_nanos(),
-            time_calculation_2.as_nanos(),
-        )
+impl Solution for Day02 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 2;
+
+    type Input<'a> = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let lines: Vec<&str> = input.split('\n').collect();
+        split_reports(&lines)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day02::part_01(input).to_string())
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day02::part_02(input).to_string())
     }
 
-    fn part_01(lines: &Vec<&str>) -> i32 {
-        let reports = split_reports(lines);
+    fn examples() -> Vec<Example> {
+        vec![
+            Example::new(1, TEST_INPUT, "2"),
+            Example::new(2, TEST_INPUT, "4"),
+        ]
+    }
+}
 
+impl Day02 {
+    fn part_01(reports: &Vec<Vec<i32>>) -> i32 {
         reports
             .iter()
             .map(|report| if safety_check(report) == true { 1 } else { 0 })
             .sum()
     }
 
-    fn part_02(lines: &Vec<&str>) -> i32 {
-        let mut reports = split_reports(lines);
+    fn part_02(reports: &Vec<Vec<i32>>) -> i32 {
+        let mut reports = reports.clone();
 
         reports
             .iter_mut()
@@ -99,13 +102,19 @@ fn safety_check(report: &Vec<i32>) -> bool {
     })
 }
 
-fn split_reports(lines: &Vec<&str>) -> Vec<Vec<i32>> {
-    lines.iter().map(|text| make_report(*text)).collect()
+fn split_reports(lines: &Vec<&str>) -> Result<Vec<Vec<i32>>, AocError> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(index, text)| {
+            make_report(text).ok_or_else(|| AocError::parse(index, text, "invalid level"))
+        })
+        .collect()
 }
 
-fn make_report(text: &str) -> Vec<i32> {
+fn make_report(text: &str) -> Option<Vec<i32>> {
     text.split_whitespace()
-        .map(|value| (value.to_owned()).parse::<i32>().unwrap())
+        .map(|value| value.parse::<i32>().ok())
         .collect()
 }
 
@@ -121,16 +130,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_part_1() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day02::part_01(&lines);
-        assert_eq!(result, 2);
-    }
-
-    #[test]
-    fn test_part_2() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day02::part_02(&lines);
-        assert_eq!(result, 4);
+    fn test_examples() {
+        crate::solution::assert_examples::<Day02>();
     }
 }
@@ -1,35 +1,39 @@
-use crate::file_handler::FileHandler;
+use crate::error::AocError;
+use crate::solution::{Example, Solution};
 use rayon::prelude::*;
 
 use std::collections::{HashMap, HashSet};
-use std::time::Instant;
 use std::usize;
 
 pub struct Day03 {}
 
-impl Day03 {
-    pub fn run() -> (String, String, String, u128, u128) {
-        let first_input = FileHandler::read("./src/Y2024/inputs/day_03_1.txt");
-
-        let lines: Vec<&str> = first_input.split('\n').collect();
-
-        let start_1 = Instant::now();
-        let result_1 = Day03::part_01(&lines);
-        let time_calculation_1 = start_1.elapsed();
-
-        let start_2 = Instant::now();
-        let result_2 = Day03::part_02(&lines);
-        let time_calculation_2 = start_2.elapsed();
-
-        (
-            "Day_03".to_owned(),
-            format!("{}", result_1),
-            format!("{}", result_2),
-            time_calculation_1.as_nanos(),
-            time_calculation_2.as_nanos(),
-        )
+impl Solution for Day03 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 3;
+
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Vec<&str>, AocError> {
+        Ok(input.split('\n').collect())
+    }
+
+    fn part_one(lines: &Vec<&str>) -> Result<String, AocError> {
+        Ok(Day03::part_01(lines).to_string())
+    }
+
+    fn part_two(lines: &Vec<&str>) -> Result<String, AocError> {
+        Ok(Day03::part_02(lines).to_string())
     }
 
+    fn examples() -> Vec<Example> {
+        vec![
+            Example::new(1, TEST_INPUT, "161"),
+            Example::new(2, TEST_INPUT_2, "48"),
+        ]
+    }
+}
+
+impl Day03 {
     fn part_01(lines: &Vec<&str>) -> i32 {
         lines
             .iter()
@@ -89,13 +93,14 @@ fn is_complete_operator(text: &String, start_index: usize) -> Option<(usize, (i3
     let comma_index = raw.find(",").unwrap_or(raw.len());
     let close_index = raw.find(")").unwrap_or(raw.len());
 
-    if comma_index < raw.len() && close_index < raw.len() {
+    // Corrupted instructions such as `mul(4*` or `mul(6,9!` are skipped.
+    if comma_index < close_index && close_index < raw.len() {
         let left = raw[4..comma_index].to_owned();
         let right = raw[comma_index + 1..close_index].to_owned();
 
         Some((
             start_index,
-            (left.parse::<i32>().unwrap(), right.parse::<i32>().unwrap()),
+            (left.parse::<i32>().ok()?, right.parse::<i32>().ok()?),
         ))
     } else {
         None
@@ -152,17 +157,8 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_part_1() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day03::part_01(&lines);
-        assert_eq!(result, 161);
-    }
-
-    #[test]
-    fn test_part_2() {
-        let lines: Vec<&str> = TEST_INPUT_2.lines().collect();
-        let result = Day03::part_02(&lines);
-        assert_eq!(result, 48);
+    fn test_examples() {
+        crate::solution::assert_examples::<Day03>();
     }
 
     #[test]
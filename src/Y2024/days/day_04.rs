@@ -1,72 +1,81 @@
-use crate::file_handler::FileHandler;
-use rayon::prelude::*;
-use std::collections::{HashMap, HashSet};
-use std::time::Instant;
+use crate::error::AocError;
+use crate::grid::Grid;
+use crate::solution::{Example, Solution};
 
 pub struct Day04 {}
 
-impl Day04 {
-    pub fn run() -> (String, String, String, u128, u128) {
-        let first_input = FileHandler::read("./src/Y2024/inputs/day_04_1.txt");
-
-        let lines: Vec<&str> = first_input.split('\n').collect();
-
-        let start_1 = Instant::now();
-        let result_1 = Day04::part_01(&lines);
-        let time_calculation_1 = start_1.elapsed();
-
-        let start_2 = Instant::now();
-        let result_2 = Day04::part_02(&lines);
-        let time_calculation_2 = start_2.elapsed();
-
-        (
-            "Day_04".to_owned(),
-            format!("{}", result_1),
-            format!("{}", result_2),
-            time_calculation_1.as_nanos(),
-            time_calculation_2.as_nanos(),
-        )
+impl Solution for Day04 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 4;
+
+    type Input<'a> = Grid<char>;
+
+    fn parse(input: &str) -> Result<Grid<char>, AocError> {
+        let lines: Vec<&str> = input.split('\n').collect();
+        Grid::parse(&lines, Some)
     }
 
-    fn part_01(lines: &Vec<&str>) -> i32 {
-        find_matching_keyword(lines, "XMAS") as i32
+    fn part_one(grid: &Grid<char>) -> Result<String, AocError> {
+        Ok(Day04::part_01(grid).to_string())
     }
 
-    fn part_02(lines: &Vec<&str>) -> i32 {
-        find_matching_keyword_cross(lines) as i32
+    fn part_two(grid: &Grid<char>) -> Result<String, AocError> {
+        Ok(Day04::part_02(grid).to_string())
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![
+            Example::new(1, TEST_INPUT, "4"),
+            Example::new(1, TEST_INPUT_2, "18"),
+            Example::new(2, TEST_INPUT_3, "9"),
+        ]
     }
 }
 
-fn find_starting_characters(lines: &Vec<&str>, start_character: &str) -> Vec<(usize, usize)> {
-    let mut result = vec![];
+impl Day04 {
+    fn part_01(grid: &Grid<char>) -> i32 {
+        find_matching_keyword(grid, "XMAS") as i32
+    }
 
-    lines.iter().enumerate().for_each(|(row, text)| {
-        let indexes: Vec<_> = (*text).match_indices(start_character).collect();
-        indexes.iter().for_each(|(column, _)| {
-            result.push((row, *column));
-        });
-    });
+    fn part_02(grid: &Grid<char>) -> i32 {
+        find_matching_keyword_cross(grid) as i32
+    }
+}
 
-    result
+fn find_starting_characters(grid: &Grid<char>, start_character: char) -> Vec<(usize, usize)> {
+    grid.iter()
+        .filter(|(_, c)| **c == start_character)
+        .map(|((column, row), _)| (row, column))
+        .collect()
 }
 
-fn get_character_at_coordinate(lines: &Vec<&str>, row: usize, column: usize) -> Option<String> {
-    if row < lines.len() {
-        let text = lines[row].to_owned();
-        if column < text.len() {
-            return Some(text.chars().nth(column).unwrap().to_string());
-        }
+/// Reads `total_characters` cells from `start` stepping by `delta`, `None` if the word would
+/// leave the grid.
+fn read_word(
+    grid: &Grid<char>,
+    start: (usize, usize),
+    delta: (isize, isize),
+    total_characters: usize,
+) -> Option<String> {
+    let word: String = grid
+        .ray(start, delta)
+        .take(total_characters)
+        .map(|coord| grid[coord])
+        .collect();
+
+    if word.len() == total_characters {
+        Some(word)
+    } else {
+        None
     }
-
-    None
 }
 
-fn find_matching_keyword_cross(lines: &Vec<&str>) -> usize {
-    let coordinate = find_starting_characters(lines, "A");
+fn find_matching_keyword_cross(grid: &Grid<char>) -> usize {
+    let coordinate = find_starting_characters(grid, 'A');
     let matching_words = ["MAS", "SAM"];
     let mut counter = 0;
     coordinate.iter().for_each(|(row, column)| {
-        if let Some(words) = get_words_cross_reading(lines, *row, *column) {
+        if let Some(words) = get_words_cross_reading(grid, *row, *column) {
             if words
                 .iter()
                 .all(|word| matching_words.iter().any(|matching| word == *matching))
@@ -80,52 +89,20 @@ fn find_matching_keyword_cross(lines: &Vec<&str>) -> usize {
     counter
 }
 
-fn get_words_cross_reading(lines: &Vec<&str>, row: usize, column: usize) -> Option<Vec<String>> {
-    let mut result = vec![];
+fn get_words_cross_reading(grid: &Grid<char>, row: usize, column: usize) -> Option<Vec<String>> {
     // top-left
-    if row.checked_sub(1).is_some() && column.checked_sub(1).is_some() {
-        let mut word = String::new();
-        (-1..=1i32).for_each(|offset| {
-            if let Some(character) = get_character_at_coordinate(
-                lines,
-                (row as i32 + offset) as usize,
-                (column as i32 + offset) as usize,
-            ) {
-                word.push_str(&character);
-            }
-        });
-
-        if word.len() == 3 {
-            result.push(word);
-        }
-    }
+    let top_left = grid.offset((column, row), (-1, -1))?;
     // top-right
-    if row.checked_sub(1).is_some() && column.checked_sub(1).is_some() {
-        let mut word = String::new();
-        (-1..=1i32).for_each(|offset| {
-            if let Some(character) = get_character_at_coordinate(
-                lines,
-                (row as i32 + offset) as usize,
-                (column as i32 - offset) as usize,
-            ) {
-                word.push_str(&character);
-            }
-        });
-
-        if word.len() == 3 {
-            result.push(word);
-        }
-    }
+    let top_right = grid.offset((column, row), (1, -1))?;
 
-    if result.len() > 0 {
-        Some(result)
-    } else {
-        None
-    }
+    Some(vec![
+        read_word(grid, top_left, (1, 1), 3)?,
+        read_word(grid, top_right, (-1, 1), 3)?,
+    ])
 }
 
-fn find_matching_keyword(lines: &Vec<&str>, keyword: &str) -> usize {
-    let start_character = keyword.chars().nth(0).unwrap().to_string();
+fn find_matching_keyword(grid: &Grid<char>, keyword: &str) -> usize {
+    let start_character = keyword.chars().nth(0).unwrap();
     let total_characters = keyword.len();
     let directions = [
         ReadDirection::Horizontal { backward: false },
@@ -140,11 +117,11 @@ fn find_matching_keyword(lines: &Vec<&str>, keyword: &str) -> usize {
 
     let mut counter = 0;
 
-    let coordinate = find_starting_characters(lines, &start_character);
+    let coordinate = find_starting_characters(grid, start_character);
     coordinate.iter().for_each(|(row, column)| {
         directions.iter().for_each(|direction| {
             if let Some(word) =
-                get_word_at_coordinate(lines, *row, *column, *direction, total_characters)
+                get_word_at_coordinate(grid, *row, *column, *direction, total_characters)
             {
                 if &word == keyword {
                     counter += 1;
@@ -157,159 +134,24 @@ fn find_matching_keyword(lines: &Vec<&str>, keyword: &str) -> usize {
 }
 
 fn get_word_at_coordinate(
-    lines: &Vec<&str>,
+    grid: &Grid<char>,
     row: usize,
     column: usize,
     direction: ReadDirection,
     total_characters: usize,
 ) -> Option<String> {
-    match direction {
-        ReadDirection::Horizontal { backward } => {
-            if backward == false {
-                let mut result = String::new();
-                (column..column + total_characters).for_each(|target_column| {
-                    if let Some(character) = get_character_at_coordinate(lines, row, target_column)
-                    {
-                        result = format!("{}{}", result, character);
-                    }
-                });
-
-                if result.len() == total_characters {
-                    return Some(result);
-                } else {
-                    None
-                }
-            } else {
-                let mut result = String::new();
-                (column.saturating_sub(total_characters.saturating_sub(1))..=column)
-                    .rev()
-                    .for_each(|target_column| {
-                        if let Some(character) =
-                            get_character_at_coordinate(lines, row, target_column)
-                        {
-                            result = format!("{}{}", result, character);
-                        }
-                    });
-
-                if result.len() == total_characters {
-                    return Some(result);
-                } else {
-                    None
-                }
-            }
-        }
-        ReadDirection::Vertical { backward } => {
-            if backward == false {
-                let mut result = String::new();
-                (row..row + total_characters).for_each(|target_row| {
-                    if let Some(character) = get_character_at_coordinate(lines, target_row, column)
-                    {
-                        result = format!("{}{}", result, character);
-                    }
-                });
-
-                if result.len() == total_characters {
-                    return Some(result);
-                } else {
-                    None
-                }
-            } else {
-                let mut result = String::new();
-                (row.saturating_sub(total_characters.saturating_sub(1))..=row)
-                    .rev()
-                    .for_each(|target_row| {
-                        if let Some(character) =
-                            get_character_at_coordinate(lines, target_row, column)
-                        {
-                            result = format!("{}{}", result, character);
-                        }
-                    });
-
-                if result.len() == total_characters {
-                    return Some(result);
-                } else {
-                    None
-                }
-            }
-        }
-        ReadDirection::DiagonalDown { left } => {
-            if left == false {
-                let mut result = String::new();
-                (0..total_characters).for_each(|offset| {
-                    if let Some(character) =
-                        get_character_at_coordinate(lines, row + offset, column + offset)
-                    {
-                        result = format!("{}{}", result, character);
-                    }
-                });
-
-                if result.len() == total_characters {
-                    return Some(result);
-                } else {
-                    None
-                }
-            } else {
-                let mut result = String::new();
-                (0..total_characters).for_each(|offset| {
-                    if column.checked_sub(offset).is_some() == true {
-                        if let Some(character) = get_character_at_coordinate(
-                            lines,
-                            row + offset,
-                            column.saturating_sub(offset),
-                        ) {
-                            result = format!("{}{}", result, character);
-                        }
-                    }
-                });
-
-                if result.len() == total_characters {
-                    return Some(result);
-                } else {
-                    None
-                }
-            }
-        }
-        ReadDirection::DiagonalUp { left } => {
-            if left == false {
-                let mut result = String::new();
-                (0..total_characters).for_each(|offset| {
-                    if row.checked_sub(offset).is_some() == true {
-                        if let Some(character) =
-                            get_character_at_coordinate(lines, row - offset, column + offset)
-                        {
-                            result = format!("{}{}", result, character);
-                        }
-                    }
-                });
-
-                if result.len() == total_characters {
-                    return Some(result);
-                } else {
-                    None
-                }
-            } else {
-                let mut result = String::new();
-                (0..total_characters).for_each(|offset| {
-                    if row.checked_sub(offset).is_some() == true
-                        && column.checked_sub(offset).is_some() == true
-                    {
-                        if let Some(character) =
-                            get_character_at_coordinate(lines, row - offset, column - offset)
-                        {
-                            result = format!("{}{}", result, character);
-                        }
-                    }
-                });
-
-                if result.len() == total_characters {
-                    return Some(result);
-                } else {
-                    None
-                }
-            }
-        }
-        _ => None,
-    }
+    let delta = match direction {
+        ReadDirection::Horizontal { backward: false } => (1, 0),
+        ReadDirection::Horizontal { backward: true } => (-1, 0),
+        ReadDirection::Vertical { backward: false } => (0, 1),
+        ReadDirection::Vertical { backward: true } => (0, -1),
+        ReadDirection::DiagonalDown { left: false } => (1, 1),
+        ReadDirection::DiagonalDown { left: true } => (-1, 1),
+        ReadDirection::DiagonalUp { left: false } => (1, -1),
+        ReadDirection::DiagonalUp { left: true } => (-1, -1),
+    };
+
+    read_word(grid, (column, row), delta, total_characters)
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -357,11 +199,12 @@ mod tests {
     #[test]
     fn test_get_word_at_coordinate_diagonal_up() {
         let lines: Vec<&str> = TEST_INPUT.lines().collect();
+        let grid = Grid::parse(&lines, Some).unwrap();
         let keyword = "XMAS";
         let total_characters = keyword.len();
 
         let result = get_word_at_coordinate(
-            &lines,
+            &grid,
             3,
             0,
             ReadDirection::DiagonalUp { left: false },
@@ -370,7 +213,7 @@ mod tests {
         assert_eq!(&result.unwrap(), "XAA.");
 
         let result = get_word_at_coordinate(
-            &lines,
+            &grid,
             3,
             0,
             ReadDirection::DiagonalUp { left: true },
@@ -382,11 +225,12 @@ mod tests {
     #[test]
     fn test_get_word_at_coordinate_diagonal_down() {
         let lines: Vec<&str> = TEST_INPUT.lines().collect();
+        let grid = Grid::parse(&lines, Some).unwrap();
         let keyword = "XMAS";
         let total_characters = keyword.len();
 
         let result = get_word_at_coordinate(
-            &lines,
+            &grid,
             0,
             2,
             ReadDirection::DiagonalDown { left: false },
@@ -395,7 +239,7 @@ mod tests {
         assert_eq!(&result.unwrap(), "XMAS");
 
         let result = get_word_at_coordinate(
-            &lines,
+            &grid,
             0,
             2,
             ReadDirection::DiagonalDown { left: true },
@@ -406,11 +250,12 @@ mod tests {
     #[test]
     fn test_get_word_at_coordinate_vertical() {
         let lines: Vec<&str> = TEST_INPUT.lines().collect();
+        let grid = Grid::parse(&lines, Some).unwrap();
         let keyword = "XMAS";
         let total_characters = keyword.len();
 
         let result = get_word_at_coordinate(
-            &lines,
+            &grid,
             0,
             1,
             ReadDirection::Vertical { backward: false },
@@ -419,7 +264,7 @@ mod tests {
         assert_eq!(&result.unwrap(), ".SAM");
 
         let result = get_word_at_coordinate(
-            &lines,
+            &grid,
             0,
             1,
             ReadDirection::Vertical { backward: true },
@@ -428,7 +273,7 @@ mod tests {
         assert!(result.is_none());
 
         let result = get_word_at_coordinate(
-            &lines,
+            &grid,
             4,
             1,
             ReadDirection::Vertical { backward: true },
@@ -437,7 +282,7 @@ mod tests {
         assert_eq!(&result.unwrap(), "XMAS");
 
         let result = get_word_at_coordinate(
-            &lines,
+            &grid,
             4,
             1,
             ReadDirection::Vertical { backward: false },
@@ -448,11 +293,12 @@ mod tests {
     #[test]
     fn test_get_word_at_coordinate_horizontal() {
         let lines: Vec<&str> = TEST_INPUT.lines().collect();
+        let grid = Grid::parse(&lines, Some).unwrap();
         let keyword = "XMAS";
         let total_characters = keyword.len();
 
         let result = get_word_at_coordinate(
-            &lines,
+            &grid,
             0,
             0,
             ReadDirection::Horizontal { backward: false },
@@ -461,7 +307,7 @@ mod tests {
         assert_eq!(&result.unwrap(), "..X.");
 
         let result = get_word_at_coordinate(
-            &lines,
+            &grid,
             0,
             0,
             ReadDirection::Horizontal { backward: true },
@@ -470,7 +316,7 @@ mod tests {
         assert!(result.is_none());
 
         let result = get_word_at_coordinate(
-            &lines,
+            &grid,
             3,
             0,
             ReadDirection::Horizontal { backward: false },
@@ -479,7 +325,7 @@ mod tests {
         assert_eq!(&result.unwrap(), "XMAS");
 
         let result = get_word_at_coordinate(
-            &lines,
+            &grid,
             1,
             4,
             ReadDirection::Horizontal { backward: true },
@@ -488,7 +334,7 @@ mod tests {
         assert_eq!(&result.unwrap(), "XMAS");
 
         let result = get_word_at_coordinate(
-            &lines,
+            &grid,
             0,
             2,
             ReadDirection::Horizontal { backward: false },
@@ -497,7 +343,7 @@ mod tests {
         assert_eq!(&result.unwrap(), "X...");
 
         let result = get_word_at_coordinate(
-            &lines,
+            &grid,
             0,
             2,
             ReadDirection::Horizontal { backward: true },
@@ -507,20 +353,7 @@ mod tests {
     }
 
     #[test]
-    fn test_part_1() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day04::part_01(&lines);
-        assert_eq!(result, 4);
-
-        let lines: Vec<&str> = TEST_INPUT_2.lines().collect();
-        let result = Day04::part_01(&lines);
-        assert_eq!(result, 18);
-    }
-
-    #[test]
-    fn test_part_2() {
-        let lines: Vec<&str> = TEST_INPUT_3.lines().collect();
-        let result = Day04::part_02(&lines);
-        assert_eq!(result, 9);
+    fn test_examples() {
+        crate::solution::assert_examples::<Day04>();
     }
 }
@@ -1,40 +1,48 @@
-use crate::file_handler::FileHandler;
+use crate::error::AocError;
+use crate::solution::{Example, Solution};
 use rayon::prelude::*;
 use std::cmp::Ordering;
 use std::collections::{HashMap, HashSet};
-use std::time::Instant;
 
 pub struct Day05 {}
 
-impl Day05 {
-    pub fn run() -> (String, String, String, u128, u128) {
-        let first_input = FileHandler::read("./src/Y2024/inputs/day_05_1.txt");
-
-        let lines: Vec<&str> = first_input.split('\n').collect();
-
-        let start_1 = Instant::now();
-        let result_1 = Day05::part_01(&lines);
-        let time_calculation_1 = start_1.elapsed();
-
-        let start_2 = Instant::now();
-        let result_2 = Day05::part_02(&lines);
-        let time_calculation_2 = start_2.elapsed();
-
-        (
-            "Day_05".to_owned(),
-            format!("{}", result_1),
-            format!("{}", result_2),
-            time_calculation_1.as_nanos(),
-            time_calculation_2.as_nanos(),
-        )
+/// The pages that must be printed after every page.
+type Rules = HashMap<i32, Vec<i32>>;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 5;
+
+    type Input<'a> = (HashMap<i32, Vec<i32>>, Vec<Vec<i32>>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let lines: Vec<&str> = input.split('\n').collect();
+        split_input(&lines)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day05::part_01(input).to_string())
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day05::part_02(input).to_string())
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![
+            Example::new(1, TEST_INPUT, "143"),
+            Example::new(2, TEST_INPUT, "123"),
+        ]
     }
+}
 
-    fn part_01(lines: &Vec<&str>) -> i32 {
-        let (rules, updates) = split_input(lines);
+impl Day05 {
+    fn part_01(input: &(HashMap<i32, Vec<i32>>, Vec<Vec<i32>>)) -> i32 {
+        let (rules, updates) = input;
         updates
             .iter()
             .map(|update| {
-                if validate_update(&rules, update) == true {
+                if validate_update(rules, update) == true {
                     find_middle_page(update)
                 } else {
                     0
@@ -43,15 +51,15 @@ impl Day05 {
             .sum()
     }
 
-    fn part_02(lines: &Vec<&str>) -> i32 {
-        let (rules, updates) = split_input(lines);
+    fn part_02(input: &(HashMap<i32, Vec<i32>>, Vec<Vec<i32>>)) -> i32 {
+        let (rules, updates) = input;
         updates
             .iter()
             .map(|update| {
-                if validate_update(&rules, update) == true {
+                if validate_update(rules, update) == true {
                     0
                 } else {
-                    let new_update = correcting_update(&rules, update);
+                    let new_update = correcting_update(rules, update);
                     find_middle_page(&new_update)
                 }
             })
@@ -102,18 +110,21 @@ fn validate_update(rules: &HashMap<i32, Vec<i32>>, update: &Vec<i32>) -> bool {
     })
 }
 
-fn split_input(lines: &Vec<&str>) -> (HashMap<i32, Vec<i32>>, Vec<Vec<i32>>) {
-    let mut rules: HashMap<i32, Vec<i32>> = HashMap::new();
+fn split_input(lines: &Vec<&str>) -> Result<(Rules, Vec<Vec<i32>>), AocError> {
+    let mut rules: Rules = HashMap::new();
     let mut updates = vec![];
     let mut end_of_rules = false;
-    lines.iter().for_each(|text| {
+    for (index, text) in lines.iter().enumerate() {
         if end_of_rules == false {
             if text.len() == 0 {
                 end_of_rules = true;
             } else {
-                let values: Vec<&str> = text.split("|").collect();
-                let key = values[0].parse::<i32>().unwrap();
-                let page_number = values[1].parse::<i32>().unwrap();
+                let (key, page_number) = text
+                    .split_once("|")
+                    .and_then(|(key, page_number)| {
+                        Some((key.parse::<i32>().ok()?, page_number.parse::<i32>().ok()?))
+                    })
+                    .ok_or_else(|| AocError::parse(index, text, "expected `X|Y`"))?;
 
                 if let Some(list) = rules.get_mut(&key) {
                     list.push(page_number);
@@ -122,17 +133,17 @@ fn split_input(lines: &Vec<&str>) -> (HashMap<i32, Vec<i32>>, Vec<Vec<i32>>) {
                 }
             }
         } else {
-            let values: Vec<&str> = text.split(",").collect();
-            let update_list = values
-                .iter()
-                .map(|text| text.parse::<i32>().unwrap())
-                .collect();
+            let update_list = text
+                .split(",")
+                .map(|text| text.parse::<i32>().ok())
+                .collect::<Option<Vec<i32>>>()
+                .ok_or_else(|| AocError::parse(index, text, "invalid page number"))?;
 
             updates.push(update_list);
         }
-    });
+    }
 
-    (rules, updates)
+    Ok((rules, updates))
 }
 
 #[cfg(test)]
@@ -142,7 +153,7 @@ mod tests {
     #[test]
     fn test_correcting_order() {
         let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let (rules, updates) = split_input(&lines);
+        let (rules, updates) = split_input(&lines).unwrap();
 
         let result = correcting_update(&rules, &vec![75, 97, 47, 61, 53]);
         assert_eq!(result, vec![97, 75, 47, 61, 53]);
@@ -157,7 +168,7 @@ mod tests {
     #[test]
     fn test_validate_rule() {
         let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let (rules, updates) = split_input(&lines);
+        let (rules, updates) = split_input(&lines).unwrap();
         let result = validate_update(&rules, &updates[0]);
         assert!(result);
 
@@ -178,17 +189,8 @@ mod tests {
     }
 
     #[test]
-    fn test_part_1() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day05::part_01(&lines);
-        assert_eq!(result, 143);
-    }
-
-    #[test]
-    fn test_part_2() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day05::part_02(&lines);
-        assert_eq!(result, 123);
+    fn test_examples() {
+        crate::solution::assert_examples::<Day05>();
     }
 }
 
@@ -1,41 +1,53 @@
-use crate::file_handler::FileHandler;
+use crate::error::AocError;
+use crate::point::Direction;
+use crate::solution::{Example, Solution};
 use rayon::{prelude::*, result};
 use std::collections::{HashMap, HashSet};
-use std::time::Instant;
 
 pub struct Day06 {}
 
-impl Day06 {
-    pub fn run() -> (String, String, String, u128, u128) {
-        let first_input = FileHandler::read("./src/Y2024/inputs/day_06_1.txt");
-
-        let lines: Vec<&str> = first_input.split('\n').collect();
-
-        let start_1 = Instant::now();
-        let result_1 = Day06::part_01(&lines);
-        let time_calculation_1 = start_1.elapsed();
-
-        let start_2 = Instant::now();
-        let result_2 = Day06::part_02(&lines);
-        let time_calculation_2 = start_2.elapsed();
-
-        (
-            "Day_06".to_owned(),
-            format!("{}", result_1),
-            format!("{}", result_2),
-            time_calculation_1.as_nanos(),
-            time_calculation_2.as_nanos(),
-        )
+impl Solution for Day06 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 6;
+
+    type Input<'a> = (usize, usize, (usize, usize), HashSet<(usize, usize)>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let lines: Vec<&str> = input.split('\n').collect();
+        parsing_input(&lines)
     }
 
-    fn part_01(lines: &Vec<&str>) -> i32 {
-        let (width, height, (mut x, mut y), obstacles) = parsing_input(&lines);
-        let mut guard_direction = MoveDirection::Up;
+    fn part_one(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day06::part_01(input).to_string())
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day06::part_02(input).to_string())
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![
+            Example::new(1, TEST_INPUT, "41"),
+            Example::new(2, TEST_INPUT, "6"),
+        ]
+    }
+}
+
+impl Day06 {
+    fn part_01(input: &(usize, usize, (usize, usize), HashSet<(usize, usize)>)) -> i32 {
+        let &(width, height, (mut x, mut y), ref obstacles) = input;
+        let mut guard_direction = Direction::Up;
         let mut positions = HashSet::new();
         positions.insert((x, y));
 
         loop {
-            match guard_direction.take_a_step(width, height, &obstacles, (&mut x, &mut y)) {
+            match take_a_step(
+                &mut guard_direction,
+                width,
+                height,
+                obstacles,
+                (&mut x, &mut y),
+            ) {
                 MoveResult::Success(direction) => {
                     positions.insert((x, y));
                 }
@@ -49,9 +61,9 @@ impl Day06 {
         positions.len() as i32
     }
 
-    fn part_02(lines: &Vec<&str>) -> i32 {
-        let (width, height, (mut x, mut y), obstacles) = parsing_input(&lines);
-        let mut guard_direction = MoveDirection::Up;
+    fn part_02(input: &(usize, usize, (usize, usize), HashSet<(usize, usize)>)) -> i32 {
+        let &(width, height, (mut x, mut y), ref obstacles) = input;
+        let mut guard_direction = Direction::Up;
         let mut positions = HashMap::new();
         let mut list = HashSet::new();
         list.insert(guard_direction.clone());
@@ -59,7 +71,13 @@ impl Day06 {
 
         let mut put_obstacle_counter = 0;
         loop {
-            match guard_direction.take_a_step(width, height, &obstacles, (&mut x, &mut y)) {
+            match take_a_step(
+                &mut guard_direction,
+                width,
+                height,
+                obstacles,
+                (&mut x, &mut y),
+            ) {
                 MoveResult::Success(mut direction) => {
                     let key = (x, y);
 
@@ -73,13 +91,12 @@ impl Day06 {
 
                     let mut can_put_obstacle = false;
                     if let Some(foot_prints) = positions.get(&key) {
-                        let mut turned_direction = direction.clone();
-                        turned_direction.turn();
+                        let turned_direction = direction.turn_right();
 
                         foot_prints.iter().any(|prev_direction| {
                             if *prev_direction == turned_direction {
                                 can_put_obstacle = true;
-                                // print_table(width, height, &obstacles, &positions, Some((x,y)));
+                                // print_table(width, height, obstacles, &positions, Some((x,y)));
                                 true
                             } else {
                                 false
@@ -91,7 +108,7 @@ impl Day06 {
                         && look_to_the_right(
                             width,
                             height,
-                            &obstacles,
+                            obstacles,
                             &positions,
                             x,
                             y,
@@ -99,7 +116,7 @@ impl Day06 {
                         )
                     {
                         can_put_obstacle = true;
-                        // print_table(width, height, &obstacles, &positions, Some((x,y)));
+                        // print_table(width, height, obstacles, &positions, Some((x,y)));
                     }
 
                     if can_put_obstacle == true {
@@ -133,23 +150,21 @@ fn simulate_walk_to_find_old_path(
     width: usize,
     height: usize,
     obstacles: &HashSet<(usize, usize)>,
-    path: &HashMap<(usize, usize), HashSet<MoveDirection>>,
+    path: &HashMap<(usize, usize), HashSet<Direction>>,
     start_x: usize,
     start_y: usize,
-    current_direction: MoveDirection,
-    put_x: usize,
-    put_y: usize,
+    current_direction: Direction,
 ) -> bool {
     let mut x = start_x;
     let mut y = start_y;
     let mut direction = current_direction.clone();
-    let mut new_path: HashMap<(usize, usize), HashSet<MoveDirection>> = path.clone();
+    let mut new_path: HashMap<(usize, usize), HashSet<Direction>> = path.clone();
     // let mut list = HashSet::new();
     // list.insert(current_direction.clone());
     // new_path.insert((start_x, start_y), list);
 
     loop {
-        match direction.take_a_step(width, height, obstacles, (&mut x, &mut y)) {
+        match take_a_step(&mut direction, width, height, obstacles, (&mut x, &mut y)) {
             MoveResult::Success(new_direction) => {
                 let key = (x, y);
 
@@ -161,14 +176,6 @@ fn simulate_walk_to_find_old_path(
 
                 if let Some(list) = new_path.get(&key) {
                     if list.contains(&new_direction) == true {
-                        print_table(
-                            width,
-                            height,
-                            obstacles,
-                            &new_path,
-                            Some((put_x, put_y)),
-                            Some((start_x, start_y)),
-                        );
                         return true;
                     }
                 }
@@ -203,15 +210,12 @@ fn look_to_the_right(
     width: usize,
     height: usize,
     obstacles: &HashSet<(usize, usize)>,
-    path: &HashMap<(usize, usize), HashSet<MoveDirection>>,
+    path: &HashMap<(usize, usize), HashSet<Direction>>,
     x: usize,
     y: usize,
-    current_direction: MoveDirection,
+    current_direction: Direction,
 ) -> bool {
-    let (put_x, put_y) = current_direction.step_forward(x, y);
-
-    let mut looking_direction = current_direction.clone();
-    looking_direction.turn();
+    let looking_direction = current_direction.turn_right();
 
     if simulate_walk_to_find_old_path(
         width,
@@ -221,8 +225,6 @@ fn look_to_the_right(
         x,
         y,
         looking_direction.clone(),
-        put_x,
-        put_y,
     ) {
         return true;
     }
@@ -230,11 +232,12 @@ fn look_to_the_right(
     false
 }
 
+#[cfg(test)]
 fn print_table(
     width: usize,
     height: usize,
     obstacles: &HashSet<(usize, usize)>,
-    path: &HashMap<(usize, usize), HashSet<MoveDirection>>,
+    path: &HashMap<(usize, usize), HashSet<Direction>>,
     put_obstacle: Option<(usize, usize)>,
     start: Option<(usize, usize)>,
 ) {
@@ -261,15 +264,14 @@ fn print_table(
             } else if obstacles.contains(&key) {
                 print!("#");
             } else if let Some(directions) = path.get(&key) {
-                if (directions.contains(&MoveDirection::Down)
-                    || directions.contains(&MoveDirection::Up))
-                    && (directions.contains(&MoveDirection::Left)
-                        || directions.contains(&MoveDirection::Right))
+                if (directions.contains(&Direction::Down) || directions.contains(&Direction::Up))
+                    && (directions.contains(&Direction::Left)
+                        || directions.contains(&Direction::Right))
                 {
                     print!("+")
                 } else {
                     directions.iter().all(|v| {
-                        v.print();
+                        print_direction(v);
                         false
                     });
                 }
@@ -291,8 +293,8 @@ mod tests {
     #[test]
     fn test_print_table() {
         let mut list = HashSet::new();
-        list.insert(MoveDirection::Up);
-        list.insert(MoveDirection::Right);
+        list.insert(Direction::Up);
+        list.insert(Direction::Right);
         let mut path = HashMap::new();
         path.insert((1, 1), list);
         print_table(4, 4, &HashSet::new(), &path, None, None);
@@ -301,275 +303,168 @@ mod tests {
     #[test]
     fn test_up() {
         let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let (width, height, (mut x, mut y), obstacles) = parsing_input(&lines);
+        let (width, height, (mut x, mut y), obstacles) = parsing_input(&lines).unwrap();
         assert_eq!(x, 4);
         assert_eq!(y, 6);
 
-        let mut guard_direction = MoveDirection::Up;
-
         (0..5).for_each(|_| {
-            let result = guard_direction.up(width, height, &obstacles, (&mut x, &mut y));
-            assert_eq!(result, MoveResult::Success(MoveDirection::Up));
+            let result = step(Direction::Up, width, height, &obstacles, (&mut x, &mut y));
+            assert_eq!(result, MoveResult::Success(Direction::Up));
         });
 
-        let result = guard_direction.up(width, height, &obstacles, (&mut x, &mut y));
+        let result = step(Direction::Up, width, height, &obstacles, (&mut x, &mut y));
         assert_eq!(result, MoveResult::Obstacle);
     }
 
     #[test]
     fn test_down() {
         let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let (width, height, (mut x, mut y), obstacles) = parsing_input(&lines);
+        let (width, height, (mut x, mut y), obstacles) = parsing_input(&lines).unwrap();
         assert_eq!(x, 4);
         assert_eq!(y, 6);
 
-        let mut guard_direction = MoveDirection::Down;
-
         (0..3).for_each(|_| {
-            let result = guard_direction.down(width, height, &obstacles, (&mut x, &mut y));
-            assert_eq!(result, MoveResult::Success(MoveDirection::Down));
+            let result = step(Direction::Down, width, height, &obstacles, (&mut x, &mut y));
+            assert_eq!(result, MoveResult::Success(Direction::Down));
         });
 
-        let result = guard_direction.down(width, height, &obstacles, (&mut x, &mut y));
+        let result = step(Direction::Down, width, height, &obstacles, (&mut x, &mut y));
         assert_eq!(result, MoveResult::Leave);
     }
 
     #[test]
     fn test_left() {
         let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let (width, height, (mut x, mut y), obstacles) = parsing_input(&lines);
+        let (width, height, (mut x, mut y), obstacles) = parsing_input(&lines).unwrap();
         assert_eq!(x, 4);
         assert_eq!(y, 6);
 
-        let mut guard_direction = MoveDirection::Left;
-
         (0..2).for_each(|_| {
-            let result = guard_direction.left(width, height, &obstacles, (&mut x, &mut y));
-            assert_eq!(result, MoveResult::Success(MoveDirection::Left));
+            let result = step(Direction::Left, width, height, &obstacles, (&mut x, &mut y));
+            assert_eq!(result, MoveResult::Success(Direction::Left));
         });
 
-        let result = guard_direction.left(width, height, &obstacles, (&mut x, &mut y));
+        let result = step(Direction::Left, width, height, &obstacles, (&mut x, &mut y));
         assert_eq!(result, MoveResult::Obstacle);
     }
 
     #[test]
     fn test_right() {
         let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let (width, height, (mut x, mut y), obstacles) = parsing_input(&lines);
+        let (width, height, (mut x, mut y), obstacles) = parsing_input(&lines).unwrap();
         assert_eq!(x, 4);
         assert_eq!(y, 6);
 
-        let mut guard_direction = MoveDirection::Right;
-
         (0..5).for_each(|_| {
-            let result = guard_direction.right(width, height, &obstacles, (&mut x, &mut y));
-            assert_eq!(result, MoveResult::Success(MoveDirection::Right));
+            let result = step(
+                Direction::Right,
+                width,
+                height,
+                &obstacles,
+                (&mut x, &mut y),
+            );
+            assert_eq!(result, MoveResult::Success(Direction::Right));
         });
 
-        let result = guard_direction.right(width, height, &obstacles, (&mut x, &mut y));
+        let result = step(
+            Direction::Right,
+            width,
+            height,
+            &obstacles,
+            (&mut x, &mut y),
+        );
         assert_eq!(result, MoveResult::Leave);
     }
 
     #[test]
-    fn test_part_1() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day06::part_01(&lines);
-        assert_eq!(result, 41);
-    }
-
-    #[test]
-    fn test_part_2() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day06::part_02(&lines);
-        assert_eq!(result, 6);
+    fn test_examples() {
+        crate::solution::assert_examples::<Day06>();
     }
 }
 
+/// Moves the guard one step towards `move_direction` unless an obstacle is in the way.
 fn step(
+    move_direction: Direction,
     width: usize,
     height: usize,
     obstacles: &HashSet<(usize, usize)>,
     guard_position: (&mut usize, &mut usize),
-    move_direction: &mut MoveDirection,
-) {
-}
-
-#[derive(Debug, PartialEq, Eq)]
-enum MoveResult {
-    Obstacle,
-    Success(MoveDirection),
-    Leave,
-}
-
-#[derive(Debug, PartialEq, Eq, Clone, Hash)]
-enum MoveDirection {
-    Up,
-    Right,
-    Down,
-    Left,
-}
-
-impl MoveDirection {
-    fn print(&self) {
-        match self {
-            MoveDirection::Down | MoveDirection::Up => print!("|"),
-            MoveDirection::Left | MoveDirection::Right => print!("-"),
-        }
-    }
-    fn turn(&mut self) {
-        *self = match self {
-            MoveDirection::Up => MoveDirection::Right,
-            MoveDirection::Right => MoveDirection::Down,
-            MoveDirection::Down => MoveDirection::Left,
-            MoveDirection::Left => MoveDirection::Up,
-        };
-    }
-
-    fn step_back(&self, x: usize, y: usize) -> (usize, usize) {
-        match self {
-            MoveDirection::Up => (x, y.saturating_add(1)),
-            MoveDirection::Down => (x, y.saturating_sub(1)),
-            MoveDirection::Left => (x.saturating_add(1), y),
-            MoveDirection::Right => (x.saturating_sub(1), y),
-        }
-    }
-
-    fn step_forward(&self, x: usize, y: usize) -> (usize, usize) {
-        match self {
-            MoveDirection::Up => (x, y.saturating_sub(1)),
-            MoveDirection::Down => (x, y.saturating_add(1)),
-            MoveDirection::Left => (x.saturating_sub(1), y),
-            MoveDirection::Right => (x.saturating_add(1), y),
-        }
-    }
-
-    fn take_a_step(
-        &mut self,
-        width: usize,
-        height: usize,
-        obstacles: &HashSet<(usize, usize)>,
-        guard_position: (&mut usize, &mut usize),
-    ) -> MoveResult {
-        let result = match self {
-            MoveDirection::Up => self.up(width, height, obstacles, guard_position),
-            MoveDirection::Right => self.right(width, height, obstacles, guard_position),
-            MoveDirection::Down => self.down(width, height, obstacles, guard_position),
-            MoveDirection::Left => self.left(width, height, obstacles, guard_position),
-        };
-
-        match result {
-            MoveResult::Success(_) => {}
-            MoveResult::Leave => {}
-            MoveResult::Obstacle => {
-                self.turn();
-            }
-        }
-
-        result
-    }
-
-    fn up(
-        &self,
-        width: usize,
-        height: usize,
-        obstacles: &HashSet<(usize, usize)>,
-        guard_position: (&mut usize, &mut usize),
-    ) -> MoveResult {
-        let (x, y) = guard_position;
-        if y.checked_sub(1).is_none() == true {
-            return MoveResult::Leave;
-        }
-
-        let next_y = y.saturating_sub(1);
-        if obstacles.contains(&(*x, next_y)) == true {
-            return MoveResult::Obstacle;
-        }
-
-        *y = next_y;
-        MoveResult::Success(MoveDirection::Up)
+) -> MoveResult {
+    let (x, y) = guard_position;
+    let Some(next) = move_direction.step_within((*x, *y), width, height) else {
+        return MoveResult::Leave;
+    };
+
+    if obstacles.contains(&next) {
+        return MoveResult::Obstacle;
     }
 
-    fn down(
-        &self,
-        width: usize,
-        height: usize,
-        obstacles: &HashSet<(usize, usize)>,
-        guard_position: (&mut usize, &mut usize),
-    ) -> MoveResult {
-        let (x, y) = guard_position;
-        if *y + 1 >= height {
-            return MoveResult::Leave;
-        }
-
-        let next_y = y.saturating_add(1);
-        if obstacles.contains(&(*x, next_y)) == true {
-            return MoveResult::Obstacle;
-        }
+    (*x, *y) = next;
+    MoveResult::Success(move_direction)
+}
 
-        *y = next_y;
-        MoveResult::Success(MoveDirection::Down)
+/// Steps forward, turning right instead when an obstacle is in the way.
+fn take_a_step(
+    move_direction: &mut Direction,
+    width: usize,
+    height: usize,
+    obstacles: &HashSet<(usize, usize)>,
+    guard_position: (&mut usize, &mut usize),
+) -> MoveResult {
+    let result = step(*move_direction, width, height, obstacles, guard_position);
+    if result == MoveResult::Obstacle {
+        *move_direction = move_direction.turn_right();
     }
 
-    fn left(
-        &self,
-        width: usize,
-        height: usize,
-        obstacles: &HashSet<(usize, usize)>,
-        guard_position: (&mut usize, &mut usize),
-    ) -> MoveResult {
-        let (x, y) = guard_position;
-        if x.checked_sub(1).is_none() == true {
-            return MoveResult::Leave;
-        }
-
-        let next_x = x.saturating_sub(1);
-        if obstacles.contains(&(next_x, *y)) == true {
-            return MoveResult::Obstacle;
-        }
+    result
+}
 
-        *x = next_x;
-        MoveResult::Success(MoveDirection::Left)
+#[cfg(test)]
+fn print_direction(direction: &Direction) {
+    if direction.is_vertical() {
+        print!("|");
+    } else {
+        print!("-");
     }
+}
 
-    fn right(
-        &self,
-        width: usize,
-        height: usize,
-        obstacles: &HashSet<(usize, usize)>,
-        guard_position: (&mut usize, &mut usize),
-    ) -> MoveResult {
-        let (x, y) = guard_position;
-        if *x + 1 >= width {
-            return MoveResult::Leave;
-        }
-
-        let next_x = x.saturating_add(1);
-        if obstacles.contains(&(next_x, *y)) == true {
-            return MoveResult::Obstacle;
-        }
-
-        *x = next_x;
-        MoveResult::Success(MoveDirection::Right)
-    }
+#[derive(Debug, PartialEq, Eq)]
+enum MoveResult {
+    Obstacle,
+    Success(Direction),
+    Leave,
 }
 
-fn parsing_input(lines: &Vec<&str>) -> (usize, usize, (usize, usize), HashSet<(usize, usize)>) {
+fn parsing_input(
+    lines: &Vec<&str>,
+) -> Result<(usize, usize, (usize, usize), HashSet<(usize, usize)>), AocError> {
     let height = lines.len();
-    let width = lines.first().unwrap().len();
+    let width = lines.first().map_or(0, |line| line.len());
 
     let mut obstacles = HashSet::new();
-    let mut guard_position = (0, 0);
-    lines.iter().enumerate().for_each(|(row, text)| {
-        text.chars().enumerate().for_each(|(column, character)| {
-            if character == '#' {
-                obstacles.insert((column, row));
-            } else if character == '^' {
-                guard_position = (column, row);
+    let mut guard_position = None;
+    for (row, text) in lines.iter().enumerate() {
+        for (column, character) in text.chars().enumerate() {
+            match character {
+                '#' => {
+                    obstacles.insert((column, row));
+                }
+                '^' => guard_position = Some((column, row)),
+                '.' => {}
+                _ => {
+                    return Err(AocError::parse(
+                        row,
+                        text,
+                        format!("unknown tile `{}`", character),
+                    ))
+                }
             }
-        });
-    });
+        }
+    }
+    let guard_position =
+        guard_position.ok_or_else(|| AocError::unsolvable("the map has no guard `^`"))?;
 
-    (width, height, guard_position, obstacles)
+    Ok((width, height, guard_position, obstacles))
 }
 
 const TEST_INPUT: &str = "....#.....
@@ -1,43 +1,44 @@
-use crate::file_handler::FileHandler;
+use crate::error::AocError;
+use crate::solution::{Example, Solution};
 use rayon::prelude::*;
 use std::collections::{HashMap, HashSet};
-use std::time::Instant;
 
 pub struct Day07 {}
 
-impl Day07 {
-    pub fn run() -> (String, String, String, u128, u128) {
-        let first_input = FileHandler::read("./src/Y2024/inputs/day_07_1.txt");
-
-        let lines: Vec<&str> = first_input.split('\n').collect();
-
-        let start_1 = Instant::now();
-        let result_1 = Day07::part_01(&lines);
-        let time_calculation_1 = start_1.elapsed();
-
-        let start_2 = Instant::now();
-        let result_2 = Day07::part_02(&lines);
-        let time_calculation_2 = start_2.elapsed();
-
-        (
-            "Day_07".to_owned(),
-            format!("{}", result_1),
-            format!("{}", result_2),
-            time_calculation_1.as_nanos(),
-            time_calculation_2.as_nanos(),
-        )
+impl Solution for Day07 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 7;
+
+    type Input<'a> = Vec<(u64, Vec<u64>)>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let lines: Vec<&str> = input.split('\n').collect();
+        parsing_input(&lines)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day07::part_01(input).to_string())
     }
 
-    fn part_01(lines: &Vec<&str>) -> u64 {
-        let input = parsing_input(lines);
+    fn part_two(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day07::part_02(input).to_string())
+    }
 
-        process_two_operators(input)
+    fn examples() -> Vec<Example> {
+        vec![
+            Example::new(1, TEST_INPUT, "3749"),
+            Example::new(2, TEST_INPUT, "11387"),
+        ]
     }
+}
 
-    fn part_02(lines: &Vec<&str>) -> u64 {
-        let input = parsing_input(lines);
+impl Day07 {
+    fn part_01(input: &Vec<(u64, Vec<u64>)>) -> u64 {
+        process_two_operators(input.clone())
+    }
 
-        process_three_operators(input)
+    fn part_02(input: &Vec<(u64, Vec<u64>)>) -> u64 {
+        process_three_operators(input.clone())
     }
 }
 
@@ -46,23 +47,14 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_part_1() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day07::part_01(&lines);
-        assert_eq!(result, 3749);
-    }
-
-    #[test]
-    fn test_part_2() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day07::part_02(&lines);
-        assert_eq!(result, 11387);
+    fn test_examples() {
+        crate::solution::assert_examples::<Day07>();
     }
 
     #[test]
     fn test_parse_input() {
         let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = parsing_input(&lines);
+        let result = parsing_input(&lines).unwrap();
         assert_eq!(190, result[0].0);
         assert_eq!(vec![10, 19], result[0].1);
     }
@@ -256,23 +248,28 @@ fn total_operators(total_numbers: usize) -> usize {
     total_numbers - 1
 }
 
-fn parsing_input(lines: &Vec<&str>) -> Vec<(u64, Vec<u64>)> {
+fn parsing_input(lines: &Vec<&str>) -> Result<Vec<(u64, Vec<u64>)>, AocError> {
     lines
         .iter()
-        .map(|text| {
-            let splited: Vec<&str> = text.split(": ").collect();
-            let left = splited[0].parse::<u64>().unwrap();
-            let values: Vec<&str> = splited[1].split(" ").collect();
-            let right = values
-                .iter()
-                .map(|value| value.parse::<u64>().unwrap())
-                .collect();
-
-            (left, right)
+        .enumerate()
+        .map(|(index, text)| {
+            parsing_equation(text)
+                .ok_or_else(|| AocError::parse(index, text, "expected `test value: numbers`"))
         })
         .collect()
 }
 
+fn parsing_equation(text: &str) -> Option<(u64, Vec<u64>)> {
+    let (left, values) = text.split_once(": ")?;
+    let left = left.parse::<u64>().ok()?;
+    let right = values
+        .split(" ")
+        .map(|value| value.parse::<u64>().ok())
+        .collect::<Option<Vec<u64>>>()?;
+
+    Some((left, right))
+}
+
 const TEST_INPUT: &str = "190: 10 19
 3267: 81 40 27
 83: 17 5
@@ -1,42 +1,47 @@
-use crate::file_handler::FileHandler;
+use crate::error::AocError;
+use crate::solution::{Example, Solution};
 use rayon::prelude::*;
 use std::collections::{HashMap, HashSet};
-use std::time::Instant;
 
 pub struct Day08 {}
 
-impl Day08 {
-    pub fn run() -> (String, String, String, u128, u128) {
-        let first_input = FileHandler::read("./src/Y2024/inputs/day_08_1.txt");
-
-        let lines: Vec<&str> = first_input.split('\n').collect();
-
-        let start_1 = Instant::now();
-        let result_1 = Day08::part_01(&lines);
-        let time_calculation_1 = start_1.elapsed();
-
-        let start_2 = Instant::now();
-        let result_2 = Day08::part_02(&lines);
-        let time_calculation_2 = start_2.elapsed();
-
-        (
-            "Day_08".to_owned(),
-            format!("{}", result_1),
-            format!("{}", result_2),
-            time_calculation_1.as_nanos(),
-            time_calculation_2.as_nanos(),
-        )
+impl Solution for Day08 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 8;
+
+    type Input<'a> = (usize, usize, HashMap<String, HashSet<(i32, i32)>>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let lines: Vec<&str> = input.split('\n').collect();
+        Ok(parse_input(&lines))
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day08::part_01(input).to_string())
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<String, AocError> {
+        Ok(Day08::part_02(input).to_string())
+    }
+
+    fn examples() -> Vec<Example> {
+        vec![
+            Example::new(1, TEST_INPUT, "14"),
+            Example::new(2, TEST_INPUT, "34"),
+        ]
     }
+}
 
-    fn part_01(lines: &Vec<&str>) -> i32 {
-        let (w, h, data) = parse_input(lines);
-        let result = process_data(w, h, data);
+impl Day08 {
+    fn part_01(input: &(usize, usize, HashMap<String, HashSet<(i32, i32)>>)) -> i32 {
+        let &(w, h, ref data) = input;
+        let result = process_data(w, h, data.clone());
         result.len() as i32
     }
 
-    fn part_02(lines: &Vec<&str>) -> i32 {
-        let (w, h, data) = parse_input(lines);
-        let result = process_data_with_resonant(w, h, data);
+    fn part_02(input: &(usize, usize, HashMap<String, HashSet<(i32, i32)>>)) -> i32 {
+        let &(w, h, ref data) = input;
+        let result = process_data_with_resonant(w, h, data.clone());
         result.len() as i32
     }
 }
@@ -46,17 +51,8 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_part_1() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day08::part_01(&lines);
-        assert_eq!(result, 14);
-    }
-
-    #[test]
-    fn test_part_2() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day08::part_02(&lines);
-        assert_eq!(result, 34);
+    fn test_examples() {
+        crate::solution::assert_examples::<Day08>();
     }
 
     #[test]
@@ -1,33 +1,46 @@
-use crate::file_handler::FileHandler;
+use crate::error::AocError;
+use crate::solution::{Example, Solution};
 use rayon::prelude::*;
 use std::collections::{HashMap, HashSet};
-use std::time::Instant;
 
 pub struct Day09 {}
 
-impl Day09 {
-    pub fn run() -> (String, String, String, u128, u128) {
-        let first_input = FileHandler::read("./src/Y2024/inputs/day_09_1.txt");
-
-        let lines: Vec<&str> = first_input.split('\n').collect();
-
-        let start_1 = Instant::now();
-        let result_1 = Day09::part_01(&lines);
-        let time_calculation_1 = start_1.elapsed();
-
-        let start_2 = Instant::now();
-        let result_2 = Day09::part_02(&lines);
-        let time_calculation_2 = start_2.elapsed();
-
-        (
-            "Day_09".to_owned(),
-            format!("{}", result_1),
-            format!("{}", result_2),
-            time_calculation_1.as_nanos(),
-            time_calculation_2.as_nanos(),
-        )
+impl Solution for Day09 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 9;
+
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Vec<&str>, AocError> {
+        let lines: Vec<&str> = input.split('\n').collect();
+        if let Some(c) = lines[0].chars().find(|c| !c.is_ascii_digit()) {
+            return Err(AocError::parse(
+                0,
+                lines[0],
+                format!("`{}` is not a block count", c),
+            ));
+        }
+
+        Ok(lines)
+    }
+
+    fn part_one(lines: &Vec<&str>) -> Result<String, AocError> {
+        Ok(Day09::part_01(lines).to_string())
+    }
+
+    fn part_two(lines: &Vec<&str>) -> Result<String, AocError> {
+        Ok(Day09::part_02(lines).to_string())
     }
 
+    fn examples() -> Vec<Example> {
+        vec![
+            Example::new(1, TEST_INPUT, "1928"),
+            Example::new(2, TEST_INPUT, "2858"),
+        ]
+    }
+}
+
+impl Day09 {
     fn part_01(lines: &Vec<&str>) -> u64 {
         let data = parse_input(&lines);
         let processed_data = process_data(&data);
@@ -52,17 +65,8 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_part_1() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day09::part_01(&lines);
-        assert_eq!(result, 1928);
-    }
-
-    #[test]
-    fn test_part_2() {
-        let lines: Vec<&str> = TEST_INPUT.lines().collect();
-        let result = Day09::part_02(&lines);
-        assert_eq!(result, 2858);
+    fn test_examples() {
+        crate::solution::assert_examples::<Day09>();
     }
 
     #[test]
@@ -0,0 +1,514 @@
+use std::fmt;
+
+use crate::file_handler::InputSource;
+use crate::output::Format;
+use crate::registry::{self, DayEntry, RegistryError};
+
+pub const USAGE: &str = "Usage:
+    aoc22 run --year <YEAR> --day <DAY> [--part <1|2>] [--inputs <DIR|->] [--format <FORMAT>]
+        [--param <NAME=VALUE>]...
+    aoc22 run --year <YEAR> --days <LIST> [--part <1|2>] [--inputs <DIR>] [--format <FORMAT>]
+    aoc22 run [--year <YEAR>] --all [--part <1|2>] [--inputs <DIR>] [--format <FORMAT>]
+        [--parallel | --sequential]
+    aoc22 verify [--year <YEAR>] [--day <DAY> | --days <LIST> | --all] [--inputs <DIR>]
+    aoc22 examples [--year <YEAR>] [--day <DAY> | --days <LIST> | --all] [--part <1|2>]
+
+    <LIST> is a comma separated list of days or ranges, e.g. `1-9` or `1,3,5-7`.
+    `--all` without `--year` runs every implemented day of every year.
+    `--inputs` points to a directory holding `Y<YEAR>/inputs/day_<DD>_1.txt`, it defaults
+    to `$AOC_INPUTS_DIR` and then to the crate's `src`. `--inputs -` reads a single day from stdin.
+    `--param` hands a parameter to the day, e.g. `min_straight=4` for 2023 Day 17.
+    `--parallel` runs the selected days concurrently, `--sequential` (default) one after the other
+    for accurate timings. Several days end with the slowest days and the total time per year.
+    <FORMAT> is `text` (default), `json`, `csv` or `table`, which aligns the days of a year with totals.
+    `verify` compares the answers with `Y<YEAR>/inputs/answers.txt`, it checks every day by default.
+    `examples` runs the examples of the puzzle texts registered by every day.";
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run(RunOptions),
+    Verify(RunOptions),
+    Examples(RunOptions),
+    Help,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum DaySelection {
+    All,
+    Days(Vec<u8>),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct RunOptions {
+    pub year: Option<u16>,
+    pub days: DaySelection,
+    pub part: Option<u8>,
+    pub inputs: Option<InputSource>,
+    pub format: Format,
+    pub parallel: bool,
+    /// The parameters of `--param`, handed to `Solution::configure` of the one selected day.
+    pub params: Vec<(String, i64)>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum CliError {
+    MissingCommand,
+    UnknownCommand(String),
+    UnknownFlag(String),
+    MissingValue(String),
+    InvalidValue(String, String),
+    ConflictingFlags(String, String),
+    NoDaySelected,
+    MissingYear,
+    StdinNeedsOneDay(usize),
+    ParamsNeedOneDay(usize),
+    Registry(RegistryError),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CliError::MissingCommand => write!(f, "missing command"),
+            CliError::UnknownCommand(command) => write!(f, "unknown command `{}`", command),
+            CliError::UnknownFlag(flag) => write!(f, "unknown flag `{}`", flag),
+            CliError::MissingValue(flag) => write!(f, "`{}` needs a value", flag),
+            CliError::InvalidValue(flag, value) => {
+                write!(f, "invalid value `{}` for `{}`", value, flag)
+            }
+            CliError::ConflictingFlags(first, second) => {
+                write!(f, "`{}` cannot be used together with `{}`", first, second)
+            }
+            CliError::NoDaySelected => write!(f, "no day selected, use --day, --days or --all"),
+            CliError::MissingYear => write!(f, "--day and --days need --year"),
+            CliError::StdinNeedsOneDay(count) => {
+                write!(
+                    f,
+                    "`--inputs -` reads a single day but {} are selected",
+                    count
+                )
+            }
+            CliError::ParamsNeedOneDay(count) => {
+                write!(
+                    f,
+                    "`--param` is for a single day but {} are selected",
+                    count
+                )
+            }
+            CliError::Registry(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for CliError {}
+
+impl From<RegistryError> for CliError {
+    fn from(error: RegistryError) -> Self {
+        CliError::Registry(error)
+    }
+}
+
+pub fn parse_args(args: &[String]) -> Result<Command, CliError> {
+    let mut args = args.iter();
+    let command = match args.next() {
+        Some(command) => command.as_str(),
+        None => return Err(CliError::MissingCommand),
+    };
+
+    match command {
+        "run" => parse_run(&mut args, None).map(Command::Run),
+        "verify" => {
+            let options = parse_run(&mut args, Some(DaySelection::All))?;
+            if options.inputs == Some(InputSource::Stdin) {
+                return Err(CliError::InvalidValue(
+                    "--inputs".to_owned(),
+                    "-".to_owned(),
+                ));
+            }
+            without_params(&options)?;
+            Ok(Command::Verify(options))
+        }
+        "examples" => {
+            let options = parse_run(&mut args, Some(DaySelection::All))?;
+            without_params(&options)?;
+            Ok(Command::Examples(options))
+        }
+        "help" | "-h" | "--help" => Ok(Command::Help),
+        _ => Err(CliError::UnknownCommand(command.to_owned())),
+    }
+}
+
+/// `verify` and `examples` run the days as they are, the examples bringing their own parameters.
+fn without_params(options: &RunOptions) -> Result<(), CliError> {
+    match options.params.first() {
+        Some(_) => Err(CliError::UnknownFlag("--param".to_owned())),
+        None => Ok(()),
+    }
+}
+
+/// Parses the options shared by `run` and `verify`, `default_days` is used when no day is selected.
+fn parse_run<'a>(
+    args: &mut impl Iterator<Item = &'a String>,
+    default_days: Option<DaySelection>,
+) -> Result<RunOptions, CliError> {
+    let mut year = None;
+    let mut days: Option<(&str, DaySelection)> = None;
+    let mut part = None;
+    let mut inputs = None;
+    let mut format = Format::Text;
+    let mut execution: Option<(&str, bool)> = None;
+    let mut params = vec![];
+
+    while let Some(flag) = args.next() {
+        let flag = flag.as_str();
+        match flag {
+            "--all" => days = Some(select_days(days, flag, DaySelection::All)?),
+            "--parallel" | "--sequential" => {
+                if let Some((previous, _)) = execution {
+                    return Err(CliError::ConflictingFlags(
+                        previous.to_owned(),
+                        flag.to_owned(),
+                    ));
+                }
+                execution = Some((flag, flag == "--parallel"));
+            }
+            "--year" | "--day" | "--days" | "--part" | "--inputs" | "--format" | "--param" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| CliError::MissingValue(flag.to_owned()))?;
+                let invalid = || CliError::InvalidValue(flag.to_owned(), value.to_owned());
+                match flag {
+                    "--year" => year = Some(value.parse::<u16>().map_err(|_| invalid())?),
+                    "--day" => {
+                        let day = value.parse::<u8>().map_err(|_| invalid())?;
+                        days = Some(select_days(days, flag, DaySelection::Days(vec![day]))?);
+                    }
+                    "--days" => {
+                        let list = parse_day_list(value).ok_or_else(invalid)?;
+                        days = Some(select_days(days, flag, DaySelection::Days(list))?);
+                    }
+                    "--inputs" => inputs = Some(InputSource::parse(value)),
+                    "--format" => format = Format::parse(value).ok_or_else(invalid)?,
+                    "--param" => {
+                        let (name, value) = value.split_once('=').ok_or_else(invalid)?;
+                        let value = value.parse::<i64>().map_err(|_| invalid())?;
+                        params.push((name.to_owned(), value));
+                    }
+                    _ => {
+                        let value = value.parse::<u8>().map_err(|_| invalid())?;
+                        if value != 1 && value != 2 {
+                            return Err(invalid());
+                        }
+                        part = Some(value);
+                    }
+                }
+            }
+            _ => return Err(CliError::UnknownFlag(flag.to_owned())),
+        }
+    }
+
+    let days = match (days, default_days) {
+        (Some((_, days)), _) => days,
+        (None, Some(days)) => days,
+        (None, None) => return Err(CliError::NoDaySelected),
+    };
+
+    if year.is_none() && days != DaySelection::All {
+        return Err(CliError::MissingYear);
+    }
+
+    Ok(RunOptions {
+        year,
+        days,
+        part,
+        inputs,
+        format,
+        parallel: execution.is_some_and(|(_, parallel)| parallel),
+        params,
+    })
+}
+
+fn select_days<'a>(
+    previous: Option<(&'a str, DaySelection)>,
+    flag: &'a str,
+    selection: DaySelection,
+) -> Result<(&'a str, DaySelection), CliError> {
+    match previous {
+        Some((previous_flag, _)) => Err(CliError::ConflictingFlags(
+            previous_flag.to_owned(),
+            flag.to_owned(),
+        )),
+        None => Ok((flag, selection)),
+    }
+}
+
+/// Parses `1-9`, `3` or `1,3,5-7` into a sorted list of days without duplicates.
+fn parse_day_list(text: &str) -> Option<Vec<u8>> {
+    let mut days = vec![];
+    for part in text.split(',') {
+        match part.split_once('-') {
+            Some((from, to)) => {
+                let from = from.trim().parse::<u8>().ok()?;
+                let to = to.trim().parse::<u8>().ok()?;
+                if from > to {
+                    return None;
+                }
+                days.extend(from..=to);
+            }
+            None => days.push(part.trim().parse::<u8>().ok()?),
+        }
+    }
+
+    days.sort();
+    days.dedup();
+    Some(days)
+}
+
+/// Turns the selection into registry entries, failing on the first unknown or unimplemented day.
+pub fn resolve(options: &RunOptions) -> Result<Vec<&'static DayEntry>, CliError> {
+    let years = match options.year {
+        Some(year) => vec![year],
+        None => registry::years(),
+    };
+
+    let mut entries = vec![];
+    for year in years {
+        match &options.days {
+            DaySelection::All => entries.extend(registry::days_of(year)?),
+            DaySelection::Days(days) => {
+                for day in days {
+                    entries.push(registry::find(year, *day)?);
+                }
+            }
+        }
+    }
+
+    if options.inputs == Some(InputSource::Stdin) && entries.len() != 1 {
+        return Err(CliError::StdinNeedsOneDay(entries.len()));
+    }
+
+    if !options.params.is_empty() && entries.len() != 1 {
+        return Err(CliError::ParamsNeedOneDay(entries.len()));
+    }
+
+    Ok(entries)
+}
+
+/// The inputs given on the command line, otherwise the ones from `AOC_INPUTS_DIR`.
+pub fn input_source(options: &RunOptions) -> InputSource {
+    options.inputs.clone().unwrap_or_else(InputSource::from_env)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(text: &str) -> Vec<String> {
+        text.split_whitespace().map(|arg| arg.to_owned()).collect()
+    }
+
+    #[test]
+    fn test_parse_run() {
+        assert_eq!(
+            parse_args(&args("run --year 2023 --day 17 --part 2")),
+            Ok(Command::Run(RunOptions {
+                year: Some(2023),
+                days: DaySelection::Days(vec![17]),
+                part: Some(2),
+                inputs: None,
+                format: Format::Text,
+                parallel: false,
+                params: vec![],
+            }))
+        );
+
+        assert_eq!(
+            parse_args(&args("run --all")),
+            Ok(Command::Run(RunOptions {
+                year: None,
+                days: DaySelection::All,
+                part: None,
+                inputs: None,
+                format: Format::Text,
+                parallel: false,
+                params: vec![],
+            }))
+        );
+
+        assert_eq!(
+            parse_args(&args("run --year 2022 --days 1-3,7,2")),
+            Ok(Command::Run(RunOptions {
+                year: Some(2022),
+                days: DaySelection::Days(vec![1, 2, 3, 7]),
+                part: None,
+                inputs: None,
+                format: Format::Text,
+                parallel: false,
+                params: vec![],
+            }))
+        );
+
+        assert_eq!(
+            parse_args(&args("run --all --format csv --parallel")),
+            Ok(Command::Run(RunOptions {
+                year: None,
+                days: DaySelection::All,
+                part: None,
+                inputs: None,
+                format: Format::Csv,
+                parallel: true,
+                params: vec![],
+            }))
+        );
+
+        assert_eq!(
+            parse_args(&args(
+                "run --year 2023 --day 17 --param min_straight=4 --param max_straight=10"
+            )),
+            Ok(Command::Run(RunOptions {
+                year: Some(2023),
+                days: DaySelection::Days(vec![17]),
+                part: None,
+                inputs: None,
+                format: Format::Text,
+                parallel: false,
+                params: vec![
+                    ("min_straight".to_owned(), 4),
+                    ("max_straight".to_owned(), 10)
+                ],
+            }))
+        );
+
+        assert_eq!(
+            parse_args(&args("verify --year 2023")),
+            Ok(Command::Verify(RunOptions {
+                year: Some(2023),
+                days: DaySelection::All,
+                part: None,
+                inputs: None,
+                format: Format::Text,
+                parallel: false,
+                params: vec![],
+            }))
+        );
+
+        assert_eq!(
+            parse_args(&args("run --year 2023 --day 5 --inputs -")),
+            Ok(Command::Run(RunOptions {
+                year: Some(2023),
+                days: DaySelection::Days(vec![5]),
+                part: None,
+                inputs: Some(InputSource::Stdin),
+                format: Format::Text,
+                parallel: false,
+                params: vec![],
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_run_errors() {
+        assert_eq!(parse_args(&args("")), Err(CliError::MissingCommand));
+        assert_eq!(
+            parse_args(&args("run --year 2023")),
+            Err(CliError::NoDaySelected)
+        );
+        assert_eq!(parse_args(&args("run --day 3")), Err(CliError::MissingYear));
+        assert_eq!(
+            parse_args(&args("run --year 2023 --part 3 --all")),
+            Err(CliError::InvalidValue("--part".to_owned(), "3".to_owned()))
+        );
+        assert_eq!(
+            parse_args(&args("run --year 2023 --days 9-1")),
+            Err(CliError::InvalidValue(
+                "--days".to_owned(),
+                "9-1".to_owned()
+            ))
+        );
+        assert_eq!(
+            parse_args(&args("run --year 2023 --day 1 --all")),
+            Err(CliError::ConflictingFlags(
+                "--day".to_owned(),
+                "--all".to_owned()
+            ))
+        );
+        assert_eq!(
+            parse_args(&args("run --year")),
+            Err(CliError::MissingValue("--year".to_owned()))
+        );
+        assert_eq!(
+            parse_args(&args("run --all --parallel --sequential")),
+            Err(CliError::ConflictingFlags(
+                "--parallel".to_owned(),
+                "--sequential".to_owned()
+            ))
+        );
+        assert_eq!(
+            parse_args(&args("run --year 2023 --day 17 --param min_straight")),
+            Err(CliError::InvalidValue(
+                "--param".to_owned(),
+                "min_straight".to_owned()
+            ))
+        );
+        assert_eq!(
+            parse_args(&args("verify --param trace=1")),
+            Err(CliError::UnknownFlag("--param".to_owned()))
+        );
+        assert_eq!(
+            parse_args(&args("verify --inputs -")),
+            Err(CliError::InvalidValue(
+                "--inputs".to_owned(),
+                "-".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_resolve() {
+        let options = RunOptions {
+            year: Some(2015),
+            days: DaySelection::All,
+            part: None,
+            inputs: None,
+            format: Format::Text,
+            parallel: false,
+            params: vec![],
+        };
+        assert_eq!(resolve(&options).unwrap().len(), 3);
+
+        let options = RunOptions {
+            year: Some(2023),
+            days: DaySelection::Days(vec![22, 23]),
+            part: None,
+            inputs: None,
+            format: Format::Text,
+            parallel: false,
+            params: vec![],
+        };
+        assert_eq!(
+            resolve(&options).err(),
+            Some(CliError::Registry(RegistryError::Unimplemented(2023, 23)))
+        );
+
+        let options = RunOptions {
+            year: Some(2015),
+            days: DaySelection::All,
+            part: None,
+            inputs: Some(InputSource::Stdin),
+            format: Format::Text,
+            parallel: false,
+            params: vec![],
+        };
+        assert_eq!(resolve(&options).err(), Some(CliError::StdinNeedsOneDay(3)));
+
+        let options = RunOptions {
+            year: Some(2015),
+            days: DaySelection::All,
+            part: None,
+            inputs: None,
+            format: Format::Text,
+            parallel: false,
+            params: vec![("trace".to_owned(), 1)],
+        };
+        assert_eq!(resolve(&options).err(), Some(CliError::ParamsNeedOneDay(3)));
+    }
+}
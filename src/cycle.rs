@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A simulation that settled into a loop: the first `prefix` states never come back, after
+/// them the next `period` states repeat forever.
+#[derive(Debug, Clone)]
+pub struct Cycle<S> {
+    pub prefix: usize,
+    pub period: usize,
+    /// Every state from the start up to and including the first repeated one, so there are
+    /// `prefix + period + 1` of them and the last one matches `states[prefix]`.
+    pub states: Vec<S>,
+}
+
+impl<S> Cycle<S> {
+    /// The index in `states` of a state equivalent to the one after `iteration` steps.
+    pub fn index_of(&self, iteration: u64) -> usize {
+        if iteration < self.states.len() as u64 {
+            return iteration as usize;
+        }
+
+        let offset = (iteration - self.prefix as u64) % self.period as u64;
+        self.prefix + offset as usize
+    }
+
+    /// The state after `iteration` steps.
+    pub fn state_at(&self, iteration: u64) -> &S {
+        &self.states[self.index_of(iteration)]
+    }
+
+    /// Extrapolates a quantity that grows by the same amount every period, such as the height
+    /// of a tower, to the state after `iteration` steps.
+    pub fn extrapolate(&self, iteration: u64, value: impl Fn(&S) -> i64) -> i64 {
+        if iteration < self.states.len() as u64 {
+            return value(&self.states[iteration as usize]);
+        }
+
+        let periods = (iteration - self.prefix as u64) / self.period as u64;
+        let growth =
+            value(&self.states[self.prefix + self.period]) - value(&self.states[self.prefix]);
+        value(self.state_at(iteration)) + periods as i64 * growth
+    }
+}
+
+/// Steps from `start` until a state repeats.
+pub fn find_cycle<S>(start: S, step: impl FnMut(&S) -> S) -> Cycle<S>
+where
+    S: Clone + Eq + Hash,
+{
+    find_cycle_by_key(start, step, |state| state.clone())
+}
+
+/// Steps from `start` until two states share the same `key`, for states that keep growing
+/// but whose future only depends on part of them.
+pub fn find_cycle_by_key<S, K>(
+    start: S,
+    mut step: impl FnMut(&S) -> S,
+    mut key: impl FnMut(&S) -> K,
+) -> Cycle<S>
+where
+    K: Eq + Hash,
+{
+    let mut seen: HashMap<K, usize> = HashMap::new();
+    let mut states = vec![start];
+
+    loop {
+        let index = states.len() - 1;
+        if let Some(&prefix) = seen.get(&key(&states[index])) {
+            return Cycle {
+                prefix,
+                period: index - prefix,
+                states,
+            };
+        }
+
+        seen.insert(key(&states[index]), index);
+        let next = step(&states[index]);
+        states.push(next);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_cycle() {
+        // 0, 1, 2, 3, 4, 2, 3, 4, ...
+        let cycle = find_cycle(0u8, |n| if *n == 4 { 2 } else { n + 1 });
+        assert_eq!(cycle.prefix, 2);
+        assert_eq!(cycle.period, 3);
+        assert_eq!(*cycle.state_at(4), 4);
+        assert_eq!(*cycle.state_at(5), 2);
+        assert_eq!(*cycle.state_at(1_000_000_000), 4);
+    }
+
+    #[test]
+    fn test_extrapolate() {
+        // A counter that only matters modulo 3, offset after the first step.
+        let cycle = find_cycle_by_key(
+            0i64,
+            |n| if *n == 0 { 10 } else { n + 1 },
+            |n| (*n != 0, n % 3),
+        );
+        assert_eq!(cycle.prefix, 1);
+        assert_eq!(cycle.period, 3);
+        assert_eq!(cycle.extrapolate(3, |n| *n), 12);
+        assert_eq!(cycle.extrapolate(100, |n| *n), 109);
+    }
+}
@@ -0,0 +1,77 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::file_handler::InputError;
+
+/// Everything that can go wrong while reading, parsing or solving a day.
+#[derive(Debug)]
+pub enum AocError {
+    Io(InputError),
+    /// `line` counts from 1 and `text` is the offending line as it was read.
+    Parse {
+        line: usize,
+        text: String,
+        reason: String,
+    },
+    Unsolvable(String),
+}
+
+impl AocError {
+    /// A parse error for the line at `index` (counting from 0) of the input.
+    pub fn parse(index: usize, text: &str, reason: impl Into<String>) -> AocError {
+        AocError::Parse {
+            line: index + 1,
+            text: text.to_owned(),
+            reason: reason.into(),
+        }
+    }
+
+    pub fn unsolvable(reason: impl Into<String>) -> AocError {
+        AocError::Unsolvable(reason.into())
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AocError::Io(error) => write!(f, "{}", error),
+            AocError::Parse { line, text, reason } => {
+                write!(f, "cannot parse line {} `{}`: {}", line, text, reason)
+            }
+            AocError::Unsolvable(reason) => write!(f, "no solution: {}", reason),
+        }
+    }
+}
+
+impl Error for AocError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AocError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<InputError> for AocError {
+    fn from(error: InputError) -> Self {
+        AocError::Io(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let error = AocError::parse(4, "move x from 1 to 2", "invalid crate count");
+        assert_eq!(
+            error.to_string(),
+            "cannot parse line 5 `move x from 1 to 2`: invalid crate count"
+        );
+        assert_eq!(
+            AocError::unsolvable("no exit").to_string(),
+            "no solution: no exit"
+        );
+    }
+}
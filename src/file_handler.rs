@@ -1,11 +1,145 @@
+use std::env;
+use std::error::Error;
+use std::fmt;
 use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Environment variable pointing to the directory the inputs are read from.
+pub const INPUTS_DIR_VAR: &str = "AOC_INPUTS_DIR";
+
+/// Where the puzzle inputs are read from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputSource {
+    /// A directory laid out like `src`, e.g. holding `Y2023/inputs/day_05_1.txt`.
+    Dir(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// `-` means stdin, anything else is an inputs directory.
+    pub fn parse(value: &str) -> InputSource {
+        match value {
+            "-" => InputSource::Stdin,
+            _ => InputSource::Dir(PathBuf::from(value)),
+        }
+    }
+
+    /// The directory in `AOC_INPUTS_DIR` if set, the crate's own `src` otherwise,
+    /// so the binary does not depend on the directory it is started from.
+    pub fn from_env() -> InputSource {
+        match env::var_os(INPUTS_DIR_VAR) {
+            Some(dir) => InputSource::Dir(PathBuf::from(dir)),
+            None => InputSource::Dir(PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/src"))),
+        }
+    }
+}
+
+/// The input of a day could not be read.
+#[derive(Debug)]
+pub struct InputError {
+    pub year: u16,
+    pub day: u8,
+    pub path: String,
+    pub error: io::Error,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "cannot read the input of Y{} Day {:02} from `{}`: {}",
+            self.year, self.day, self.path, self.error
+        )
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
 
 pub struct FileHandler {}
 
 impl FileHandler {
-    pub fn read(file_path: &str) -> String {
-        let contents = fs::read_to_string(file_path).expect("");
+    pub fn input_path(dir: &Path, year: u16, day: u8) -> PathBuf {
+        dir.join(format!("Y{}", year))
+            .join("inputs")
+            .join(format!("day_{:02}_1.txt", day))
+    }
+
+    pub fn answers_path(dir: &Path, year: u16) -> PathBuf {
+        dir.join(format!("Y{}", year))
+            .join("inputs")
+            .join("answers.txt")
+    }
+
+    pub fn read(source: &InputSource, year: u16, day: u8) -> Result<String, InputError> {
+        let (path, contents) = match source {
+            InputSource::Dir(dir) => {
+                let path = FileHandler::input_path(dir, year, day);
+                let contents = fs::read_to_string(&path);
+                (path.display().to_string(), contents)
+            }
+            InputSource::Stdin => {
+                let mut contents = String::new();
+                let read = io::stdin().read_to_string(&mut contents).map(|_| contents);
+                ("<stdin>".to_owned(), read)
+            }
+        };
+
+        contents.map_err(|error| InputError {
+            year,
+            day,
+            path,
+            error,
+        })
+    }
+
+    /// Turns `\r\n` line endings into `\n` and, with `trim_trailing_blank_lines`, drops the
+    /// empty lines at the end, so days can split on `'\n'` whatever saved the input.
+    pub fn normalize(input: &str, trim_trailing_blank_lines: bool) -> String {
+        let normalized = input.replace("\r\n", "\n");
+        if !trim_trailing_blank_lines {
+            return normalized;
+        }
+
+        normalized.trim_end_matches('\n').to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_source() {
+        assert_eq!(InputSource::parse("-"), InputSource::Stdin);
+        assert_eq!(
+            InputSource::parse("/tmp/inputs"),
+            InputSource::Dir(PathBuf::from("/tmp/inputs"))
+        );
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(FileHandler::normalize("a\r\nb\r\n\r\n", true), "a\nb");
+        assert_eq!(FileHandler::normalize("a\r\nb\r\n", false), "a\nb\n");
+        assert_eq!(FileHandler::normalize("a\n\nb\n", true), "a\n\nb");
+        assert_eq!(FileHandler::normalize("  [D]\n", true), "  [D]");
+    }
+
+    #[test]
+    fn test_read() {
+        let source = InputSource::Dir(PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/src")));
+        assert!(FileHandler::read(&source, 2015, 1).is_ok());
 
-        contents
+        let source = InputSource::Dir(PathBuf::from("/nowhere"));
+        let error = FileHandler::read(&source, 2023, 5).unwrap_err();
+        assert_eq!((error.year, error.day), (2023, 5));
+        assert!(error.to_string().starts_with(
+            "cannot read the input of Y2023 Day 05 from `/nowhere/Y2023/inputs/day_05_1.txt`"
+        ));
     }
 }
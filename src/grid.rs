@@ -0,0 +1,317 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::error::AocError;
+use crate::point::Direction;
+
+/// Offsets of the 4 orthogonal neighbours: up, right, down, left.
+pub const NEIGHBOURS_4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets of the 8 neighbours, clockwise from up.
+pub const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// A dense 2D grid stored row by row. Coordinates are `(x, y)` with `x` the column
+/// and `y` the row, `(0, 0)` being the top left corner.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Grid<T> {
+        assert_eq!(cells.len(), width * height, "cells do not fill the grid");
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Builds a grid from the lines of a puzzle, one cell per character. Trailing blank lines
+    /// are ignored, rows of different lengths and characters `cell` rejects are parse errors.
+    pub fn parse(
+        lines: &[&str],
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>, AocError> {
+        let height = lines.len() - lines.iter().rev().take_while(|l| l.is_empty()).count();
+        let width = lines.first().map_or(0, |line| line.chars().count());
+
+        let mut cells = Vec::with_capacity(width * height);
+        for (index, line) in lines[..height].iter().enumerate() {
+            if line.chars().count() != width {
+                return Err(AocError::parse(
+                    index,
+                    line,
+                    format!("expected a row of {} cells", width),
+                ));
+            }
+
+            for c in line.chars() {
+                let value = cell(c).ok_or_else(|| {
+                    AocError::parse(index, line, format!("unexpected cell `{}`", c))
+                })?;
+                cells.push(value);
+            }
+        }
+
+        Ok(Grid::new(width, height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (x, y): (usize, usize)) -> Option<&T> {
+        if x < self.width && y < self.height {
+            Some(&self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, (x, y): (usize, usize)) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            Some(&mut self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// Moves `(dx, dy)` away from `(x, y)`, `None` if that leaves the grid.
+    pub fn offset(
+        &self,
+        (x, y): (usize, usize),
+        (dx, dy): (isize, isize),
+    ) -> Option<(usize, usize)> {
+        let x = x.checked_add_signed(dx)?;
+        let y = y.checked_add_signed(dy)?;
+        if x < self.width && y < self.height {
+            Some((x, y))
+        } else {
+            None
+        }
+    }
+
+    pub fn step(&self, coord: (usize, usize), direction: Direction) -> Option<(usize, usize)> {
+        self.offset(coord, direction.delta())
+    }
+
+    pub fn neighbours_4(&self, coord: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        NEIGHBOURS_4
+            .iter()
+            .filter_map(move |delta| self.offset(coord, *delta))
+    }
+
+    pub fn neighbours_8(&self, coord: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        NEIGHBOURS_8
+            .iter()
+            .filter_map(move |delta| self.offset(coord, *delta))
+    }
+
+    /// The coordinates from `start` (included) stepping by `delta` until the edge of the grid.
+    pub fn ray(
+        &self,
+        start: (usize, usize),
+        delta: (isize, isize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        std::iter::successors(self.get(start).map(|_| start), move |coord| {
+            self.offset(*coord, delta)
+        })
+    }
+
+    pub fn row(&self, y: usize) -> impl Iterator<Item = &T> + '_ {
+        self.cells[y * self.width..(y + 1) * self.width].iter()
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> + '_ {
+        self.ray((x, 0), (0, 1)).map(move |coord| &self[coord])
+    }
+
+    /// The cells from `start` going down to the right.
+    pub fn diagonal(&self, start: (usize, usize)) -> impl Iterator<Item = &T> + '_ {
+        self.ray(start, (1, 1)).map(move |coord| &self[coord])
+    }
+
+    /// The cells from `start` going down to the left.
+    pub fn anti_diagonal(&self, start: (usize, usize)) -> impl Iterator<Item = &T> + '_ {
+        self.ray(start, (-1, 1)).map(move |coord| &self[coord])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Every coordinate, row by row.
+    pub fn coordinates(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        self.coordinates().zip(self.cells.iter())
+    }
+
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        self.iter()
+            .find(|(_, value)| predicate(value))
+            .map(|(coord, _)| coord)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T> {
+        Grid::new(width, height, vec![value; width * height])
+    }
+
+    fn rebuild(
+        &self,
+        width: usize,
+        height: usize,
+        source: impl Fn(usize, usize) -> (usize, usize),
+    ) -> Grid<T> {
+        let mut cells = Vec::with_capacity(self.cells.len());
+        for y in 0..height {
+            for x in 0..width {
+                cells.push(self[source(x, y)].clone());
+            }
+        }
+
+        Grid::new(width, height, cells)
+    }
+
+    pub fn transpose(&self) -> Grid<T> {
+        self.rebuild(self.height, self.width, |x, y| (y, x))
+    }
+
+    pub fn rotate_clockwise(&self) -> Grid<T> {
+        let height = self.height;
+        self.rebuild(self.height, self.width, |x, y| (y, height - 1 - x))
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Grid<T> {
+        let width = self.width;
+        self.rebuild(self.height, self.width, |x, y| (width - 1 - y, x))
+    }
+
+    /// Mirrors the grid left to right.
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        let width = self.width;
+        self.rebuild(self.width, self.height, |x, y| (width - 1 - x, y))
+    }
+
+    /// Mirrors the grid top to bottom.
+    pub fn flip_vertical(&self) -> Grid<T> {
+        let height = self.height;
+        self.rebuild(self.width, self.height, |x, y| (x, height - 1 - y))
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coord: (usize, usize)) -> &T {
+        self.get(coord)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", coord))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, coord: (usize, usize)) -> &mut T {
+        self.get_mut(coord)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", coord))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for value in row {
+                write!(f, "{}", value)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Grid<char> {
+        Grid::parse(&["abc", "def", ""], Some).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = sample();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 'f');
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.to_string(), "abc\ndef");
+
+        let digits = Grid::parse(&["12", "34"], |c| c.to_digit(10)).unwrap();
+        assert_eq!(digits.row(1).sum::<u32>(), 7);
+
+        assert!(matches!(
+            Grid::parse(&["12", "3"], |c| c.to_digit(10)),
+            Err(AocError::Parse { line: 2, .. })
+        ));
+        assert!(matches!(
+            Grid::parse(&["1x"], |c| c.to_digit(10)),
+            Err(AocError::Parse { line: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = sample();
+        let result: Vec<(usize, usize)> = grid.neighbours_4((0, 0)).collect();
+        assert_eq!(result, vec![(1, 0), (0, 1)]);
+
+        let result: Vec<(usize, usize)> = grid.neighbours_8((1, 0)).collect();
+        assert_eq!(result, vec![(2, 0), (2, 1), (1, 1), (0, 1), (0, 0)]);
+    }
+
+    #[test]
+    fn test_iterators() {
+        let grid = sample();
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(grid.diagonal((0, 0)).collect::<String>(), "ae");
+        assert_eq!(grid.anti_diagonal((2, 0)).collect::<String>(), "ce");
+        assert_eq!(grid.ray((2, 1), (-1, 0)).count(), 3);
+        assert_eq!(grid.position(|c| *c == 'e'), Some((1, 1)));
+    }
+
+    #[test]
+    fn test_transform() {
+        let grid = sample();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.flip_horizontal().to_string(), "cba\nfed");
+        assert_eq!(grid.flip_vertical().to_string(), "def\nabc");
+    }
+}
@@ -0,0 +1,296 @@
+use std::fmt;
+
+/// The integers `start..end`, `end` excluded. Empty when `start >= end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    pub fn new(start: i64, end: i64) -> Interval {
+        Interval { start, end }
+    }
+
+    pub fn with_length(start: i64, length: i64) -> Interval {
+        Interval::new(start, start + length)
+    }
+
+    /// `start..=end`, for puzzles that give both bounds included.
+    pub fn inclusive(start: i64, end: i64) -> Interval {
+        Interval::new(start, end + 1)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    pub fn len(&self) -> u64 {
+        if self.is_empty() {
+            0
+        } else {
+            self.end.abs_diff(self.start)
+        }
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    pub fn intersect(&self, other: &Interval) -> Option<Interval> {
+        let interval = Interval::new(self.start.max(other.start), self.end.min(other.end));
+        if interval.is_empty() {
+            None
+        } else {
+            Some(interval)
+        }
+    }
+
+    /// The values below `value` and the values from `value` on.
+    pub fn split_at(&self, value: i64) -> (Option<Interval>, Option<Interval>) {
+        let below = Interval::new(self.start, self.end.min(value));
+        let above = Interval::new(self.start.max(value), self.end);
+        (
+            Some(below).filter(|interval| !interval.is_empty()),
+            Some(above).filter(|interval| !interval.is_empty()),
+        )
+    }
+
+    pub fn shift(&self, offset: i64) -> Interval {
+        Interval::new(self.start + offset, self.end + offset)
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}..{}", self.start, self.end)
+    }
+}
+
+/// A set of integers stored as sorted, disjoint and non-touching intervals.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub fn new() -> IntervalSet {
+        IntervalSet::default()
+    }
+
+    pub fn insert(&mut self, interval: Interval) {
+        if interval.is_empty() {
+            return;
+        }
+
+        let mut merged = interval;
+        let mut intervals = Vec::with_capacity(self.intervals.len() + 1);
+        for current in self.intervals.drain(..) {
+            if current.end < merged.start || merged.end < current.start {
+                intervals.push(current);
+            } else {
+                merged =
+                    Interval::new(merged.start.min(current.start), merged.end.max(current.end));
+            }
+        }
+
+        let position = intervals.partition_point(|current| current.start < merged.start);
+        intervals.insert(position, merged);
+        self.intervals = intervals;
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        let mut result = self.clone();
+        other.iter().for_each(|interval| result.insert(interval));
+        result
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        self.iter()
+            .flat_map(|interval| other.iter().filter_map(move |o| interval.intersect(&o)))
+            .collect()
+    }
+
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut remaining = self.intervals.clone();
+        for removed in other.iter() {
+            remaining = remaining
+                .iter()
+                .flat_map(|interval| {
+                    let (below, _) = interval.split_at(removed.start);
+                    let (_, above) = interval.split_at(removed.end);
+                    [below, above]
+                })
+                .flatten()
+                .collect();
+        }
+
+        remaining.into_iter().collect()
+    }
+
+    /// The values below `value` and the values from `value` on.
+    pub fn split_at(&self, value: i64) -> (IntervalSet, IntervalSet) {
+        let mut below = IntervalSet::new();
+        let mut above = IntervalSet::new();
+        for interval in self.iter() {
+            let (low, high) = interval.split_at(value);
+            low.into_iter().for_each(|interval| below.insert(interval));
+            high.into_iter().for_each(|interval| above.insert(interval));
+        }
+
+        (below, above)
+    }
+
+    pub fn shift(&self, offset: i64) -> IntervalSet {
+        IntervalSet {
+            intervals: self.iter().map(|interval| interval.shift(offset)).collect(),
+        }
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.iter().any(|interval| interval.contains(value))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// How many integers are in the set.
+    pub fn len(&self) -> u64 {
+        self.iter().map(|interval| interval.len()).sum()
+    }
+
+    pub fn min(&self) -> Option<i64> {
+        self.intervals.first().map(|interval| interval.start)
+    }
+
+    pub fn max(&self) -> Option<i64> {
+        self.intervals.last().map(|interval| interval.end - 1)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Interval> + '_ {
+        self.intervals.iter().copied()
+    }
+}
+
+impl From<Interval> for IntervalSet {
+    fn from(interval: Interval) -> IntervalSet {
+        let mut set = IntervalSet::new();
+        set.insert(interval);
+        set
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> IntervalSet {
+        let mut set = IntervalSet::new();
+        iter.into_iter().for_each(|interval| set.insert(interval));
+        set
+    }
+}
+
+/// A piecewise function that shifts each source interval by its own offset and leaves the
+/// values outside of every source interval unchanged. Source intervals must not overlap.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RangeMapper {
+    pieces: Vec<(Interval, i64)>,
+}
+
+impl RangeMapper {
+    pub fn new() -> RangeMapper {
+        RangeMapper::default()
+    }
+
+    /// Maps `source` onto the interval of the same length starting at `destination`.
+    pub fn add(&mut self, source: Interval, destination: i64) {
+        self.pieces.push((source, destination - source.start));
+    }
+
+    pub fn map(&self, value: i64) -> i64 {
+        self.pieces
+            .iter()
+            .find(|(source, _)| source.contains(value))
+            .map_or(value, |(_, offset)| value + offset)
+    }
+
+    pub fn map_set(&self, values: &IntervalSet) -> IntervalSet {
+        let mut unmapped = values.clone();
+        let mut result = IntervalSet::new();
+        for (source, offset) in self.pieces.iter() {
+            let covered = unmapped.intersection(&IntervalSet::from(*source));
+            result = result.union(&covered.shift(*offset));
+            unmapped = unmapped.difference(&covered);
+        }
+
+        result.union(&unmapped)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interval() {
+        let interval = Interval::new(2, 8);
+        assert_eq!(interval.len(), 6);
+        assert!(interval.contains(2) && !interval.contains(8));
+        assert_eq!(
+            interval.intersect(&Interval::new(6, 10)),
+            Some(Interval::new(6, 8))
+        );
+        assert_eq!(interval.intersect(&Interval::new(8, 10)), None);
+        assert_eq!(
+            interval.split_at(5),
+            (Some(Interval::new(2, 5)), Some(Interval::new(5, 8)))
+        );
+        assert_eq!(interval.split_at(2), (None, Some(interval)));
+        assert_eq!(interval.shift(-2), Interval::new(0, 6));
+        assert_eq!(Interval::inclusive(1, 4000).len(), 4000);
+    }
+
+    #[test]
+    fn test_interval_set() {
+        let set: IntervalSet = [
+            Interval::new(10, 20),
+            Interval::new(0, 5),
+            Interval::new(5, 7),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(
+            set.iter().collect::<Vec<_>>(),
+            vec![Interval::new(0, 7), Interval::new(10, 20)]
+        );
+        assert_eq!(set.len(), 17);
+
+        let other = IntervalSet::from(Interval::new(3, 12));
+        assert_eq!(set.union(&other), IntervalSet::from(Interval::new(0, 20)));
+        assert_eq!(set.intersection(&other).len(), 6);
+        assert_eq!(
+            set.difference(&other).iter().collect::<Vec<_>>(),
+            vec![Interval::new(0, 3), Interval::new(12, 20)]
+        );
+
+        let (below, above) = set.split_at(15);
+        assert_eq!(below.max(), Some(14));
+        assert_eq!(above.min(), Some(15));
+        assert_eq!(set.shift(5).min(), Some(5));
+    }
+
+    #[test]
+    fn test_range_mapper() {
+        let mut mapper = RangeMapper::new();
+        mapper.add(Interval::with_length(98, 2), 50);
+        mapper.add(Interval::with_length(50, 48), 52);
+        assert_eq!(mapper.map(79), 81);
+        assert_eq!(mapper.map(99), 51);
+        assert_eq!(mapper.map(10), 10);
+
+        let mapped = mapper.map_set(&IntervalSet::from(Interval::new(90, 110)));
+        assert_eq!(
+            mapped.iter().collect::<Vec<_>>(),
+            vec![Interval::new(50, 52), Interval::new(92, 110)]
+        );
+    }
+}
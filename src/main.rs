@@ -1,72 +1,249 @@
+pub mod cli;
+pub mod cycle;
+pub mod error;
 pub mod file_handler;
+pub mod grid;
+pub mod interval;
+pub mod output;
+pub mod parsing;
+pub mod point;
+pub mod registry;
+pub mod search;
+pub mod solution;
+pub mod verify;
 
 pub mod Y2015;
 pub mod Y2022;
 pub mod Y2023;
 pub mod Y2024;
 
-fn main() {
-    run_2024();
+use std::panic;
+use std::process::ExitCode;
+use std::time::Instant;
+
+use rayon::prelude::*;
+
+use cli::{Command, RunOptions};
+use file_handler::InputSource;
+use output::{Format, Output, Status};
+use registry::DayEntry;
+use solution::SolutionReport;
+use verify::{Answers, Verdict};
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    match cli::parse_args(&args) {
+        Ok(Command::Run(options)) => run(&options),
+        Ok(Command::Verify(options)) => verify(&options),
+        Ok(Command::Examples(options)) => examples(&options),
+        Ok(Command::Help) => {
+            println!("{}", cli::USAGE);
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("error: {}\n\n{}", error, cli::USAGE);
+            ExitCode::from(2)
+        }
+    }
 }
 
-fn run_2024() {
-    // print_result(crate::Y2024::days::day_01::Day01::run());
-    // print_result(crate::Y2024::days::day_02::Day02::run());
-    print_result(crate::Y2024::days::day_03::Day03::run());
+fn run(options: &RunOptions) -> ExitCode {
+    let entries = match cli::resolve(options) {
+        Ok(entries) => entries,
+        Err(error) => {
+            eprintln!("error: {}", error);
+            return ExitCode::from(2);
+        }
+    };
+
+    let source = cli::input_source(options);
+    let params: Vec<(&str, i64)> = options
+        .params
+        .iter()
+        .map(|(name, value)| (name.as_str(), *value))
+        .collect();
+    let mut output = Output::new(options.format, options.part);
+    let start = Instant::now();
+    let mut outcomes = vec![];
+    if options.parallel {
+        // The days share the thread pool, so their durations are only indicative.
+        let results: Vec<_> = entries
+            .par_iter()
+            .map(|entry| run_entry(entry, &source, options.part, &params))
+            .collect();
+        for (entry, outcome) in entries.iter().zip(results) {
+            output.add(entry.year, entry.day, &outcome);
+            outcomes.push(outcome);
+        }
+    } else {
+        for entry in entries.iter() {
+            let outcome = run_entry(entry, &source, options.part, &params);
+            output.add(entry.year, entry.day, &outcome);
+            outcomes.push(outcome);
+        }
+    }
+    output.finish();
+
+    let reports: Vec<SolutionReport> = outcomes.iter().flatten().cloned().collect();
+    if options.format == Format::Text && entries.len() > 1 {
+        print!("{}", output::summary(&reports, start.elapsed()));
+    }
+
+    if reports.len() == outcomes.len() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
 }
 
-fn run_2023() {
-    // print_result(crate::Y2023::days::day_01::Day01::run());
-    // print_result(crate::Y2023::days::day_02::Day02::run());
-    // print_result(crate::Y2023::days::day_03::Day03::run());
-    // print_result(crate::Y2023::days::day_04::Day04::run());
-    // print_result(crate::Y2023::days::day_05::Day05::run());
-    // print_result(crate::Y2023::days::day_06::Day06::run());
-    // print_result(crate::Y2023::days::day_07::Day07::run());
-    // print_result(crate::Y2023::days::day_08::Day08::run());
-    // print_result(crate::Y2023::days::day_09::Day09::run());
-    // print_result(crate::Y2023::days::day_10::Day10::run());
-    // print_result(crate::Y2023::days::day_11::Day11::run());
-    // print_result(crate::Y2023::days::day_12::Day12::run());
-    // print_result(crate::Y2023::days::day_13::Day13::run());
-    // print_result(crate::Y2023::days::day_14::Day14::run());
-    // print_result(crate::Y2023::days::day_15::Day15::run());
-    // print_result(crate::Y2023::days::day_16::Day16::run());
-    // print_result(crate::Y2023::days::day_17::Day17::run());
-    // print_result(crate::Y2023::days::day_18::Day18::run());
-    // print_result(crate::Y2023::days::day_19::Day19::run());
-    // print_result(crate::Y2023::days::day_20::Day20::run());
-    // print_result(crate::Y2023::days::day_21::Day21::run());
-    // print_result(crate::Y2023::days::day_22::Day22::run());
+/// Runs one day, only `part` when one is selected, reporting a failure or a panic so the
+/// remaining days still run.
+fn run_entry(
+    entry: &DayEntry,
+    source: &InputSource,
+    part: Option<u8>,
+    params: &[(&str, i64)],
+) -> Result<SolutionReport, Status> {
+    match panic::catch_unwind(|| (entry.run)(source, part, params)) {
+        Ok(Ok(report)) => Ok(report),
+        Ok(Err(error)) => {
+            eprintln!("Y{} Day {:02} failed: {}\n", entry.year, entry.day, error);
+            Err(Status::Failed)
+        }
+        Err(_) => {
+            eprintln!("Y{} Day {:02} panicked\n", entry.year, entry.day);
+            Err(Status::Panicked)
+        }
+    }
 }
 
-fn print_result(result: (String, String, String, u128, u128)) {
-    print!(
-        "{}\nPart_1: {}\nPart_2: {}\n\nDuration_1(ns): {}\nDuration_2(ns): {}\n-----\n\n",
-        result.0, result.1, result.2, result.3, result.4
+fn verify(options: &RunOptions) -> ExitCode {
+    let entries = match cli::resolve(options) {
+        Ok(entries) => entries,
+        Err(error) => {
+            eprintln!("error: {}", error);
+            return ExitCode::from(2);
+        }
+    };
+
+    let source = cli::input_source(options);
+    let dir = match &source {
+        InputSource::Dir(dir) => dir,
+        InputSource::Stdin => unreachable!("verify does not accept stdin"),
+    };
+
+    let parts: Vec<u8> = match options.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    let (mut passed, mut failed, mut missing, mut errors) = (0, 0, 0, 0);
+    let mut answers = Answers::default();
+    let mut current_year = None;
+    for entry in entries {
+        if current_year != Some(entry.year) {
+            println!("===== Y{} =====\n", entry.year);
+            current_year = Some(entry.year);
+            answers = match Answers::load(dir, entry.year) {
+                Ok(answers) => answers,
+                Err(error) => {
+                    eprintln!("error: Y{} answers: {}", entry.year, error);
+                    return ExitCode::from(2);
+                }
+            };
+        }
+
+        if !verify::has_input(dir, entry.year, entry.day) {
+            for part in parts.iter() {
+                missing += 1;
+                println!("Day_{:02} part {}: missing input", entry.day, part);
+            }
+            continue;
+        }
+
+        let report = match run_entry(entry, &source, options.part, &[]) {
+            Ok(report) => report,
+            Err(_) => {
+                errors += 1;
+                continue;
+            }
+        };
+
+        let verdicts = verify::check(&answers, &report);
+        for part in parts.iter() {
+            match &verdicts[*part as usize - 1] {
+                Verdict::Pass => {
+                    passed += 1;
+                    println!("{} part {}: pass", report.name, part);
+                }
+                Verdict::Fail { expected, actual } => {
+                    failed += 1;
+                    println!(
+                        "{} part {}: FAIL, expected {} but got {}",
+                        report.name, part, expected, actual
+                    );
+                }
+                Verdict::Missing => {
+                    missing += 1;
+                    println!("{} part {}: missing", report.name, part);
+                }
+            }
+        }
+    }
+
+    println!(
+        "\npassed: {}, failed: {}, missing: {}, errors: {}",
+        passed, failed, missing, errors
     );
+    if failed > 0 || errors > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
 }
 
-fn run_2022() {
-    // print_result(crate::Y2015::days::day_01::Day01::run());
-    // print_result(crate::Y2015::days::day_02::Day02::run());
-    // print_result(crate::Y2015::days::day_03::Day03::run());
-
-    // print_result(crate::Y2022::days::day_01::Day01::run());
-    // print_result(crate::Y2022::days::day_02::Day02::run());
-    // print_result(crate::Y2022::days::day_03::Day03::run());
-    // print_result(crate::Y2022::days::day_04::Day04::run());
-    // print_result(crate::Y2022::days::day_05::Day05::run());
-    // print_result(crate::Y2022::days::day_06::Day06::run());
-    // print_result(crate::Y2022::days::day_07::Day07::run());
-    // print_result(crate::Y2022::days::day_08::Day08::run());
-    // print_result(crate::Y2022::days::day_09::Day09::run());
-    // print_result(crate::Y2022::days::day_10::Day10::run());
-    // print_result(crate::Y2022::days::day_11::Day11::run());
-    // print_result(crate::Y2022::days::day_12::Day12::run());
-    // print_result(crate::Y2022::days::day_13::Day13::run());
-    // print_result(crate::Y2022::days::day_14::Day14::run());
-    // print_result(crate::Y2022::days::day_15::Day15::run());
-    // print_result(crate::Y2022::days::day_16::Day16::run());
-    // print_result(crate::Y2022::days::day_17::Day17::run());
+fn examples(options: &RunOptions) -> ExitCode {
+    let entries = match cli::resolve(options) {
+        Ok(entries) => entries,
+        Err(error) => {
+            eprintln!("error: {}", error);
+            return ExitCode::from(2);
+        }
+    };
+
+    let (mut passed, mut failed) = (0, 0);
+    let mut current_year = None;
+    for entry in entries {
+        if current_year != Some(entry.year) {
+            println!("===== Y{} =====\n", entry.year);
+            current_year = Some(entry.year);
+        }
+
+        let reports: Vec<_> = match panic::catch_unwind(entry.examples) {
+            Ok(reports) => reports,
+            Err(_) => {
+                eprintln!("Y{} Day {:02} panicked\n", entry.year, entry.day);
+                failed += 1;
+                continue;
+            }
+        };
+        for report in reports.iter() {
+            if options.part.is_some_and(|part| part != report.example.part) {
+                continue;
+            }
+            if report.passed() {
+                passed += 1;
+            } else {
+                failed += 1;
+            }
+            println!("Day_{:02} {}", entry.day, report);
+        }
+    }
+
+    println!("\npassed: {}, failed: {}", passed, failed);
+    if failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
 }
@@ -0,0 +1,449 @@
+use std::time::Duration;
+
+use crate::solution::SolutionReport;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+    Table,
+}
+
+impl Format {
+    pub fn parse(value: &str) -> Option<Format> {
+        match value {
+            "text" => Some(Format::Text),
+            "json" => Some(Format::Json),
+            "csv" => Some(Format::Csv),
+            "table" => Some(Format::Table),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Status {
+    Ok,
+    Failed,
+    Panicked,
+}
+
+impl Status {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Status::Ok => "ok",
+            Status::Failed => "failed",
+            Status::Panicked => "panicked",
+        }
+    }
+}
+
+/// One part of one day, which is what the machine-readable formats are made of.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Row {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub parse_ns: u128,
+    pub solve_ns: u128,
+    pub status: Status,
+}
+
+impl Row {
+    /// A day that did not produce a report still gets a row per part, with its status.
+    pub fn of(year: u16, day: u8, part: u8, outcome: &Result<SolutionReport, Status>) -> Row {
+        let (answer, parse, solve, status) = match outcome {
+            Ok(report) if part == 1 => (
+                report.part_one.clone(),
+                report.duration_parse,
+                report.duration_one,
+                Status::Ok,
+            ),
+            Ok(report) => (
+                report.part_two.clone(),
+                report.duration_parse,
+                report.duration_two,
+                Status::Ok,
+            ),
+            Err(status) => (String::new(), Duration::ZERO, Duration::ZERO, *status),
+        };
+
+        Row {
+            year,
+            day,
+            part,
+            answer,
+            parse_ns: parse.as_nanos(),
+            solve_ns: solve.as_nanos(),
+            status,
+        }
+    }
+}
+
+/// Prints the results of a run in the selected format. Text and CSV are printed as the days
+/// finish, JSON and the table need every row and are printed by `finish`.
+pub struct Output {
+    format: Format,
+    parts: Vec<u8>,
+    rows: Vec<Row>,
+    current_year: Option<u16>,
+}
+
+impl Output {
+    pub fn new(format: Format, part: Option<u8>) -> Output {
+        if format == Format::Csv {
+            println!("{}", CSV_HEADER);
+        }
+
+        Output {
+            format,
+            parts: match part {
+                Some(part) => vec![part],
+                None => vec![1, 2],
+            },
+            rows: vec![],
+            current_year: None,
+        }
+    }
+
+    pub fn add(&mut self, year: u16, day: u8, outcome: &Result<SolutionReport, Status>) {
+        if self.format == Format::Text {
+            if self.current_year != Some(year) {
+                println!("===== Y{} =====\n", year);
+                self.current_year = Some(year);
+            }
+            if let Ok(report) = outcome {
+                print_report(report, &self.parts);
+            }
+            return;
+        }
+
+        for part in self.parts.iter() {
+            let row = Row::of(year, day, *part, outcome);
+            match self.format {
+                Format::Csv => println!("{}", csv_row(&row)),
+                _ => self.rows.push(row),
+            }
+        }
+    }
+
+    pub fn finish(self) {
+        match self.format {
+            Format::Json => println!("{}", json(&self.rows)),
+            Format::Table => print!("{}", table(&self.rows)),
+            Format::Text | Format::Csv => {}
+        }
+    }
+}
+
+fn print_report(report: &SolutionReport, parts: &[u8]) {
+    match parts {
+        [1] => println!(
+            "{}\nPart_1: {}\n\nDuration_parse(ns): {}\nDuration_1(ns): {}\n-----\n",
+            report.name,
+            report.part_one,
+            report.duration_parse.as_nanos(),
+            report.duration_one.as_nanos()
+        ),
+        [2] => println!(
+            "{}\nPart_2: {}\n\nDuration_parse(ns): {}\nDuration_2(ns): {}\n-----\n",
+            report.name,
+            report.part_two,
+            report.duration_parse.as_nanos(),
+            report.duration_two.as_nanos()
+        ),
+        _ => println!("{}", report),
+    }
+}
+
+const CSV_HEADER: &str = "year,day,part,answer,parse_ns,solve_ns,status";
+
+fn csv_row(row: &Row) -> String {
+    let answer = if row.answer.contains(|c| c == ',' || c == '"' || c == '\n') {
+        format!("\"{}\"", row.answer.replace('"', "\"\""))
+    } else {
+        row.answer.clone()
+    };
+
+    format!(
+        "{},{},{},{},{},{},{}",
+        row.year,
+        row.day,
+        row.part,
+        answer,
+        row.parse_ns,
+        row.solve_ns,
+        row.status.as_str()
+    )
+}
+
+fn json_string(text: &str) -> String {
+    let mut result = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            c if (c as u32) < 0x20 => result.push_str(&format!("\\u{:04x}", c as u32)),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
+
+fn json(rows: &[Row]) -> String {
+    let objects: Vec<String> = rows
+        .iter()
+        .map(|row| {
+            format!(
+                "  {{\"year\": {}, \"day\": {}, \"part\": {}, \"answer\": {}, \"parse_ns\": {}, \"solve_ns\": {}, \"status\": \"{}\"}}",
+                row.year,
+                row.day,
+                row.part,
+                json_string(&row.answer),
+                row.parse_ns,
+                row.solve_ns,
+                row.status.as_str()
+            )
+        })
+        .collect();
+
+    format!("[\n{}\n]", objects.join(",\n"))
+}
+
+fn millis(nanos: u128) -> String {
+    format!("{:.3}", nanos as f64 / 1_000_000.0)
+}
+
+/// One aligned table per year with a line per part and the total time of the year.
+/// Parsing is shared by both parts, so it only counts once per day in the total.
+fn table(rows: &[Row]) -> String {
+    let header = ["Day", "Part", "Answer", "Parse(ms)", "Solve(ms)", "Status"];
+    let mut result = String::new();
+
+    let mut start = 0;
+    while start < rows.len() {
+        let year = rows[start].year;
+        let end = start
+            + rows[start..]
+                .iter()
+                .take_while(|row| row.year == year)
+                .count();
+
+        let mut lines: Vec<[String; 6]> = vec![header.map(|title| title.to_owned())];
+        let (mut total_parse, mut total_solve) = (0, 0);
+        for (index, row) in rows[start..end].iter().enumerate() {
+            if index == 0 || rows[start + index - 1].day != row.day {
+                total_parse += row.parse_ns;
+            }
+            total_solve += row.solve_ns;
+            lines.push([
+                format!("{:02}", row.day),
+                row.part.to_string(),
+                row.answer.replace('\n', " "),
+                millis(row.parse_ns),
+                millis(row.solve_ns),
+                row.status.as_str().to_owned(),
+            ]);
+        }
+        lines.push([
+            "Total".to_owned(),
+            String::new(),
+            String::new(),
+            millis(total_parse),
+            millis(total_solve),
+            String::new(),
+        ]);
+
+        let mut widths = [0; 6];
+        for line in lines.iter() {
+            for (column, value) in line.iter().enumerate() {
+                widths[column] = widths[column].max(value.len());
+            }
+        }
+
+        result.push_str(&format!("===== Y{} =====\n", year));
+        for line in lines.iter() {
+            let cells: Vec<String> = line
+                .iter()
+                .enumerate()
+                .map(|(column, value)| match column {
+                    3 | 4 => format!("{:>width$}", value, width = widths[column]),
+                    _ => format!("{:<width$}", value, width = widths[column]),
+                })
+                .collect();
+            result.push_str(cells.join("  ").trim_end());
+            result.push('\n');
+        }
+        result.push('\n');
+
+        start = end;
+    }
+
+    result
+}
+
+const SLOWEST_DAYS: usize = 10;
+
+fn total(report: &SolutionReport) -> Duration {
+    report.duration_parse + report.duration_one + report.duration_two
+}
+
+/// The slowest days of a run and the total time of every year, `wall` being how long
+/// the whole run took.
+pub fn summary(reports: &[SolutionReport], wall: Duration) -> String {
+    let mut slowest: Vec<&SolutionReport> = reports.iter().collect();
+    slowest.sort_by_key(|report| std::cmp::Reverse(total(report)));
+
+    let mut result = String::from("===== Summary =====\n\nSlowest days:\n");
+    for report in slowest.iter().take(SLOWEST_DAYS) {
+        result.push_str(&format!(
+            "  Y{} {}  {:>12} ms\n",
+            report.year,
+            report.name,
+            millis(total(report).as_nanos())
+        ));
+    }
+
+    result.push_str("\nTotal per year:\n");
+    let mut years: Vec<u16> = reports.iter().map(|report| report.year).collect();
+    years.dedup();
+    for year in years {
+        let days: Vec<&SolutionReport> = reports
+            .iter()
+            .filter(|report| report.year == year)
+            .collect();
+        let time: Duration = days.iter().map(|report| total(report)).sum();
+        result.push_str(&format!(
+            "  Y{}  {:>12} ms  ({} days)\n",
+            year,
+            millis(time.as_nanos()),
+            days.len()
+        ));
+    }
+
+    result.push_str(&format!("\nWall time: {} ms\n", millis(wall.as_nanos())));
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(day: u8, part: u8, answer: &str, status: Status) -> Row {
+        Row {
+            year: 2023,
+            day,
+            part,
+            answer: answer.to_owned(),
+            parse_ns: 1_000_000,
+            solve_ns: 2_500_000,
+            status,
+        }
+    }
+
+    #[test]
+    fn test_row_of() {
+        let report = SolutionReport {
+            year: 2022,
+            day: 5,
+            name: "Day_05".to_owned(),
+            part_one: "CMZ".to_owned(),
+            part_two: "MCD".to_owned(),
+            duration_parse: Duration::from_nanos(10),
+            duration_one: Duration::from_nanos(20),
+            duration_two: Duration::from_nanos(30),
+        };
+        let result = Row::of(2022, 5, 2, &Ok(report));
+        assert_eq!(
+            (result.answer.as_str(), result.parse_ns, result.solve_ns),
+            ("MCD", 10, 30)
+        );
+
+        let result = Row::of(2022, 5, 1, &Err(Status::Panicked));
+        assert_eq!(
+            (result.answer.as_str(), result.status),
+            ("", Status::Panicked)
+        );
+    }
+
+    #[test]
+    fn test_csv() {
+        assert_eq!(
+            csv_row(&row(8, 1, "11567", Status::Ok)),
+            "2023,8,1,11567,1000000,2500000,ok"
+        );
+        assert_eq!(
+            csv_row(&row(8, 2, "a,\"b\"", Status::Ok)),
+            "2023,8,2,\"a,\"\"b\"\"\",1000000,2500000,ok"
+        );
+    }
+
+    #[test]
+    fn test_json() {
+        let rows = vec![
+            row(8, 1, "11567", Status::Ok),
+            row(9, 1, "", Status::Failed),
+        ];
+        assert_eq!(
+            json(&rows),
+            "[
+  {\"year\": 2023, \"day\": 8, \"part\": 1, \"answer\": \"11567\", \"parse_ns\": 1000000, \"solve_ns\": 2500000, \"status\": \"ok\"},
+  {\"year\": 2023, \"day\": 9, \"part\": 1, \"answer\": \"\", \"parse_ns\": 1000000, \"solve_ns\": 2500000, \"status\": \"failed\"}
+]"
+        );
+        assert_eq!(json_string("a\"b\\\n"), "\"a\\\"b\\\\\\n\"");
+    }
+
+    #[test]
+    fn test_summary() {
+        let report = |year: u16, day: u8, millis: u64| SolutionReport {
+            year,
+            day,
+            name: format!("Day_{:02}", day),
+            part_one: String::new(),
+            part_two: String::new(),
+            duration_parse: Duration::ZERO,
+            duration_one: Duration::from_millis(millis),
+            duration_two: Duration::ZERO,
+        };
+        let reports = vec![report(2022, 1, 2), report(2022, 2, 30), report(2023, 1, 5)];
+        assert_eq!(
+            summary(&reports, Duration::from_millis(20)),
+            "===== Summary =====
+
+Slowest days:
+  Y2022 Day_02        30.000 ms
+  Y2023 Day_01         5.000 ms
+  Y2022 Day_01         2.000 ms
+
+Total per year:
+  Y2022        32.000 ms  (2 days)
+  Y2023         5.000 ms  (1 days)
+
+Wall time: 20.000 ms
+"
+        );
+    }
+
+    #[test]
+    fn test_table() {
+        let rows = vec![
+            row(8, 1, "11567", Status::Ok),
+            row(8, 2, "9858474970153", Status::Ok),
+        ];
+        assert_eq!(
+            table(&rows),
+            "===== Y2023 =====
+Day    Part  Answer         Parse(ms)  Solve(ms)  Status
+08     1     11567              1.000      2.500  ok
+08     2     9858474970153      1.000      2.500  ok
+Total                           1.000      5.000
+
+"
+        );
+    }
+}
@@ -0,0 +1,152 @@
+use std::str::FromStr;
+
+/// Every integer in `line` in order, a `-` right before the digits making it negative unless
+/// it follows a digit, as in the range `1-3`. Numbers that do not fit in `T` are skipped.
+pub fn signed_integers<T: FromStr>(line: &str) -> Vec<T> {
+    let bytes = line.as_bytes();
+    let mut result = vec![];
+    let mut index = 0;
+    while index < bytes.len() {
+        let negative = bytes[index] == b'-'
+            && bytes.get(index + 1).is_some_and(u8::is_ascii_digit)
+            && (index == 0 || !bytes[index - 1].is_ascii_digit());
+        if !negative && !bytes[index].is_ascii_digit() {
+            index += 1;
+            continue;
+        }
+
+        let start = index;
+        index += 1;
+        while index < bytes.len() && bytes[index].is_ascii_digit() {
+            index += 1;
+        }
+
+        if let Ok(value) = line[start..index].parse::<T>() {
+            result.push(value);
+        }
+    }
+
+    result
+}
+
+/// The groups of lines separated by blank lines, blank lines themselves left out, each with
+/// the index of its first line for error messages.
+pub fn blocks(input: &str) -> Vec<(usize, Vec<&str>)> {
+    let mut result = vec![];
+    let mut block = vec![];
+    let mut first_index = 0;
+    for (index, line) in input.lines().enumerate() {
+        if line.trim().is_empty() {
+            if !block.is_empty() {
+                result.push((first_index, std::mem::take(&mut block)));
+            }
+        } else {
+            if block.is_empty() {
+                first_index = index;
+            }
+            block.push(line);
+        }
+    }
+
+    if !block.is_empty() {
+        result.push((first_index, block));
+    }
+
+    result
+}
+
+/// Splits `key: value` at the first `:`, both sides trimmed.
+pub fn key_value(line: &str) -> Option<(&str, &str)> {
+    let (key, value) = line.split_once(':')?;
+    Some((key.trim(), value.trim()))
+}
+
+/// The non-empty trimmed items of a `separator` separated list.
+pub fn list(text: &str, separator: char) -> Vec<&str> {
+    text.split(separator)
+        .map(|item| item.trim())
+        .filter(|item| !item.is_empty())
+        .collect()
+}
+
+/// Splits `a -> b, c` into `a` and the list `[b, c]`.
+pub fn arrow_list(line: &str) -> Option<(&str, Vec<&str>)> {
+    let (source, targets) = line.split_once("->")?;
+    Some((source.trim(), list(targets, ',')))
+}
+
+/// The texts between the literal `labels`, the line having to start with the first label,
+/// e.g. `["move ", " from ", " to "]` reads the three values of `move 1 from 2 to 3`.
+pub fn labelled<'a>(line: &'a str, labels: &[&str]) -> Option<Vec<&'a str>> {
+    let (first, rest) = labels.split_first()?;
+    let mut remaining = line.strip_prefix(first)?;
+    let mut result = vec![];
+    for label in rest.iter() {
+        let (value, next) = remaining.split_once(label)?;
+        result.push(value);
+        remaining = next;
+    }
+    result.push(remaining);
+
+    Some(result)
+}
+
+/// `labelled` with every value parsed, `None` if any of them does not parse.
+pub fn labelled_values<T: FromStr>(line: &str, labels: &[&str]) -> Option<Vec<T>> {
+    labelled(line, labels)?
+        .iter()
+        .map(|value| value.trim().parse::<T>().ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_signed_integers() {
+        let line = "Sensor at x=2, y=-18: closest beacon is at x=-2, y=15";
+        assert_eq!(signed_integers::<i32>(line), vec![2, -18, -2, 15]);
+        assert_eq!(signed_integers::<u8>("Card  12: 1-2 300"), vec![12, 1, 2]);
+        assert!(signed_integers::<i64>("no numbers - here").is_empty());
+    }
+
+    #[test]
+    fn test_blocks() {
+        let input = "a\nb\n\n\nc\n\nd\n";
+        assert_eq!(
+            blocks(input),
+            vec![(0, vec!["a", "b"]), (4, vec!["c"]), (6, vec!["d"])]
+        );
+        assert!(blocks("").is_empty());
+    }
+
+    #[test]
+    fn test_key_value_and_lists() {
+        assert_eq!(key_value("Game 1: 3 blue"), Some(("Game 1", "3 blue")));
+        assert_eq!(key_value("no separator"), None);
+        assert_eq!(list("3 blue, 4 red,", ','), vec!["3 blue", "4 red"]);
+        assert_eq!(
+            arrow_list("%a -> inv, con"),
+            Some(("%a", vec!["inv", "con"]))
+        );
+    }
+
+    #[test]
+    fn test_labelled() {
+        let labels = ["move ", " from ", " to "];
+        assert_eq!(
+            labelled("move 1 from 2 to 3", &labels),
+            Some(vec!["1", "2", "3"])
+        );
+        assert_eq!(
+            labelled_values::<usize>("move 1 from 2 to 3", &labels),
+            Some(vec![1, 2, 3])
+        );
+        assert_eq!(
+            labelled_values::<usize>("move x from 2 to 3", &labels),
+            None
+        );
+        assert_eq!(labelled("take 1 from 2 to 3", &labels), None);
+    }
+}
@@ -0,0 +1,268 @@
+use std::fmt;
+use std::ops::{Add, Sub};
+
+/// One of the 8 directions on a grid where `y` grows downwards, so `Up` decreases `y`.
+/// The variants are ordered clockwise from `Up`, the same order as `grid::NEIGHBOURS_8`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    /// Clockwise from `Up`.
+    pub const CARDINALS: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// Clockwise from `UpRight`.
+    pub const DIAGONALS: [Direction; 4] = [
+        Direction::UpRight,
+        Direction::DownRight,
+        Direction::DownLeft,
+        Direction::UpLeft,
+    ];
+
+    /// Clockwise from `Up`.
+    pub const ALL: [Direction; 8] = [
+        Direction::Up,
+        Direction::UpRight,
+        Direction::Right,
+        Direction::DownRight,
+        Direction::Down,
+        Direction::DownLeft,
+        Direction::Left,
+        Direction::UpLeft,
+    ];
+
+    /// Reads `U/D/L/R`, `^v<>` and `N/S/E/W`.
+    pub fn from_char(c: char) -> Option<Direction> {
+        match c {
+            'U' | '^' | 'N' => Some(Direction::Up),
+            'D' | 'v' | 'S' => Some(Direction::Down),
+            'L' | '<' | 'W' => Some(Direction::Left),
+            'R' | '>' | 'E' => Some(Direction::Right),
+            _ => None,
+        }
+    }
+
+    /// Reads the hex digit encoding where `0` to `3` are right, down, left and up.
+    pub fn from_hex_digit(c: char) -> Option<Direction> {
+        match c {
+            '0' => Some(Direction::Right),
+            '1' => Some(Direction::Down),
+            '2' => Some(Direction::Left),
+            '3' => Some(Direction::Up),
+            _ => None,
+        }
+    }
+
+    pub fn is_cardinal(self) -> bool {
+        Direction::CARDINALS.contains(&self)
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Direction::Left | Direction::Right)
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Direction::Up | Direction::Down)
+    }
+
+    pub fn turn_right(self) -> Direction {
+        Direction::ALL[(self.index() + 2) % 8]
+    }
+
+    pub fn turn_left(self) -> Direction {
+        Direction::ALL[(self.index() + 6) % 8]
+    }
+
+    pub fn turn_around(self) -> Direction {
+        Direction::ALL[(self.index() + 4) % 8]
+    }
+
+    /// The `(dx, dy)` of one step, as used by `Grid::offset`.
+    pub fn delta(self) -> (isize, isize) {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::UpRight => (1, -1),
+            Direction::Right => (1, 0),
+            Direction::DownRight => (1, 1),
+            Direction::Down => (0, 1),
+            Direction::DownLeft => (-1, 1),
+            Direction::Left => (-1, 0),
+            Direction::UpLeft => (-1, -1),
+        }
+    }
+
+    /// One step from `(x, y)` inside a `width` by `height` grid, `None` if that leaves it.
+    pub fn step_within(
+        self,
+        (x, y): (usize, usize),
+        width: usize,
+        height: usize,
+    ) -> Option<(usize, usize)> {
+        let (dx, dy) = self.delta();
+        let x = x.checked_add_signed(dx)?;
+        let y = y.checked_add_signed(dy)?;
+        if x < width && y < height {
+            Some((x, y))
+        } else {
+            None
+        }
+    }
+
+    pub fn to_char(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::UpRight => '/',
+            Direction::Right => '>',
+            Direction::DownRight => '\\',
+            Direction::Down => 'v',
+            Direction::DownLeft => '/',
+            Direction::Left => '<',
+            Direction::UpLeft => '\\',
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_char())
+    }
+}
+
+/// An unbounded point, for puzzles that wander off any fixed grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub fn new(x: i64, y: i64) -> Point {
+        Point { x, y }
+    }
+
+    pub fn step(self, direction: Direction) -> Point {
+        self.step_by(direction, 1)
+    }
+
+    pub fn step_by(self, direction: Direction, distance: i64) -> Point {
+        let (dx, dy) = direction.delta();
+        Point::new(self.x + dx as i64 * distance, self.y + dy as i64 * distance)
+    }
+
+    pub fn manhattan(self, other: Point) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    pub fn neighbours_4(self) -> impl Iterator<Item = Point> {
+        Direction::CARDINALS
+            .into_iter()
+            .map(move |direction| self.step(direction))
+    }
+
+    pub fn neighbours_8(self) -> impl Iterator<Item = Point> {
+        Direction::ALL
+            .into_iter()
+            .map(move |direction| self.step(direction))
+    }
+
+    /// The point as a grid coordinate, `None` outside of a `width` by `height` grid.
+    pub fn to_coord(self, width: usize, height: usize) -> Option<(usize, usize)> {
+        let x = usize::try_from(self.x).ok()?;
+        let y = usize::try_from(self.y).ok()?;
+        if x < width && y < height {
+            Some((x, y))
+        } else {
+            None
+        }
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((x, y): (usize, usize)) -> Point {
+        Point::new(x as i64, y as i64)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turn() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.turn_around(), Direction::Right);
+        assert_eq!(Direction::UpLeft.turn_right(), Direction::UpRight);
+        assert_eq!(Direction::DownRight.turn_around(), Direction::UpLeft);
+        assert!(Direction::CARDINALS.iter().all(|d| d.is_cardinal()));
+        assert!(Direction::DIAGONALS.iter().all(|d| !d.is_cardinal()));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Direction::from_char('U'), Some(Direction::Up));
+        assert_eq!(Direction::from_char('v'), Some(Direction::Down));
+        assert_eq!(Direction::from_char('W'), Some(Direction::Left));
+        assert_eq!(Direction::from_char('>'), Some(Direction::Right));
+        assert_eq!(Direction::from_char('x'), None);
+        assert_eq!(Direction::from_hex_digit('1'), Some(Direction::Down));
+        assert_eq!(Direction::from_hex_digit('4'), None);
+    }
+
+    #[test]
+    fn test_step() {
+        assert_eq!(Direction::Left.step_within((0, 3), 5, 5), None);
+        assert_eq!(Direction::Down.step_within((2, 4), 5, 5), None);
+        assert_eq!(Direction::UpRight.step_within((2, 4), 5, 5), Some((3, 3)));
+
+        let point = Point::ORIGIN
+            .step(Direction::Up)
+            .step_by(Direction::Right, 3);
+        assert_eq!(point, Point::new(3, -1));
+        assert_eq!(point.manhattan(Point::ORIGIN), 4);
+        assert_eq!(point.to_coord(5, 5), None);
+        assert_eq!(Point::new(3, 1).to_coord(5, 5), Some((3, 1)));
+        assert_eq!(Point::ORIGIN.neighbours_8().count(), 8);
+    }
+}
@@ -0,0 +1,174 @@
+use std::fmt;
+
+use crate::error::AocError;
+use crate::file_handler::InputSource;
+use crate::solution::{self, ExampleReport, Solution, SolutionReport, Timer};
+
+/// Solves a day, only the given part when there is one, with the given parameters.
+pub type RunFn = fn(&InputSource, Option<u8>, &[(&str, i64)]) -> Result<SolutionReport, AocError>;
+
+/// One implemented puzzle: which year/day it belongs to and how to run and benchmark it.
+pub struct DayEntry {
+    pub year: u16,
+    pub day: u8,
+    pub run: RunFn,
+    pub bench: fn(&str, &mut dyn Timer) -> Result<(), AocError>,
+    pub examples: fn() -> Vec<ExampleReport>,
+}
+
+impl DayEntry {
+    pub const fn of<S: Solution>() -> DayEntry {
+        DayEntry {
+            year: S::YEAR,
+            day: S::DAY,
+            run: solution::run::<S>,
+            bench: solution::bench::<S>,
+            examples: solution::run_examples::<S>,
+        }
+    }
+}
+
+/// Every `Solution` in the project. Keep it sorted by year then day.
+pub const DAYS: &[DayEntry] = &[
+    DayEntry::of::<crate::Y2015::days::day_01::Day01>(),
+    DayEntry::of::<crate::Y2015::days::day_02::Day02>(),
+    DayEntry::of::<crate::Y2015::days::day_03::Day03>(),
+    DayEntry::of::<crate::Y2022::days::day_01::Day01>(),
+    DayEntry::of::<crate::Y2022::days::day_02::Day02>(),
+    DayEntry::of::<crate::Y2022::days::day_03::Day03>(),
+    DayEntry::of::<crate::Y2022::days::day_04::Day04>(),
+    DayEntry::of::<crate::Y2022::days::day_05::Day05>(),
+    DayEntry::of::<crate::Y2022::days::day_06::Day06>(),
+    DayEntry::of::<crate::Y2022::days::day_07::Day07>(),
+    DayEntry::of::<crate::Y2022::days::day_08::Day08>(),
+    DayEntry::of::<crate::Y2022::days::day_09::Day09>(),
+    DayEntry::of::<crate::Y2022::days::day_10::Day10>(),
+    DayEntry::of::<crate::Y2022::days::day_11::Day11>(),
+    DayEntry::of::<crate::Y2022::days::day_12::Day12>(),
+    DayEntry::of::<crate::Y2022::days::day_13::Day13>(),
+    DayEntry::of::<crate::Y2022::days::day_14::Day14>(),
+    DayEntry::of::<crate::Y2022::days::day_15::Day15>(),
+    DayEntry::of::<crate::Y2022::days::day_16::Day16>(),
+    DayEntry::of::<crate::Y2022::days::day_17::Day17>(),
+    DayEntry::of::<crate::Y2023::days::day_01::Day01>(),
+    DayEntry::of::<crate::Y2023::days::day_02::Day02>(),
+    DayEntry::of::<crate::Y2023::days::day_03::Day03>(),
+    DayEntry::of::<crate::Y2023::days::day_04::Day04>(),
+    DayEntry::of::<crate::Y2023::days::day_05::Day05>(),
+    DayEntry::of::<crate::Y2023::days::day_06::Day06>(),
+    DayEntry::of::<crate::Y2023::days::day_07::Day07>(),
+    DayEntry::of::<crate::Y2023::days::day_08::Day08>(),
+    DayEntry::of::<crate::Y2023::days::day_09::Day09>(),
+    DayEntry::of::<crate::Y2023::days::day_10::Day10>(),
+    DayEntry::of::<crate::Y2023::days::day_11::Day11>(),
+    DayEntry::of::<crate::Y2023::days::day_12::Day12>(),
+    DayEntry::of::<crate::Y2023::days::day_13::Day13>(),
+    DayEntry::of::<crate::Y2023::days::day_14::Day14>(),
+    DayEntry::of::<crate::Y2023::days::day_15::Day15>(),
+    DayEntry::of::<crate::Y2023::days::day_16::Day16>(),
+    DayEntry::of::<crate::Y2023::days::day_17::Day17>(),
+    DayEntry::of::<crate::Y2023::days::day_18::Day18>(),
+    DayEntry::of::<crate::Y2023::days::day_19::Day19>(),
+    DayEntry::of::<crate::Y2023::days::day_20::Day20>(),
+    DayEntry::of::<crate::Y2023::days::day_21::Day21>(),
+    DayEntry::of::<crate::Y2023::days::day_22::Day22>(),
+    DayEntry::of::<crate::Y2024::days::day_01::Day01>(),
+    DayEntry::of::<crate::Y2024::days::day_02::Day02>(),
+    DayEntry::of::<crate::Y2024::days::day_03::Day03>(),
+    DayEntry::of::<crate::Y2024::days::day_04::Day04>(),
+    DayEntry::of::<crate::Y2024::days::day_05::Day05>(),
+    DayEntry::of::<crate::Y2024::days::day_06::Day06>(),
+    DayEntry::of::<crate::Y2024::days::day_07::Day07>(),
+    DayEntry::of::<crate::Y2024::days::day_08::Day08>(),
+    DayEntry::of::<crate::Y2024::days::day_09::Day09>(),
+];
+
+#[derive(Debug, PartialEq)]
+pub enum RegistryError {
+    UnknownYear(u16),
+    UnknownDay(u16, u8),
+    Unimplemented(u16, u8),
+}
+
+impl fmt::Display for RegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RegistryError::UnknownYear(year) => write!(
+                f,
+                "unknown year {} (available: {})",
+                year,
+                years()
+                    .iter()
+                    .map(|year| year.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            RegistryError::UnknownDay(year, day) => {
+                write!(f, "unknown day {} for {}, days go from 1 to 25", day, year)
+            }
+            RegistryError::Unimplemented(year, day) => {
+                write!(f, "Y{} Day {:02} is not implemented yet", year, day)
+            }
+        }
+    }
+}
+
+impl std::error::Error for RegistryError {}
+
+/// All years that have at least one implemented day, in ascending order.
+pub fn years() -> Vec<u16> {
+    let mut years: Vec<u16> = DAYS.iter().map(|entry| entry.year).collect();
+    years.dedup();
+    years
+}
+
+/// All implemented days of `year`.
+pub fn days_of(year: u16) -> Result<Vec<&'static DayEntry>, RegistryError> {
+    let entries: Vec<&DayEntry> = DAYS.iter().filter(|entry| entry.year == year).collect();
+    if entries.is_empty() {
+        return Err(RegistryError::UnknownYear(year));
+    }
+
+    Ok(entries)
+}
+
+pub fn find(year: u16, day: u8) -> Result<&'static DayEntry, RegistryError> {
+    let entries = days_of(year)?;
+    if !(1..=25).contains(&day) {
+        return Err(RegistryError::UnknownDay(year, day));
+    }
+
+    entries
+        .into_iter()
+        .find(|entry| entry.day == day)
+        .ok_or(RegistryError::Unimplemented(year, day))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_sorted() {
+        let keys: Vec<(u16, u8)> = DAYS.iter().map(|entry| (entry.year, entry.day)).collect();
+        let mut sorted = keys.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(keys, sorted);
+    }
+
+    #[test]
+    fn test_find() {
+        assert_eq!(years(), vec![2015, 2022, 2023, 2024]);
+        assert_eq!(find(2023, 17).unwrap().day, 17);
+        assert_eq!(find(2019, 1).err(), Some(RegistryError::UnknownYear(2019)));
+        assert_eq!(
+            find(2023, 26).err(),
+            Some(RegistryError::UnknownDay(2023, 26))
+        );
+        assert_eq!(
+            find(2015, 4).err(),
+            Some(RegistryError::Unimplemented(2015, 4))
+        );
+    }
+}
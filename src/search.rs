@@ -0,0 +1,256 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// What a search found: the lowest cost it found to every state it reached, how each of them
+/// was reached and the first goal it settled, if any. When the search stops at a goal, the
+/// states still on its frontier keep their best cost so far, which may not be their shortest.
+#[derive(Debug, Clone)]
+pub struct SearchResult<S, C> {
+    pub distances: HashMap<S, C>,
+    pub predecessors: HashMap<S, S>,
+    pub goal: Option<S>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> SearchResult<S, C> {
+    pub fn distance(&self, state: &S) -> Option<C> {
+        self.distances.get(state).copied()
+    }
+
+    /// The cost of the goal the search stopped at.
+    pub fn goal_distance(&self) -> Option<C> {
+        self.goal.as_ref().and_then(|goal| self.distance(goal))
+    }
+
+    /// The states from a source to `target`, both included. Empty if `target` was not reached.
+    pub fn path_to(&self, target: &S) -> Vec<S> {
+        if !self.distances.contains_key(target) {
+            return vec![];
+        }
+
+        let mut path = vec![target.clone()];
+        while let Some(previous) = self.predecessors.get(path.last().unwrap()) {
+            path.push(previous.clone());
+        }
+        path.reverse();
+
+        path
+    }
+}
+
+/// Breadth-first search where every move costs 1. Stops at the first state for which
+/// `is_goal` holds, pass `|_| false` to explore everything reachable.
+pub fn bfs<S, I>(
+    sources: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> SearchResult<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut result = SearchResult {
+        distances: HashMap::new(),
+        predecessors: HashMap::new(),
+        goal: None,
+    };
+
+    let mut queue = VecDeque::new();
+    for source in sources {
+        if !result.distances.contains_key(&source) {
+            result.distances.insert(source.clone(), 0);
+            queue.push_back(source);
+        }
+    }
+
+    while let Some(state) = queue.pop_front() {
+        if is_goal(&state) {
+            result.goal = Some(state);
+            break;
+        }
+
+        let distance = result.distances[&state] + 1;
+        for next in neighbours(&state) {
+            if !result.distances.contains_key(&next) {
+                result.distances.insert(next.clone(), distance);
+                result.predecessors.insert(next.clone(), state.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+
+    result
+}
+
+/// Dijkstra's shortest paths, `neighbours` yielding each next state with the cost of the move.
+pub fn dijkstra<S, C, I>(
+    sources: impl IntoIterator<Item = S>,
+    neighbours: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> SearchResult<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(sources, neighbours, |_| C::default(), is_goal)
+}
+
+/// A* search. `heuristic` must never overestimate the remaining cost to a goal, otherwise
+/// the goal distance may not be the shortest one.
+pub fn astar<S, C, I>(
+    sources: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> SearchResult<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut result = SearchResult {
+        distances: HashMap::new(),
+        predecessors: HashMap::new(),
+        goal: None,
+    };
+
+    let mut heap = BinaryHeap::new();
+    for source in sources {
+        if !result.distances.contains_key(&source) {
+            result.distances.insert(source.clone(), C::default());
+            heap.push(Entry {
+                priority: heuristic(&source),
+                cost: C::default(),
+                state: source,
+            });
+        }
+    }
+
+    while let Some(Entry { cost, state, .. }) = heap.pop() {
+        // A cheaper way to this state was settled after this entry was queued.
+        if result
+            .distances
+            .get(&state)
+            .is_some_and(|best| cost > *best)
+        {
+            continue;
+        }
+
+        if is_goal(&state) {
+            result.goal = Some(state);
+            break;
+        }
+
+        for (next, step_cost) in neighbours(&state) {
+            let next_cost = cost + step_cost;
+            if result
+                .distances
+                .get(&next)
+                .is_none_or(|best| next_cost < *best)
+            {
+                result.distances.insert(next.clone(), next_cost);
+                result.predecessors.insert(next.clone(), state.clone());
+                heap.push(Entry {
+                    priority: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    state: next,
+                });
+            }
+        }
+    }
+
+    result
+}
+
+/// A queued state, ordered so that `BinaryHeap` pops the lowest priority first.
+struct Entry<S, C> {
+    priority: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> PartialEq for Entry<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S, C: Ord> Eq for Entry<S, C> {}
+
+impl<S, C: Ord> PartialOrd for Entry<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> Ord for Entry<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 0 -> 1 -> 3 costs 5, 0 -> 2 -> 3 costs 3, 4 is unreachable.
+    fn edges(node: &u8) -> Vec<(u8, u32)> {
+        match node {
+            0 => vec![(1, 1), (2, 2)],
+            1 => vec![(3, 4)],
+            2 => vec![(3, 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let result = bfs(
+            [0u8],
+            |node| edges(node).into_iter().map(|(n, _)| n),
+            |_| false,
+        );
+        assert_eq!(result.distance(&3), Some(2));
+        assert_eq!(result.distance(&4), None);
+        assert_eq!(result.path_to(&3).len(), 3);
+        assert_eq!(result.goal, None);
+
+        let result = bfs(
+            [2u8, 0],
+            |node| edges(node).into_iter().map(|(n, _)| n),
+            |n| *n == 3,
+        );
+        assert_eq!(result.goal_distance(), Some(1));
+        assert_eq!(result.path_to(&3), vec![2, 3]);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let result = dijkstra([0u8], edges, |n| *n == 3);
+        assert_eq!(result.goal, Some(3));
+        assert_eq!(result.goal_distance(), Some(3));
+        assert_eq!(result.path_to(&3), vec![0, 2, 3]);
+        assert!(result.path_to(&4).is_empty());
+    }
+
+    #[test]
+    fn test_astar() {
+        // Manhattan distance on an open 10x10 grid.
+        let target = (9i32, 9i32);
+        let result = astar(
+            [(0i32, 0i32)],
+            |&(x, y)| {
+                [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+                    .into_iter()
+                    .filter(|&(x, y)| (0..10).contains(&x) && (0..10).contains(&y))
+                    .map(|next| (next, 1))
+            },
+            |&(x, y)| (target.0 - x).abs() + (target.1 - y).abs(),
+            |state| *state == target,
+        );
+        assert_eq!(result.goal_distance(), Some(18));
+        assert_eq!(result.path_to(&target).len(), 19);
+    }
+}
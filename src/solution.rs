@@ -0,0 +1,393 @@
+use std::fmt;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::error::AocError;
+use crate::file_handler::{FileHandler, InputSource};
+
+/// A single puzzle day. `parse` turns the raw input into whatever model both parts work on,
+/// so it only runs once, and the parts render their answer as a string so every day can be
+/// reported the same way. Malformed input is reported as an `AocError` rather than a panic.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+
+    type Input<'a>;
+
+    /// Whether the empty lines at the end of the input are dropped before parsing, which every
+    /// day expects unless its format gives them a meaning.
+    const TRIM_TRAILING_BLANK_LINES: bool = true;
+
+    fn name() -> String {
+        format!("Day_{:02}", Self::DAY)
+    }
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError>;
+
+    fn part_one(input: &Self::Input<'_>) -> Result<String, AocError>;
+
+    fn part_two(input: &Self::Input<'_>) -> Result<String, AocError>;
+
+    /// The examples of the puzzle text with their expected answers.
+    fn examples() -> Vec<Example> {
+        vec![]
+    }
+
+    /// Applies the parameters of an example, for puzzles whose examples are smaller than the
+    /// real input, e.g. a different row to look at.
+    fn configure(_input: &mut Self::Input<'_>, params: &[(&str, i64)]) -> Result<(), AocError> {
+        configure_each(params, |name, _| Err(unknown_parameter(name)))
+    }
+}
+
+/// Applies the parameters one by one, stopping at the first one `apply` rejects.
+pub fn configure_each(
+    params: &[(&str, i64)],
+    mut apply: impl FnMut(&str, i64) -> Result<(), AocError>,
+) -> Result<(), AocError> {
+    for (name, value) in params.iter() {
+        apply(name, *value)?;
+    }
+
+    Ok(())
+}
+
+/// The error of a parameter the day does not take.
+pub fn unknown_parameter(name: &str) -> AocError {
+    AocError::unsolvable(format!("unknown parameter `{}`", name))
+}
+
+/// An example input of a part with the answer the puzzle text gives for it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Example {
+    pub part: u8,
+    pub input: &'static str,
+    pub expected: &'static str,
+    pub params: Vec<(&'static str, i64)>,
+}
+
+impl Example {
+    pub fn new(part: u8, input: &'static str, expected: &'static str) -> Example {
+        Example {
+            part,
+            input,
+            expected,
+            params: vec![],
+        }
+    }
+
+    pub fn with(mut self, name: &'static str, value: i64) -> Example {
+        self.params.push((name, value));
+        self
+    }
+}
+
+/// What an example gave when it ran.
+#[derive(Debug)]
+pub struct ExampleReport {
+    pub example: Example,
+    pub answer: Result<String, AocError>,
+}
+
+impl ExampleReport {
+    pub fn passed(&self) -> bool {
+        matches!(&self.answer, Ok(answer) if answer == self.example.expected)
+    }
+}
+
+impl fmt::Display for ExampleReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let params: Vec<String> = self
+            .example
+            .params
+            .iter()
+            .map(|(name, value)| format!("{}={}", name, value))
+            .collect();
+        write!(f, "part {}", self.example.part)?;
+        if !params.is_empty() {
+            write!(f, " ({})", params.join(", "))?;
+        }
+
+        match &self.answer {
+            Ok(_) if self.passed() => write!(f, ": pass"),
+            Ok(answer) => write!(
+                f,
+                ": FAIL, expected {} but got {}",
+                self.example.expected, answer
+            ),
+            Err(error) => write!(f, ": FAIL, {}", error),
+        }
+    }
+}
+
+/// Answers and timings of one day.
+#[derive(Debug, Clone)]
+pub struct SolutionReport {
+    pub year: u16,
+    pub day: u8,
+    pub name: String,
+    pub part_one: String,
+    pub part_two: String,
+    pub duration_parse: Duration,
+    pub duration_one: Duration,
+    pub duration_two: Duration,
+}
+
+impl fmt::Display for SolutionReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}\nPart_1: {}\nPart_2: {}\n\nDuration_parse(ns): {}\nDuration_1(ns): {}\nDuration_2(ns): {}\n-----\n",
+            self.name,
+            self.part_one,
+            self.part_two,
+            self.duration_parse.as_nanos(),
+            self.duration_one.as_nanos(),
+            self.duration_two.as_nanos()
+        )
+    }
+}
+
+/// Reads the input of `S` from `source` and solves it, only `part` when one is given.
+pub fn run<S: Solution>(
+    source: &InputSource,
+    part: Option<u8>,
+    params: &[(&str, i64)],
+) -> Result<SolutionReport, AocError> {
+    let input = FileHandler::read(source, S::YEAR, S::DAY)?;
+    solve::<S>(&input, part, params)
+}
+
+/// Parses `input` once, applies `params` and solves the parts of `S` on the parsed model,
+/// timing the parsing and each part on their own. When `part` is given the other part is
+/// skipped and left empty with a zero duration.
+pub fn solve<S: Solution>(
+    input: &str,
+    part: Option<u8>,
+    params: &[(&str, i64)],
+) -> Result<SolutionReport, AocError> {
+    let input = FileHandler::normalize(input, S::TRIM_TRAILING_BLANK_LINES);
+
+    let start_parse = Instant::now();
+    let mut parsed = S::parse(&input)?;
+    let duration_parse = start_parse.elapsed();
+    S::configure(&mut parsed, params)?;
+
+    let (part_one, duration_one) = if part.is_none_or(|part| part == 1) {
+        let start_1 = Instant::now();
+        (S::part_one(&parsed)?, start_1.elapsed())
+    } else {
+        (String::new(), Duration::ZERO)
+    };
+
+    let (part_two, duration_two) = if part.is_none_or(|part| part == 2) {
+        let start_2 = Instant::now();
+        (S::part_two(&parsed)?, start_2.elapsed())
+    } else {
+        (String::new(), Duration::ZERO)
+    };
+
+    Ok(SolutionReport {
+        year: S::YEAR,
+        day: S::DAY,
+        name: S::name(),
+        part_one,
+        part_two,
+        duration_parse,
+        duration_one,
+        duration_two,
+    })
+}
+
+/// Solves one example of `S`.
+pub fn run_example<S: Solution>(example: &Example) -> Result<String, AocError> {
+    run_example_on::<S>(example, example.input)
+}
+
+/// Solves `example` of `S` on `input` rather than the example's own text, e.g. the same
+/// text saved with other line endings.
+fn run_example_on<S: Solution>(example: &Example, input: &str) -> Result<String, AocError> {
+    let input = FileHandler::normalize(input, S::TRIM_TRAILING_BLANK_LINES);
+    let mut parsed = S::parse(&input)?;
+    S::configure(&mut parsed, &example.params)?;
+    match example.part {
+        1 => S::part_one(&parsed),
+        _ => S::part_two(&parsed),
+    }
+}
+
+pub fn run_examples<S: Solution>() -> Vec<ExampleReport> {
+    S::examples()
+        .into_iter()
+        .map(|example| ExampleReport {
+            answer: run_example::<S>(&example),
+            example,
+        })
+        .collect()
+}
+
+/// Panics with every example of `S` that does not give its expected answer, as written and
+/// saved with CRLF line endings and a trailing newline, which is what the `test_examples`
+/// test of every day calls.
+pub fn assert_examples<S: Solution>() {
+    let mut failures: Vec<String> = run_examples::<S>()
+        .iter()
+        .filter(|report| !report.passed())
+        .map(|report| report.to_string())
+        .collect();
+
+    for example in S::examples() {
+        let crlf = format!("{}\n", example.input).replace('\n', "\r\n");
+        let report = ExampleReport {
+            answer: run_example_on::<S>(&example, &crlf),
+            example,
+        };
+        if !report.passed() {
+            failures.push(format!("{} with CRLF line endings", report));
+        }
+    }
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+/// Measures a routine many times, implemented by the benchmarks on top of criterion.
+pub trait Timer {
+    fn time(&mut self, label: &str, routine: &mut dyn FnMut());
+}
+
+/// Times parsing, part one and part two of `S` on their own. The input is parsed once
+/// up front so the parts are measured without it.
+pub fn bench<S: Solution>(input: &str, timer: &mut dyn Timer) -> Result<(), AocError> {
+    let input = FileHandler::normalize(input, S::TRIM_TRAILING_BLANK_LINES);
+    timer.time("parse", &mut || {
+        black_box(S::parse(black_box(&input)).is_ok());
+    });
+
+    let parsed = S::parse(&input)?;
+    timer.time("part_one", &mut || {
+        black_box(S::part_one(black_box(&parsed)).is_ok());
+    });
+    timer.time("part_two", &mut || {
+        black_box(S::part_two(black_box(&parsed)).is_ok());
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sample {}
+
+    impl Solution for Sample {
+        const YEAR: u16 = 2015;
+        const DAY: u8 = 7;
+
+        type Input<'a> = Vec<&'a str>;
+
+        fn parse(input: &str) -> Result<Vec<&str>, AocError> {
+            Ok(input.split('\n').collect())
+        }
+
+        fn part_one(lines: &Vec<&str>) -> Result<String, AocError> {
+            Ok(lines.len().to_string())
+        }
+
+        fn part_two(lines: &Vec<&str>) -> Result<String, AocError> {
+            Ok(lines.concat())
+        }
+
+        fn examples() -> Vec<Example> {
+            vec![Example::new(1, "a\nb\nc", "3"), Example::new(2, "x", "y")]
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        let report = solve::<Sample>("ab\ncd", None, &[]).unwrap();
+        assert_eq!(report.name, "Day_07");
+        assert_eq!((report.year, report.day), (2015, 7));
+        assert_eq!(report.part_one, "2");
+        assert_eq!(report.part_two, "abcd");
+    }
+
+    struct FailingPartTwo {}
+
+    impl Solution for FailingPartTwo {
+        const YEAR: u16 = 2015;
+        const DAY: u8 = 8;
+
+        type Input<'a> = &'a str;
+
+        fn parse(input: &str) -> Result<&str, AocError> {
+            Ok(input)
+        }
+
+        fn part_one(input: &&str) -> Result<String, AocError> {
+            Ok(input.len().to_string())
+        }
+
+        fn part_two(_: &&str) -> Result<String, AocError> {
+            panic!("part two should not run")
+        }
+    }
+
+    #[test]
+    fn test_solve_one_part() {
+        let report = solve::<FailingPartTwo>("abc", Some(1), &[]).unwrap();
+        assert_eq!(report.part_one, "3");
+        assert_eq!(report.part_two, "");
+        assert_eq!(report.duration_two, Duration::ZERO);
+
+        let report = solve::<Sample>("ab\ncd", Some(2), &[]).unwrap();
+        assert_eq!(report.part_one, "");
+        assert_eq!(report.part_two, "abcd");
+    }
+
+    #[test]
+    fn test_solve_params() {
+        assert_eq!(
+            solve::<Sample>("ab", None, &[("size", 3)])
+                .unwrap_err()
+                .to_string(),
+            "no solution: unknown parameter `size`"
+        );
+    }
+
+    #[test]
+    fn test_solve_crlf() {
+        let report = solve::<Sample>("ab\r\ncd\r\n", None, &[]).unwrap();
+        assert_eq!(report.part_one, "2");
+        assert_eq!(report.part_two, "abcd");
+    }
+
+    #[test]
+    fn test_run_examples() {
+        let reports = run_examples::<Sample>();
+        assert!(reports[0].passed());
+        assert_eq!(reports[1].to_string(), "part 2: FAIL, expected y but got x");
+
+        let example = Example::new(1, "ab", "1").with("size", 3);
+        assert_eq!(
+            run_example::<Sample>(&example).unwrap_err().to_string(),
+            "no solution: unknown parameter `size`"
+        );
+    }
+
+    struct Labels(Vec<String>);
+
+    impl Timer for Labels {
+        fn time(&mut self, label: &str, routine: &mut dyn FnMut()) {
+            routine();
+            self.0.push(label.to_owned());
+        }
+    }
+
+    #[test]
+    fn test_bench() {
+        let mut labels = Labels(vec![]);
+        bench::<Sample>("ab\ncd", &mut labels).unwrap();
+        assert_eq!(labels.0, vec!["parse", "part_one", "part_two"]);
+    }
+}
@@ -0,0 +1,137 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use crate::error::AocError;
+use crate::file_handler::FileHandler;
+use crate::solution::SolutionReport;
+
+/// Recorded answers of one year, read from `Y<YEAR>/inputs/answers.txt` next to the inputs.
+/// Every line is `<day> <part> <answer>`, blank lines and lines starting with `#` are skipped.
+/// An answer spanning several lines, like a screen of letters, writes its line breaks as `\n`.
+#[derive(Debug, Default)]
+pub struct Answers {
+    expected: HashMap<(u8, u8), String>,
+}
+
+impl Answers {
+    pub fn parse(text: &str) -> Result<Answers, AocError> {
+        let mut expected = HashMap::new();
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let invalid = || AocError::parse(index, line, "expected `<day> <part> <answer>`");
+            let mut values = line.splitn(3, ' ');
+            let day = values.next().and_then(|day| day.parse::<u8>().ok());
+            let part = values.next().and_then(|part| part.parse::<u8>().ok());
+            let answer = values.next().map(|answer| answer.trim());
+            match (day, part, answer) {
+                (Some(day), Some(part @ 1..=2), Some(answer)) if !answer.is_empty() => {
+                    expected.insert((day, part), answer.replace("\\n", "\n"));
+                }
+                _ => return Err(invalid()),
+            }
+        }
+
+        Ok(Answers { expected })
+    }
+
+    /// A year without an answers file simply has every answer missing.
+    pub fn load(dir: &Path, year: u16) -> Result<Answers, AocError> {
+        match fs::read_to_string(FileHandler::answers_path(dir, year)) {
+            Ok(text) => Answers::parse(&text),
+            Err(_) => Ok(Answers::default()),
+        }
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.expected
+            .get(&(day, part))
+            .map(|answer| answer.as_str())
+    }
+}
+
+/// Whether `dir` holds an input for the day, a missing or blank file leaving its answers
+/// missing rather than failing the day.
+pub fn has_input(dir: &Path, year: u16, day: u8) -> bool {
+    fs::read_to_string(FileHandler::input_path(dir, year, day))
+        .is_ok_and(|input| !input.trim().is_empty())
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String, actual: String },
+    Missing,
+}
+
+/// Compares both parts of `report` with the recorded answers.
+pub fn check(answers: &Answers, report: &SolutionReport) -> [Verdict; 2] {
+    let verdict = |part: u8, actual: &str| match answers.get(report.day, part) {
+        Some(expected) if expected == actual => Verdict::Pass,
+        Some(expected) => Verdict::Fail {
+            expected: expected.to_owned(),
+            actual: actual.to_owned(),
+        },
+        None => Verdict::Missing,
+    };
+
+    [verdict(1, &report.part_one), verdict(2, &report.part_two)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = Answers::parse("# Y2023\n1 1 54390\n1 2 54277\n\n8 1 11567").unwrap();
+        assert_eq!(answers.get(1, 2), Some("54277"));
+        assert_eq!(answers.get(8, 1), Some("11567"));
+        assert_eq!(answers.get(8, 2), None);
+
+        let answers = Answers::parse("10 2 #..#\\n####").unwrap();
+        assert_eq!(answers.get(10, 2), Some("#..#\n####"));
+
+        assert!(matches!(
+            Answers::parse("1 1 54390\n1 3 54277"),
+            Err(AocError::Parse { line: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn test_has_input() {
+        let dir = Path::new("./src");
+        assert!(has_input(dir, 2023, 1));
+        assert!(!has_input(dir, 2023, 26));
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse("5 1 CMZ").unwrap();
+        let report = SolutionReport {
+            year: 2022,
+            day: 5,
+            name: "Day_05".to_owned(),
+            part_one: "CMZ".to_owned(),
+            part_two: "MCD".to_owned(),
+            duration_parse: Duration::ZERO,
+            duration_one: Duration::ZERO,
+            duration_two: Duration::ZERO,
+        };
+        assert_eq!(check(&answers, &report), [Verdict::Pass, Verdict::Missing]);
+
+        let answers = Answers::parse("5 1 ABC").unwrap();
+        assert_eq!(
+            check(&answers, &report)[0],
+            Verdict::Fail {
+                expected: "ABC".to_owned(),
+                actual: "CMZ".to_owned()
+            }
+        );
+    }
+}